@@ -2,7 +2,10 @@ use obs_properties::obs_properties_to_functions;
 use parse::UpdaterInput;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Fields, ItemImpl, LitStr, Type, TypePath};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, token::Comma, Data, DeriveInput, Field, Fields,
+    ItemImpl, LitStr, Type, TypePath,
+};
 
 mod docs;
 mod fields;
@@ -35,6 +38,10 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
     let updatable_type = u_input.updatable_type;
 
     let input = parse_macro_input!(item as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     let i_ident = input.ident;
     let updater_name = format_ident!("{}", i_ident);
@@ -42,22 +49,19 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
     let visibility = input.vis;
     let attributes = input.attrs;
 
-    let fields = match input.data {
-        Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => fields.named,
-            _ => panic!("Only named fields are supported"),
-        },
-        _ => panic!("Only structs are supported"),
-    };
-
     let (struct_fields, struct_initializers) = fields::generate_struct_fields(&fields);
-    let functions = obs_properties_to_functions(
+    let functions = match obs_properties_to_functions(
         &fields,
         quote! {
             use libobs_wrapper::data::ObsObjectUpdater;
             self.get_settings_updater()
         },
-    );
+    ) {
+        Ok(functions) => functions,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let setters = functions.setters;
+    let validate = validate_fn(&functions.checks);
 
     let updatable_type2 = updatable_type.clone();
     let expanded = quote! {
@@ -105,8 +109,12 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
                 #id_value.into()
             }
 
-            fn update(self) -> Result<(), libobs_wrapper::utils::ObsError> {
+            #validate
+
+            fn update(mut self) -> Result<(), libobs_wrapper::utils::ObsError> {
                 use libobs_wrapper::utils::traits::ObsUpdatable;
+                self.validate()?;
+
                 let #updater_name {
                     settings_updater,
                     updatable,
@@ -126,7 +134,7 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
 
         impl <'a> #updater_name <'a> {
-            #(#functions)*
+            #(#setters)*
         }
     };
 
@@ -148,6 +156,34 @@ pub fn obs_object_updater(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Each field in the struct must be annotated with `#[obs_property(type_t = "...")]`.
 /// Supported `type_t` values:
 ///
+/// * `enum` - An enum implementing `ToPrimitive`, stored as integer
+/// * `enum_string` - An enum implementing `StringEnum`, stored as string
+/// * `string` - Anything convertible into an `ObsString`
+/// * `bool`
+/// * `int` - An `i64`
+/// * `double` / `float` - An `f64`
+/// * `path` - An `ObsPath`
+/// * `color` - A `u32` in `0xAARRGGBB` format
+/// * `font` - An `ObsFont`
+/// * `frame_rate` - A `(numerator, denominator)` tuple
+/// * `array` - A `Vec<String>`, as used by editable lists
+///
+/// If the field type is `Option<T>`, the setter takes an `Option` and `None`
+/// removes the value again, so the default of the plugin is used.
+/// A different key in the settings can be given with `settings_key = "..."`.
+///
+/// `default = ...` sets the value when the builder is created, so it is used
+/// instead of the default of the plugin. For `int`, `double` and `float`
+/// properties, `min`, `max` and `step` restrict the allowed values. The setter
+/// then returns a `Result`, a `set_*_clamped` setter is added that moves the
+/// value into the range instead, and `build` or `update` fail if a value set
+/// in another way is out of range.
+///
+/// ```ignore
+/// #[obs_property(type_t = "int", default = 6, min = 0, max = 120)]
+/// timeout_frames: i64,
+/// ```
+///
 /// ```rust
 /// use libobs_wrapper::data::StringEnum;
 /// use libobs_simple_macro::obs_object_builder;
@@ -194,6 +230,10 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
     let id = parse_macro_input!(attr as LitStr);
 
     let input = parse_macro_input!(item as DeriveInput);
+    let fields = match named_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
     let i_ident = input.ident;
     let builder_name = format_ident!("{}", i_ident);
@@ -202,24 +242,27 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
     let visibility = input.vis;
     let attributes = input.attrs;
 
-    let fields = match input.data {
-        Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => fields.named,
-            _ => panic!("Only named fields are supported"),
-        },
-        _ => panic!("Only structs are supported"),
-    };
-
     let id_value = id.value();
     let (struct_fields, struct_initializers) = fields::generate_struct_fields(&fields);
 
-    let functions = obs_properties_to_functions(
+    let functions = match obs_properties_to_functions(
         &fields,
         quote! {
             use libobs_wrapper::data::ObsObjectBuilder;
             self.get_settings_updater()
         },
-    );
+    ) {
+        Ok(functions) => functions,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let setters = functions.setters;
+    let defaults = functions.defaults;
+    let validate = validate_fn(&functions.checks);
+    let builder_mut = if defaults.is_empty() {
+        quote! {}
+    } else {
+        quote! { mut }
+    };
 
     let expanded = quote! {
         #(#attributes)*
@@ -249,7 +292,7 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
                 let mut hotkeys = libobs_wrapper::data::ObsData::new(runtime.clone())?;
                 let mut settings = libobs_wrapper::data::ObsData::new(runtime.clone())?;
 
-                Ok(Self {
+                let #builder_mut builder = Self {
                     #(#struct_initializers,)*
                     name,
                     settings_updater: settings.bulk_update(),
@@ -257,7 +300,10 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
                     hotkeys_updater: hotkeys.bulk_update(),
                     hotkeys,
                     runtime
-                })
+                };
+
+                #(#defaults)*
+                Ok(builder)
             }
 
             fn get_settings(&self) -> &libobs_wrapper::data::ObsData {
@@ -284,7 +330,11 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
                 #id_value.into()
             }
 
-            fn build(self) -> Result<libobs_wrapper::utils::ObjectInfo, libobs_wrapper::utils::ObsError> {
+            #validate
+
+            fn build(mut self) -> Result<libobs_wrapper::utils::ObjectInfo, libobs_wrapper::utils::ObsError> {
+                self.validate()?;
+
                 let name = self.get_name();
                 let #builder_name {
                     settings_updater,
@@ -307,7 +357,7 @@ pub fn obs_object_builder(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
 
         impl #builder_name {
-            #(#functions)*
+            #(#setters)*
         }
     };
 
@@ -337,10 +387,15 @@ pub fn obs_object_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let impl_item2 = impl_item.clone();
 
     // Create the builder and updater struct names
-    let base_name = if let Type::Path(TypePath { path, .. }) = &*input.self_ty {
-        path.segments.last().unwrap().ident.to_string()
-    } else {
-        panic!("Only path types are supported in self_ty")
+    let base_name = match &*input.self_ty {
+        Type::Path(TypePath { path, .. }) if !path.segments.is_empty() => {
+            path.segments.last().unwrap().ident.to_string()
+        }
+        other => {
+            return syn::Error::new_spanned(other, "Only path types are supported in self_ty")
+                .to_compile_error()
+                .into()
+        }
     };
 
     let builder_name = format_ident!("{}Builder", base_name);
@@ -360,3 +415,41 @@ pub fn obs_object_impl(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Overrides `validate` of the builder and updater traits if any field has a range.
+/// The pending changes are applied first, so the checks see every value that was set.
+fn validate_fn(checks: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    if checks.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        fn validate(&mut self) -> Result<(), libobs_wrapper::utils::ObsError> {
+            use libobs_wrapper::data::ObsDataGetters;
+
+            let settings_updater =
+                std::mem::replace(&mut self.settings_updater, self.settings.bulk_update());
+            settings_updater.update()?;
+
+            #(#checks)*
+            Ok(())
+        }
+    }
+}
+
+/// Returns the named fields of the struct or an error pointing at the item.
+fn named_fields(input: &DeriveInput) -> syn::Result<Punctuated<Field, Comma>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.clone()),
+            other => Err(syn::Error::new_spanned(
+                other,
+                "Only named fields are supported",
+            )),
+        },
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Only structs are supported",
+        )),
+    }
+}
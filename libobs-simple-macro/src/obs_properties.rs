@@ -1,13 +1,28 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{punctuated::Punctuated, token::Comma, Field, LitStr, MetaNameValue, Token};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Error, Expr, Field,
+    GenericArgument, LitStr, MetaNameValue, PathArguments, Result, Token, Type,
+};
 
 use crate::docs::collect_doc;
 
+const SUPPORTED_TYPES: &str = "`enum`, `enum_string`, `string`, `bool`, `int`, `double`, `float`, `path`, `color`, `font`, `frame_rate` or `array`";
+
+/// Code generated for the `obs_property` fields of a struct
+#[derive(Default)]
+pub struct PropertyFunctions {
+    pub setters: Vec<TokenStream>,
+    /// Statements that apply the `default` of the fields to `builder`
+    pub defaults: Vec<TokenStream>,
+    /// Statements that check `self.settings` against `min`, `max` and `step`
+    pub checks: Vec<TokenStream>,
+}
+
 pub fn obs_properties_to_functions(
     fields: &Punctuated<Field, Comma>,
     settings_getter: TokenStream,
-) -> Vec<TokenStream> {
+) -> Result<PropertyFunctions> {
     let obs_properties = fields
         .iter()
         .filter_map(|f| {
@@ -17,121 +32,363 @@ pub fn obs_properties_to_functions(
         })
         .collect::<Vec<_>>();
 
-    let mut functions = Vec::new();
+    // Collects the errors of all fields, so they are reported at once
+    let mut functions = PropertyFunctions::default();
+    let mut error: Option<Error> = None;
     for (field, attr) in obs_properties {
-        let field_type = &field.ty;
-        let field_name = field.ident.as_ref().unwrap();
-
-        let name_values: Punctuated<MetaNameValue, Token![,]> = attr
-            .parse_args_with(Punctuated::parse_terminated)
-            .unwrap_or_else(|_| {
-                panic!(
-                    "Field {} has invalid obs_property, should be name value",
+        if let Err(e) = property_to_function(field, attr, &settings_getter, &mut functions) {
+            match error.as_mut() {
+                Some(error) => error.combine(e),
+                None => error = Some(e),
+            }
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(functions),
+    }
+}
+
+fn property_to_function(
+    field: &Field,
+    attr: &Attribute,
+    settings_getter: &TokenStream,
+    functions: &mut PropertyFunctions,
+) -> Result<()> {
+    let field_name = field
+        .ident
+        .as_ref()
+        .ok_or_else(|| Error::new(field.span(), "obs_property fields must be named"))?;
+
+    let name_values: Punctuated<MetaNameValue, Token![,]> = attr
+        .parse_args_with(Punctuated::parse_terminated)
+        .map_err(|e| {
+            Error::new(
+                e.span(),
+                format!(
+                    "Field {} has invalid obs_property, should be name value pairs like `type_t = \"string\"`",
                     field_name
-                )
-            });
+                ),
+            )
+        })?;
+
+    let mut type_t = None;
+    let mut settings_key = None;
+    let mut default = None;
+    let mut min = None;
+    let mut max = None;
+    let mut step = None;
+    for name_value in &name_values {
+        let lit = string_value(&name_value.value);
+        let value = Some(name_value.value.clone());
+        if name_value.path.is_ident("type_t") {
+            type_t =
+                Some(lit.ok_or_else(|| {
+                    Error::new(name_value.value.span(), "type_t must be a string")
+                })?);
+        } else if name_value.path.is_ident("settings_key") {
+            settings_key = Some(lit.ok_or_else(|| {
+                Error::new(name_value.value.span(), "settings_key must be a string")
+            })?);
+        } else if name_value.path.is_ident("default") {
+            default = value;
+        } else if name_value.path.is_ident("min") {
+            min = value;
+        } else if name_value.path.is_ident("max") {
+            max = value;
+        } else if name_value.path.is_ident("step") {
+            step = value;
+        } else {
+            return Err(Error::new(
+                name_value.path.span(),
+                "Unknown obs_property argument, expected `type_t`, `settings_key`, `default`, `min`, `max` or `step`",
+            ));
+        }
+    }
+
+    let type_t =
+        type_t.ok_or_else(|| Error::new_spanned(attr, "type_t is required for obs_property"))?;
+    let settings_key = settings_key
+        .map(|key| key.value())
+        .unwrap_or_else(|| field_name.to_string());
+
+    // `Option<T>` fields unset the value when `None` is passed, so the default is used
+    let (is_option, field_type) = match option_inner(&field.ty) {
+        Some(inner) => (true, inner),
+        None => (false, &field.ty),
+    };
 
-        let type_t = &name_values
-            .iter()
-            .find(|e| *e.path.get_ident().unwrap() == "type_t")
-            .expect("type_t is required for obs_property")
-            .value;
+    let (_docs_str, docs_attr) = collect_doc(&field.attrs);
 
-        let type_t = match type_t {
-            syn::Expr::Lit(e) => match &e.lit {
-                syn::Lit::Str(s) => s.value(),
-                _ => panic!("type_t must be a string"),
+    let obs_settings_key = LitStr::new(&settings_key, Span::call_site());
+    let set_field = quote::format_ident!("set_{}", field_name);
+
+    let (generics, param_type, body) = match type_t.value().as_str() {
+        "enum" => (
+            quote! {},
+            quote! { #field_type },
+            quote! {
+                use num_traits::ToPrimitive;
+                let val = #field_name.to_i32().unwrap();
+
+                #settings_getter
+                    .set_int_ref(#obs_settings_key, val as i64);
             },
-            _ => panic!("type_t must be a string"),
-        };
+        ),
+        "enum_string" => (
+            quote! {},
+            quote! { #field_type },
+            quote! {
+                use libobs_wrapper::data::StringEnum;
 
-        #[allow(unused_variables)]
-        let mut obs_settings_name = field_name.to_string();
-        let pot_name = &name_values
-            .iter()
-            .find(|e| *e.path.get_ident().unwrap() == "settings_key");
-
-        if let Some(n) = pot_name {
-            obs_settings_name = match &n.value {
-                syn::Expr::Lit(e) => match &e.lit {
-                    syn::Lit::Str(s) => s.value(),
-                    _ => panic!("setings_key must be a string"),
-                },
-                _ => panic!("settings_key must be a string"),
-            };
+                #settings_getter
+                    .set_string_ref(#obs_settings_key, #field_name.to_str());
+            },
+        ),
+        "string" => (
+            quote! { <T: Into<libobs_wrapper::utils::ObsString> + Sync + Send> },
+            quote! { T },
+            quote! {
+                #settings_getter
+                    .set_string_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        "bool" => (
+            quote! {},
+            quote! { bool },
+            quote! {
+                #settings_getter
+                    .set_bool_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        "int" => (
+            quote! {},
+            quote! { i64 },
+            quote! {
+                #settings_getter
+                    .set_int_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        "double" | "float" => (
+            quote! {},
+            quote! { f64 },
+            quote! {
+                #settings_getter
+                    .set_double_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        "path" => (
+            quote! {},
+            quote! { libobs_wrapper::utils::ObsPath },
+            quote! {
+                #settings_getter
+                    .set_string_ref(#obs_settings_key, #field_name.build());
+            },
+        ),
+        "color" => (
+            quote! {},
+            quote! { u32 },
+            quote! {
+                // Colors are passed as ARGB, but libobs stores them as ABGR
+                let abgr = (#field_name & 0xFF00FF00)
+                    | ((#field_name >> 16) & 0xFF)
+                    | ((#field_name & 0xFF) << 16);
+
+                #settings_getter
+                    .set_int_ref(#obs_settings_key, abgr as i64);
+            },
+        ),
+        "font" => (
+            quote! {},
+            quote! { libobs_wrapper::data::ObsFont },
+            quote! {
+                #settings_getter
+                    .set_font_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        "frame_rate" => (
+            quote! {},
+            quote! { (u32, u32) },
+            quote! {
+                let (numerator, denominator) = #field_name;
+
+                #settings_getter
+                    .set_frame_rate_ref(#obs_settings_key, numerator, denominator);
+            },
+        ),
+        "array" => (
+            quote! {},
+            quote! { Vec<String> },
+            quote! {
+                #settings_getter
+                    .set_string_array_ref(#obs_settings_key, #field_name);
+            },
+        ),
+        other => {
+            return Err(Error::new(
+                type_t.span(),
+                format!(
+                    "Unsupported type_t `{}`. Should be one of {}",
+                    other, SUPPORTED_TYPES
+                ),
+            ))
         }
+    };
 
-        let (_docs_str, docs_attr) = collect_doc(&field.attrs);
+    let range = match (&min, &max, &step) {
+        (None, None, None) => None,
+        _ => {
+            let (number, getter) = match type_t.value().as_str() {
+                "int" => (quote! { i64 }, quote! { get_int }),
+                "double" | "float" => (quote! { f64 }, quote! { get_double }),
+                _ => {
+                    let given = min.as_ref().or(max.as_ref()).or(step.as_ref()).unwrap();
+                    return Err(Error::new(
+                        given.span(),
+                        "min, max and step are only supported for `int`, `double` and `float` properties",
+                    ));
+                }
+            };
+
+            let bound = |e: &Option<Expr>| match e {
+                Some(e) => quote! { Some((#e) as #number) },
+                None => quote! { None },
+            };
+            let (min, max, step) = (bound(&min), bound(&max), bound(&step));
 
-        let obs_settings_key = LitStr::new(&obs_settings_name, Span::call_site());
-        let set_field = quote::format_ident!("set_{}", field_name);
-        let type_t_str = type_t.as_str();
-        let to_add = match type_t_str {
-            "enum" => {
+            Some((
                 quote! {
-                    #(#docs_attr)*
-                    pub fn #set_field(mut self, #field_name: #field_type) -> Self {
-                        use num_traits::ToPrimitive;
-                        let val = #field_name.to_i32().unwrap();
+                    libobs_wrapper::data::ObsSettingRange::<#number> {
+                        min: #min,
+                        max: #max,
+                        step: #step,
+                    }
+                },
+                getter,
+            ))
+        }
+    };
 
-                        #settings_getter
-                            .set_int_ref(#obs_settings_key, val as i64);
+    let wrap_option = |param_type: &TokenStream, body: TokenStream| {
+        if !is_option {
+            return (param_type.clone(), body);
+        }
 
-                        self
+        (
+            quote! { Option<#param_type> },
+            quote! {
+                match #field_name {
+                    Some(#field_name) => { #body }
+                    None => {
+                        #settings_getter
+                            .unset_user_value_ref(#obs_settings_key);
                     }
                 }
-            }
-            "enum_string" => {
-                quote! {
-                    #(#docs_attr)*
-                    pub fn #set_field(mut self, #field_name: #field_type) -> Self {
-                        use libobs_wrapper::data::StringEnum;
-
-                        #settings_getter
-                            .set_string_ref(#obs_settings_key, #field_name.to_str());
+            },
+        )
+    };
 
-                        self
-                    }
+    match &range {
+        None => {
+            let (param_type, body) = wrap_option(&param_type, body);
+            functions.setters.push(quote! {
+                #(#docs_attr)*
+                pub fn #set_field #generics(mut self, #field_name: #param_type) -> Self {
+                    #body
+                    self
                 }
-            }
-            "string" => {
+            });
+        }
+        Some((range, getter)) => {
+            let (checked_type, checked_body) = wrap_option(
+                &param_type,
                 quote! {
-                    #(#docs_attr)*
-                    pub fn #set_field<T: Into<libobs_wrapper::utils::ObsString> + Sync + Send>(mut self, #field_name: T) -> Self {
-                        #settings_getter
-                            .set_string_ref(#obs_settings_key, #field_name);
-                        self
-                    }
-                }
-            }
-            "bool" => {
+                    #range.check(#obs_settings_key, #field_name)?;
+                    #body
+                },
+            );
+            let (clamped_type, clamped_body) = wrap_option(
+                &param_type,
                 quote! {
-                    #(#docs_attr)*
-                    pub fn #set_field(mut self, #field_name: bool) -> Self {
-                        #settings_getter
-                            .set_bool_ref(#obs_settings_key, #field_name);
-                        self
-                    }
+                    let #field_name = #range.clamp(#field_name);
+                    #body
+                },
+            );
+
+            let set_clamped = quote::format_ident!("set_{}_clamped", field_name);
+            let clamped_doc = format!(
+                " Same as `{}`, but moves the value into the allowed range instead of failing.",
+                set_field
+            );
+
+            functions.setters.push(quote! {
+                #(#docs_attr)*
+                ///
+                /// Returns an error if the value is outside of the allowed range.
+                pub fn #set_field(mut self, #field_name: #checked_type) -> Result<Self, libobs_wrapper::utils::ObsError> {
+                    #checked_body
+                    Ok(self)
                 }
-            }
-            "int" => {
-                quote! {
-                    #(#docs_attr)*
-                    pub fn #set_field(mut self, #field_name: i64) -> Self {
-                        #settings_getter
-                            .set_int_ref(#obs_settings_key, #field_name);
-                        self
-                    }
+
+                #[doc = #clamped_doc]
+                pub fn #set_clamped(mut self, #field_name: #clamped_type) -> Self {
+                    #clamped_body
+                    self
                 }
-            }
-            _ => panic!(
-                "Unsupported type_t {}. Should either be `enum`, `string`, `bool` or `int`",
-                type_t
-            ),
+            });
+
+            functions.checks.push(quote! {
+                if let Some(value) = self.settings.#getter(#obs_settings_key)? {
+                    #range.check(#obs_settings_key, value)?;
+                }
+            });
+        }
+    }
+
+    if let Some(default) = default {
+        let value = if is_option {
+            quote! { Some(#default) }
+        } else {
+            quote! { #default }
         };
 
-        functions.push(to_add);
+        let fallible = range.as_ref().map(|_| quote! { ? });
+        functions.defaults.push(quote! {
+            builder = builder.#set_field(#value)#fallible;
+        });
+    }
+
+    Ok(())
+}
+
+fn string_value(expr: &Expr) -> Option<LitStr> {
+    match expr {
+        Expr::Lit(e) => match &e.lit {
+            syn::Lit::Str(s) => Some(s.clone()),
+            _ => None,
+        },
+        _ => None,
     }
+}
+
+/// Returns `T` if the type is `Option<T>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(p) if p.qself.is_none() => &p.path,
+        _ => return None,
+    };
 
-    functions
+    let last = path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
 }
@@ -0,0 +1,6 @@
+/// Checks that invalid `obs_property` attributes are reported at the offending field
+#[test]
+pub fn test_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
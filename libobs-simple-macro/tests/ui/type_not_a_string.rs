@@ -0,0 +1,9 @@
+use libobs_simple_macro::obs_object_builder;
+
+#[obs_object_builder("test_source")]
+pub struct TestSourceBuilder {
+    #[obs_property(type_t = int)]
+    count: i64,
+}
+
+fn main() {}
@@ -0,0 +1,473 @@
+//! Generates builder and updater structs from the properties of loaded plugins
+//!
+//! Writing the `#[obs_object_builder]` structs of this crate by hand means
+//! they can drift from the settings the plugins actually use. The functions
+//! in this module read the property schema of an object type from libobs and
+//! emit a Rust module with a builder, an updater and an enum for every list
+//! property, documented with the descriptions and ranges of the properties.
+//!
+//! The generated code uses `libobs`, `libobs_simple_macro`, `log`,
+//! `num_derive` and `num_traits`, which have to be dependencies of the crate
+//! it is placed in.
+//!
+//! Number properties get the minimum and maximum of the plugin, so the
+//! generated setters reject values the plugin would not accept.
+//!
+//! List properties are turned into enums with the items that were available
+//! on the machine the code was generated on. For lists that are filled at
+//! runtime (e.g. devices or windows), the field should be changed to a plain
+//! `string` or `int` property afterwards.
+use std::{collections::HashMap, fmt::Write};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::properties::{
+        types::{ObsListItemValue, ObsListProperty, ObsNumberProperty},
+        ObsComboFormat, ObsComboType, ObsProperty, ObsPropertyObject, ObsTextType,
+    },
+    services::ObsServiceRef,
+    sources::ObsSourceRef,
+    utils::ObsError,
+};
+
+use crate::error::ObsSimpleError;
+
+mod naming;
+use naming::{field_ident, pascal_case, unique_variant};
+
+#[cfg(test)]
+mod naming_tests;
+
+/// The kind of object the code is generated for. It determines which type
+/// the generated updater updates and which builder trait is implemented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObsObjectKind {
+    Source,
+    Filter,
+    Transition,
+    Service,
+}
+
+impl ObsObjectKind {
+    fn updatable_type(&self) -> &'static str {
+        match self {
+            ObsObjectKind::Source | ObsObjectKind::Filter => "ObsSourceRef",
+            ObsObjectKind::Transition => "ObsTransitionRef",
+            ObsObjectKind::Service => "ObsServiceRef",
+        }
+    }
+
+    /// The `use` path of the updatable type and the builder trait, if any
+    fn imports(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            ObsObjectKind::Source => ("sources", Some("ObsSourceBuilder")),
+            ObsObjectKind::Filter => ("sources", None),
+            ObsObjectKind::Transition => ("transitions", Some("ObsTransitionBuilder")),
+            ObsObjectKind::Service => ("services", Some("ObsServiceBuilder")),
+        }
+    }
+}
+
+/// Reads the properties of the object type `id` and generates a module with
+/// `{struct_name}Builder` and `{struct_name}Updater` for it.
+pub fn generate_object_module(
+    context: &ObsContext,
+    kind: ObsObjectKind,
+    id: &str,
+    struct_name: &str,
+) -> Result<String, ObsSimpleError> {
+    let registered = match kind {
+        ObsObjectKind::Service => context
+            .available_service_types()?
+            .iter()
+            .any(|t| t.id == id),
+        _ => context.available_source_types()?.iter().any(|t| t.id == id),
+    };
+
+    if !registered {
+        return Err(ObsError::SourceNotAvailable(id.to_string()).into());
+    }
+
+    let properties = match kind {
+        ObsObjectKind::Service => ObsServiceRef::get_properties_by_id(id, context.runtime())?,
+        _ => ObsSourceRef::get_properties_by_id(id, context.runtime())?,
+    };
+
+    Ok(generate_from_properties(kind, id, struct_name, &properties))
+}
+
+/// Generates the module for already fetched properties, see `generate_object_module`.
+pub fn generate_from_properties(
+    kind: ObsObjectKind,
+    id: &str,
+    struct_name: &str,
+    properties: &HashMap<String, ObsProperty>,
+) -> String {
+    // Properties are stored in a map, sort them so the output is stable
+    let mut keys = properties.keys().collect::<Vec<_>>();
+    keys.sort_unstable();
+
+    let mut enums = String::new();
+    let mut fields = String::new();
+    let mut uses_int_enum = false;
+    let mut uses_string_enum = false;
+
+    for key in keys {
+        let field = match generate_field(struct_name, key, &properties[key]) {
+            GeneratedField::Field(field) => field,
+            GeneratedField::Skipped(reason) => {
+                let _ = writeln!(fields, "    // `{}` is not generated: {}", key, reason);
+                continue;
+            }
+        };
+
+        if let Some(list_enum) = &field.list_enum {
+            match list_enum.kind {
+                EnumKind::Int => uses_int_enum = true,
+                EnumKind::String => uses_string_enum = true,
+            }
+
+            enums.push_str(&list_enum.code);
+            enums.push('\n');
+        }
+
+        for doc in &field.docs {
+            let _ = writeln!(fields, "    /// {}", doc);
+        }
+
+        let settings_key = if field.name != *key {
+            format!(", settings_key = {:?}", key)
+        } else {
+            String::new()
+        };
+
+        let _ = writeln!(
+            fields,
+            "    #[obs_property(type_t = \"{}\"{}{})]",
+            field.type_t, settings_key, field.range
+        );
+        let _ = writeln!(fields, "    {}: {},", field.name, field.rust_type);
+        fields.push('\n');
+    }
+
+    let fields = fields.trim_end();
+    let updatable = kind.updatable_type();
+    let (module, builder_trait) = kind.imports();
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "//! Generated from the properties of `{}`, regenerate this file instead of editing it.",
+        id
+    );
+
+    let mut wrapper_imports = Vec::new();
+    if uses_string_enum {
+        wrapper_imports.push("data::StringEnum".to_string());
+    }
+    match builder_trait {
+        Some(t) => wrapper_imports.push(format!("{}::{{{}, {}}}", module, t, updatable)),
+        None => wrapper_imports.push(format!("{}::{}", module, updatable)),
+    }
+
+    if wrapper_imports.len() == 1 {
+        let _ = writeln!(out, "use libobs_wrapper::{};", wrapper_imports[0]);
+    } else {
+        let _ = writeln!(
+            out,
+            "use libobs_wrapper::{{{}}};",
+            wrapper_imports.join(", ")
+        );
+    }
+    if uses_int_enum {
+        let _ = writeln!(out, "use num_derive::{{FromPrimitive, ToPrimitive}};");
+    }
+    out.push('\n');
+    out.push_str(&enums);
+
+    for (attr, suffix, doc) in [
+        (
+            format!("obs_object_builder({:?})", id),
+            "Builder",
+            format!("Creates a new `{}`.", id),
+        ),
+        (
+            format!("obs_object_updater({:?}, {})", id, updatable),
+            "Updater",
+            format!("Updates the settings of an existing `{}`.", id),
+        ),
+    ] {
+        let _ = writeln!(out, "#[libobs_simple_macro::{}]", attr);
+        if suffix == "Builder" {
+            let _ = writeln!(out, "#[derive(Debug)]");
+        }
+        let _ = writeln!(out, "/// {}", doc);
+        let _ = writeln!(out, "pub struct {}{} {{", struct_name, suffix);
+        if !fields.is_empty() {
+            let _ = writeln!(out, "{}", fields);
+        }
+        let _ = writeln!(out, "}}\n");
+    }
+
+    if let Some(builder_trait) = builder_trait {
+        let _ = writeln!(
+            out,
+            "impl {} for {}Builder {{}}",
+            builder_trait, struct_name
+        );
+    }
+
+    // Drop the empty line after the last item
+    let len = out.trim_end().len();
+    out.truncate(len);
+    out.push('\n');
+
+    out
+}
+
+enum GeneratedField {
+    Field(Field),
+    Skipped(&'static str),
+}
+
+struct Field {
+    name: String,
+    type_t: &'static str,
+    rust_type: String,
+    docs: Vec<String>,
+    /// Extra `obs_property` arguments, e.g. `, min = 0, max = 10`
+    range: String,
+    list_enum: Option<ListEnum>,
+}
+
+enum EnumKind {
+    Int,
+    String,
+}
+
+struct ListEnum {
+    kind: EnumKind,
+    code: String,
+}
+
+fn generate_field(struct_name: &str, key: &str, property: &ObsProperty) -> GeneratedField {
+    let name = field_ident(key);
+    let field = |type_t, rust_type: &str, docs| {
+        GeneratedField::Field(Field {
+            name: name.clone(),
+            type_t,
+            rust_type: rust_type.to_string(),
+            docs,
+            range: String::new(),
+            list_enum: None,
+        })
+    };
+
+    match property {
+        ObsProperty::Bool => field("bool", "bool", Vec::new()),
+        ObsProperty::Int(number) => with_range(
+            field("int", "i64", number_docs(number)),
+            format!(", min = {}, max = {}", number.min(), number.max()),
+        ),
+        ObsProperty::Text(text) => {
+            if *text.text_type() == ObsTextType::Info {
+                return GeneratedField::Skipped("informational text, not a setting");
+            }
+
+            field("string", "String", description_docs(text.description()))
+        }
+        ObsProperty::Float(number) => {
+            let range = if number.min().is_finite() && number.max().is_finite() {
+                format!(", min = {:?}, max = {:?}", number.min(), number.max())
+            } else {
+                String::new()
+            };
+
+            with_range(field("double", "f64", number_docs(number)), range)
+        }
+        ObsProperty::Path(path) => {
+            let mut docs = description_docs(path.description());
+            if !path.filter().is_empty() {
+                docs.push(format!("Filter: `{}`", path.filter()));
+            }
+
+            field("path", "libobs_wrapper::utils::ObsPath", docs)
+        }
+        ObsProperty::Color(color) => {
+            let mut docs = description_docs(color.description());
+            docs.push("Color in the `0xAARRGGBB` format, the alpha is ignored".to_string());
+
+            field("color", "u32", docs)
+        }
+        ObsProperty::ColorAlpha(color) => {
+            let mut docs = description_docs(color.description());
+            docs.push("Color with alpha in the `0xAARRGGBB` format".to_string());
+
+            field("color", "u32", docs)
+        }
+        ObsProperty::Font(font) => field(
+            "font",
+            "libobs_wrapper::data::ObsFont",
+            description_docs(font.description()),
+        ),
+        ObsProperty::FrameRate(frame_rate) => {
+            let mut docs = description_docs(frame_rate.description());
+            docs.push("Frame rate as `(numerator, denominator)`".to_string());
+
+            field("frame_rate", "(u32, u32)", docs)
+        }
+        ObsProperty::EditableList(list) => {
+            let mut docs = description_docs(list.description());
+            if !list.filter().is_empty() {
+                docs.push(format!("Filter: `{}`", list.filter()));
+            }
+
+            field("array", "Vec<String>", docs)
+        }
+        ObsProperty::List(list) => generate_list_field(struct_name, name, list),
+        ObsProperty::Button(_) => GeneratedField::Skipped("buttons are not settings"),
+        ObsProperty::Group(_) => GeneratedField::Skipped("groups are not settings"),
+        ObsProperty::Invalid => GeneratedField::Skipped("the property is invalid"),
+    }
+}
+
+/// The step is only documented, plugins accept values between the steps
+fn with_range(field: GeneratedField, range: String) -> GeneratedField {
+    match field {
+        GeneratedField::Field(field) => GeneratedField::Field(Field { range, ..field }),
+        skipped => skipped,
+    }
+}
+
+fn generate_list_field(struct_name: &str, name: String, list: &ObsListProperty) -> GeneratedField {
+    let docs = description_docs(list.description());
+
+    // Editable combo boxes accept any value, so an enum would be too strict
+    let editable = *list.list_type() == ObsComboType::Editable;
+    let (type_t, rust_type) = match list.format() {
+        ObsComboFormat::Int => ("int", "i64"),
+        ObsComboFormat::String => ("string", "String"),
+        ObsComboFormat::Bool => ("bool", "bool"),
+        ObsComboFormat::Float => {
+            return GeneratedField::Skipped("float lists are not supported");
+        }
+        ObsComboFormat::Invalid => return GeneratedField::Skipped("the list format is invalid"),
+    };
+
+    let plain = || {
+        GeneratedField::Field(Field {
+            name: name.clone(),
+            type_t,
+            rust_type: rust_type.to_string(),
+            docs: docs.clone(),
+            range: String::new(),
+            list_enum: None,
+        })
+    };
+
+    if editable || list.items().is_empty() {
+        return plain();
+    }
+
+    let enum_name = format!("{}{}", struct_name, pascal_case(&name));
+    let mut variants = Vec::new();
+    for item in list.items() {
+        let variant = unique_variant(&variants, item.name());
+        let value = match item.value() {
+            ObsListItemValue::Int(v) => v.to_string(),
+            ObsListItemValue::String(v) => format!("{:?}", v),
+            _ => return plain(),
+        };
+
+        variants.push((variant, item.name().clone(), value));
+    }
+
+    let mut code = String::new();
+    if let Some(description) = list.description() {
+        let _ = writeln!(code, "/// {}", doc_line(description));
+    }
+
+    let kind = match list.format() {
+        ObsComboFormat::Int => {
+            let _ = writeln!(code, "#[repr(i64)]");
+            let _ = writeln!(
+                code,
+                "#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]"
+            );
+            let _ = writeln!(code, "pub enum {} {{", enum_name);
+            for (variant, display, value) in &variants {
+                let _ = writeln!(code, "    /// {}", doc_line(display));
+                let _ = writeln!(code, "    {} = {},", variant, value);
+            }
+            let _ = writeln!(code, "}}");
+
+            EnumKind::Int
+        }
+        ObsComboFormat::String => {
+            let _ = writeln!(code, "#[derive(Clone, Copy, Debug, PartialEq, Eq)]");
+            let _ = writeln!(code, "pub enum {} {{", enum_name);
+            for (variant, display, _) in &variants {
+                let _ = writeln!(code, "    /// {}", doc_line(display));
+                let _ = writeln!(code, "    {},", variant);
+            }
+            let _ = writeln!(code, "}}\n");
+
+            let _ = writeln!(code, "impl StringEnum for {} {{", enum_name);
+            let _ = writeln!(code, "    fn to_str(&self) -> &str {{");
+            let _ = writeln!(code, "        match self {{");
+            for (variant, _, value) in &variants {
+                let _ = writeln!(code, "            {}::{} => {},", enum_name, variant, value);
+            }
+            let _ = writeln!(code, "        }}");
+            let _ = writeln!(code, "    }}");
+            let _ = writeln!(code, "}}");
+
+            EnumKind::String
+        }
+        _ => return plain(),
+    };
+
+    GeneratedField::Field(Field {
+        name,
+        type_t: match kind {
+            EnumKind::Int => "enum",
+            EnumKind::String => "enum_string",
+        },
+        rust_type: enum_name,
+        docs,
+        range: String::new(),
+        list_enum: Some(ListEnum { kind, code }),
+    })
+}
+
+fn number_docs<T>(number: &ObsNumberProperty<T>) -> Vec<String>
+where
+    T: Clone + Copy + std::fmt::Debug + std::fmt::Display,
+{
+    let mut docs = description_docs(number.description());
+    let suffix = number.suffix().trim();
+    let suffix = if suffix.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", suffix)
+    };
+
+    docs.push(format!(
+        "Range: `{}` to `{}`, step `{}`{}",
+        number.min(),
+        number.max(),
+        number.step(),
+        suffix
+    ));
+
+    docs
+}
+
+fn description_docs(description: &Option<String>) -> Vec<String> {
+    description.iter().map(|d| doc_line(d)).collect()
+}
+
+/// Descriptions may contain line breaks, which would end the doc comment
+fn doc_line(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
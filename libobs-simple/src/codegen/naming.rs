@@ -0,0 +1,70 @@
+//! Conversion of settings keys and list items into Rust identifiers
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override",
+    "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Turns a settings key into a valid snake case field name.
+pub(super) fn field_ident(key: &str) -> String {
+    let mut ident = String::new();
+    let mut prev_lower = false;
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && prev_lower {
+                ident.push('_');
+            }
+
+            prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+            ident.push(c.to_ascii_lowercase());
+        } else {
+            if !ident.ends_with('_') {
+                ident.push('_');
+            }
+            prev_lower = false;
+        }
+    }
+
+    let mut ident = ident.trim_matches('_').to_string();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert_str(0, "field_");
+    }
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+
+    ident
+}
+
+pub(super) fn pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+/// Makes a variant name out of the display name of a list item that
+/// differs from the already generated `existing` variants.
+pub(super) fn unique_variant(existing: &[(String, String, String)], display: &str) -> String {
+    let mut variant = pascal_case(display);
+    if variant.is_empty() || variant.starts_with(|c: char| c.is_ascii_digit()) {
+        variant.insert_str(0, "Value");
+    }
+
+    let base = variant.clone();
+    let mut i = 2;
+    while existing.iter().any(|(v, _, _)| *v == variant) {
+        variant = format!("{}{}", base, i);
+        i += 1;
+    }
+
+    variant
+}
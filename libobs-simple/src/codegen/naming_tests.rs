@@ -0,0 +1,30 @@
+use super::naming::{field_ident, pascal_case, unique_variant};
+
+#[test]
+fn test_field_ident() {
+    assert_eq!(field_ident("device_id"), "device_id");
+    assert_eq!(field_ident("ColorRange"), "color_range");
+    assert_eq!(field_ident("keyframe-sec"), "keyframe_sec");
+    assert_eq!(field_ident("type"), "type_");
+    assert_eq!(field_ident("3d_mode"), "field_3d_mode");
+    assert_eq!(field_ident("__"), "field_");
+}
+
+#[test]
+fn test_pascal_case() {
+    assert_eq!(pascal_case("color_range"), "ColorRange");
+    assert_eq!(pascal_case("Rec. 709"), "Rec709");
+    assert_eq!(pascal_case("sRGB"), "SRGB");
+}
+
+#[test]
+fn test_unique_variant() {
+    let mut existing = Vec::new();
+    for display in ["Default", "Default", "720p", ""] {
+        let variant = unique_variant(&existing, display);
+        existing.push((variant, display.to_string(), String::new()));
+    }
+
+    let variants = existing.into_iter().map(|(v, _, _)| v).collect::<Vec<_>>();
+    assert_eq!(variants, ["Default", "Default2", "Value720p", "Value"]);
+}
@@ -1,9 +1,12 @@
 #![cfg_attr(doc, feature(doc_cfg))]
 //! A simplified interface for recording and streaming with libobs
 
+pub mod codegen;
 pub mod error;
 pub mod output;
+pub mod services;
 pub mod sources;
+pub mod transitions;
 
 pub use error::ObsSimpleError;
 pub use libobs_wrapper as wrapper;
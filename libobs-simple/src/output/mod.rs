@@ -1 +1,2 @@
 pub mod simple;
+pub mod streaming;
@@ -39,9 +39,12 @@ use libobs_wrapper::{
     context::ObsContext,
     data::{output::ObsOutputRef, ObsData},
     encoders::{ObsAudioEncoderType, ObsContextEncoders, ObsVideoEncoderType},
+    services::ObsServiceRef,
     utils::{AudioEncoderInfo, ObsError, ObsPath, ObsString, OutputInfo, VideoEncoderInfo},
 };
 
+use super::streaming::StreamingOutputBuilder;
+
 /// Preset for x264 software encoder
 #[derive(Debug, Clone, Copy)]
 pub enum X264Preset {
@@ -164,6 +167,8 @@ pub struct OutputSettings {
     audio_bitrate: u32,
     video_encoder: VideoEncoder,
     audio_encoder: AudioEncoder,
+    /// Mixers that are recorded, each as its own audio track
+    audio_tracks: Vec<usize>,
     custom_encoder_settings: Option<String>,
     path: ObsPath,
     format: OutputFormat,
@@ -231,6 +236,16 @@ impl OutputSettings {
         self.audio_encoder = encoder;
         self
     }
+
+    /// Sets the mixers (0 to 5) that are recorded. Every mixer is written as its
+    /// own audio track, in the given order. Defaults to only the first mixer.
+    ///
+    /// Route sources to mixers with `ObsSourceRef::set_audio_mixers`. FLV only
+    /// supports a single audio track.
+    pub fn with_audio_tracks<I: IntoIterator<Item = usize>>(mut self, mixers: I) -> Self {
+        self.audio_tracks = mixers.into_iter().collect();
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -245,6 +260,13 @@ pub trait ObsContextSimpleExt {
         name: T,
         path: K,
     ) -> SimpleOutputBuilder;
+
+    /// Creates a builder for an output that streams to the given service.
+    fn streaming_output_builder<T: Into<ObsString>>(
+        &self,
+        name: T,
+        service: ObsServiceRef,
+    ) -> StreamingOutputBuilder;
 }
 
 impl ObsContextSimpleExt for ObsContext {
@@ -255,6 +277,14 @@ impl ObsContextSimpleExt for ObsContext {
     ) -> SimpleOutputBuilder {
         SimpleOutputBuilder::new(self.clone(), name, path)
     }
+
+    fn streaming_output_builder<T: Into<ObsString>>(
+        &self,
+        name: T,
+        service: ObsServiceRef,
+    ) -> StreamingOutputBuilder {
+        StreamingOutputBuilder::new(self.clone(), name, service)
+    }
 }
 
 impl SimpleOutputBuilder {
@@ -270,6 +300,7 @@ impl SimpleOutputBuilder {
                 audio_bitrate: 160,
                 video_encoder: VideoEncoder::X264(X264Preset::VeryFast),
                 audio_encoder: AudioEncoder::AAC,
+                audio_tracks: vec![0],
                 custom_encoder_settings: None,
                 path: path.into(),
                 format: OutputFormat::default(),
@@ -298,6 +329,12 @@ impl SimpleOutputBuilder {
         self
     }
 
+    /// Sets the mixers that are recorded, see `OutputSettings::with_audio_tracks`.
+    pub fn audio_tracks<I: IntoIterator<Item = usize>>(mut self, mixers: I) -> Self {
+        self.settings.audio_tracks = mixers.into_iter().collect();
+        self
+    }
+
     /// Sets the output path.
     pub fn path<P: Into<ObsPath>>(mut self, path: P) -> Self {
         self.settings.path = path.into();
@@ -324,6 +361,30 @@ impl SimpleOutputBuilder {
 
     /// Builds and returns the configured output.
     pub fn build(mut self) -> Result<ObsOutputRef, ObsError> {
+        if self.settings.audio_tracks.is_empty() {
+            return Err(ObsError::InvalidOperation(
+                "At least one audio track has to be recorded".to_string(),
+            ));
+        }
+
+        // Checked before anything is created, so an invalid mixer doesn't leave an output behind
+        for (i, mixer_idx) in self.settings.audio_tracks.iter().enumerate() {
+            if *mixer_idx >= libobs::MAX_AUDIO_MIXES as usize {
+                return Err(ObsError::InvalidOperation(format!(
+                    "Mixer {} is out of bounds (max {})",
+                    mixer_idx,
+                    libobs::MAX_AUDIO_MIXES - 1
+                )));
+            }
+
+            if self.settings.audio_tracks[..i].contains(mixer_idx) {
+                return Err(ObsError::InvalidOperation(format!(
+                    "Mixer {} is recorded more than once",
+                    mixer_idx
+                )));
+            }
+        }
+
         // Determine the output type based on format
         let output_id = match self.settings.format {
             OutputFormat::HybridMP4 => "mp4_output",
@@ -350,10 +411,16 @@ impl SimpleOutputBuilder {
         let mut output = self.context.output(output_info)?;
 
         // Create and configure video encoder (with hardware fallback)
-        let video_encoder_type = self.select_video_encoder_type(&self.settings.video_encoder)?;
+        let video_encoder_type =
+            select_video_encoder_type(&self.context, &self.settings.video_encoder)?;
         let mut video_settings = self.context.data()?;
 
-        self.configure_video_encoder(&mut video_settings)?;
+        configure_video_encoder(
+            &mut video_settings,
+            &self.settings.video_encoder,
+            self.settings.video_bitrate,
+            self.settings.custom_encoder_settings.as_deref(),
+        )?;
 
         let video_encoder_info = VideoEncoderInfo::new(
             video_encoder_type,
@@ -364,110 +431,136 @@ impl SimpleOutputBuilder {
 
         output.create_and_set_video_encoder(video_encoder_info)?;
 
-        // Create and configure audio encoder
-        let audio_encoder_type = match &self.settings.audio_encoder {
-            AudioEncoder::AAC => ObsAudioEncoderType::FFMPEG_AAC,
-            AudioEncoder::Opus => ObsAudioEncoderType::FFMPEG_OPUS,
-            AudioEncoder::Custom(encoder_type) => encoder_type.clone(),
-        };
-
+        // Create and configure one audio encoder per track
+        let audio_encoder_type = audio_encoder_type(&self.settings.audio_encoder);
         log::trace!("Selected audio encoder: {:?}", audio_encoder_type);
-        let mut audio_settings = self.context.data()?;
-        audio_settings.set_string("rate_control", "CBR")?;
-        audio_settings.set_int("bitrate", self.settings.audio_bitrate as i64)?;
-
-        let audio_encoder_info = AudioEncoderInfo::new(
-            audio_encoder_type,
-            "simple_audio",
-            Some(audio_settings),
-            None,
-        );
 
-        log::trace!("Creating audio encoder with info: {:?}", audio_encoder_info);
-        output.create_and_set_audio_encoder(audio_encoder_info, 0)?;
+        let single_track = self.settings.audio_tracks.len() == 1;
+        for (track_idx, mixer_idx) in self.settings.audio_tracks.iter().copied().enumerate() {
+            let mut audio_settings = self.context.data()?;
+            configure_audio_encoder(&mut audio_settings, self.settings.audio_bitrate)?;
+
+            let name = if single_track {
+                "simple_audio".to_string()
+            } else {
+                format!("simple_audio_track{}", mixer_idx + 1)
+            };
+
+            let audio_encoder_info =
+                AudioEncoderInfo::new(audio_encoder_type.clone(), name, Some(audio_settings), None);
+
+            log::trace!("Creating audio encoder with info: {:?}", audio_encoder_info);
+            output.create_and_set_audio_track(audio_encoder_info, mixer_idx, track_idx)?;
+        }
 
         Ok(output)
     }
+}
 
-    fn select_video_encoder_type(
-        &self,
-        encoder: &VideoEncoder,
-    ) -> Result<ObsVideoEncoderType, ObsError> {
-        match encoder {
-            VideoEncoder::X264(_) => Ok(ObsVideoEncoderType::OBS_X264),
-            VideoEncoder::Custom(t) => Ok(t.clone()),
-            VideoEncoder::Hardware { codec, .. } => {
-                // Build preferred candidates for the requested codec
-                let candidates = self.hardware_candidates(*codec);
-                // Query available encoders
-                let available = self
-                    .context
-                    .available_video_encoders()?
-                    .into_iter()
-                    .map(|b| b.get_encoder_id().clone())
-                    .collect::<Vec<_>>();
-                // Pick first preferred candidate that is available
-                for cand in candidates {
-                    if available.iter().any(|a| a == &cand) {
-                        return Ok(cand);
-                    }
+/// Picks the encoder type for the given configuration, hardware encoders
+/// fall back to x264 if none of the candidates is available.
+pub(super) fn select_video_encoder_type(
+    context: &ObsContext,
+    encoder: &VideoEncoder,
+) -> Result<ObsVideoEncoderType, ObsError> {
+    match encoder {
+        VideoEncoder::X264(_) => Ok(ObsVideoEncoderType::OBS_X264),
+        VideoEncoder::Custom(t) => Ok(t.clone()),
+        VideoEncoder::Hardware { codec, .. } => {
+            // Build preferred candidates for the requested codec
+            let candidates = hardware_candidates(*codec);
+            // Query available encoders
+            let available = context
+                .available_video_encoders()?
+                .into_iter()
+                .map(|b| b.get_encoder_id().clone())
+                .collect::<Vec<_>>();
+            // Pick first preferred candidate that is available
+            for cand in candidates {
+                if available.iter().any(|a| a == &cand) {
+                    return Ok(cand);
                 }
-                // Fallback to x264 if no hardware encoder is available
-                Ok(ObsVideoEncoderType::OBS_X264)
             }
+            // Fallback to x264 if no hardware encoder is available
+            Ok(ObsVideoEncoderType::OBS_X264)
         }
     }
+}
 
-    fn hardware_candidates(&self, codec: HardwareCodec) -> Vec<ObsVideoEncoderType> {
-        match codec {
-            HardwareCodec::H264 => vec![
-                ObsVideoEncoderType::OBS_NVENC_H264_TEX,
-                ObsVideoEncoderType::H264_TEXTURE_AMF,
-                ObsVideoEncoderType::OBS_QSV11_V2,
-                // software fallbacks for vendor SDKs
-                ObsVideoEncoderType::OBS_NVENC_H264_SOFT,
-                ObsVideoEncoderType::OBS_QSV11_SOFT_V2,
-            ],
-            HardwareCodec::HEVC => vec![
-                ObsVideoEncoderType::OBS_NVENC_HEVC_TEX,
-                ObsVideoEncoderType::H265_TEXTURE_AMF,
-                ObsVideoEncoderType::OBS_QSV11_HEVC,
-                ObsVideoEncoderType::OBS_NVENC_HEVC_SOFT,
-                ObsVideoEncoderType::OBS_QSV11_HEVC_SOFT,
-            ],
-            HardwareCodec::AV1 => vec![
-                ObsVideoEncoderType::OBS_NVENC_AV1_TEX,
-                ObsVideoEncoderType::AV1_TEXTURE_AMF,
-                ObsVideoEncoderType::OBS_QSV11_AV1,
-                ObsVideoEncoderType::OBS_NVENC_AV1_SOFT,
-                ObsVideoEncoderType::OBS_QSV11_AV1_SOFT,
-            ],
-        }
+fn hardware_candidates(codec: HardwareCodec) -> Vec<ObsVideoEncoderType> {
+    match codec {
+        HardwareCodec::H264 => vec![
+            ObsVideoEncoderType::OBS_NVENC_H264_TEX,
+            ObsVideoEncoderType::H264_TEXTURE_AMF,
+            ObsVideoEncoderType::OBS_QSV11_V2,
+            // software fallbacks for vendor SDKs
+            ObsVideoEncoderType::OBS_NVENC_H264_SOFT,
+            ObsVideoEncoderType::OBS_QSV11_SOFT_V2,
+        ],
+        HardwareCodec::HEVC => vec![
+            ObsVideoEncoderType::OBS_NVENC_HEVC_TEX,
+            ObsVideoEncoderType::H265_TEXTURE_AMF,
+            ObsVideoEncoderType::OBS_QSV11_HEVC,
+            ObsVideoEncoderType::OBS_NVENC_HEVC_SOFT,
+            ObsVideoEncoderType::OBS_QSV11_HEVC_SOFT,
+        ],
+        HardwareCodec::AV1 => vec![
+            ObsVideoEncoderType::OBS_NVENC_AV1_TEX,
+            ObsVideoEncoderType::AV1_TEXTURE_AMF,
+            ObsVideoEncoderType::OBS_QSV11_AV1,
+            ObsVideoEncoderType::OBS_NVENC_AV1_SOFT,
+            ObsVideoEncoderType::OBS_QSV11_AV1_SOFT,
+        ],
     }
+}
 
-    fn get_encoder_preset(&self, encoder: &VideoEncoder) -> Option<&str> {
-        match encoder {
-            VideoEncoder::X264(preset) => Some(preset.as_str()),
-            VideoEncoder::Hardware { preset, .. } => Some(preset.as_str()),
-            VideoEncoder::Custom(_) => None,
-        }
+fn get_encoder_preset(encoder: &VideoEncoder) -> Option<&str> {
+    match encoder {
+        VideoEncoder::X264(preset) => Some(preset.as_str()),
+        VideoEncoder::Hardware { preset, .. } => Some(preset.as_str()),
+        VideoEncoder::Custom(_) => None,
     }
+}
 
-    fn configure_video_encoder(&self, settings: &mut ObsData) -> Result<(), ObsError> {
-        // Set rate control to CBR
-        settings.set_string("rate_control", "CBR")?;
-        settings.set_int("bitrate", self.settings.video_bitrate as i64)?;
+/// Sets CBR rate control with the given bitrate (in Kbps) and the preset of the encoder.
+pub(super) fn configure_video_encoder(
+    settings: &mut ObsData,
+    encoder: &VideoEncoder,
+    bitrate: u32,
+    custom_encoder_settings: Option<&str>,
+) -> Result<(), ObsError> {
+    // Set rate control to CBR
+    settings.set_string("rate_control", "CBR")?;
+    settings.set_int("bitrate", bitrate as i64)?;
+
+    // Set preset if available
+    if let Some(preset) = get_encoder_preset(encoder) {
+        settings.set_string("preset", preset)?;
+    }
 
-        // Set preset if available
-        if let Some(preset) = self.get_encoder_preset(&self.settings.video_encoder) {
-            settings.set_string("preset", preset)?;
-        }
+    // Apply custom encoder settings if provided (mainly for x264)
+    if let Some(custom) = custom_encoder_settings {
+        settings.set_string("x264opts", custom)?;
+    }
 
-        // Apply custom encoder settings if provided (mainly for x264)
-        if let Some(ref custom) = self.settings.custom_encoder_settings {
-            settings.set_string("x264opts", custom.as_str())?;
-        }
+    Ok(())
+}
 
-        Ok(())
+pub(super) fn audio_encoder_type(encoder: &AudioEncoder) -> ObsAudioEncoderType {
+    match encoder {
+        AudioEncoder::AAC => ObsAudioEncoderType::FFMPEG_AAC,
+        AudioEncoder::Opus => ObsAudioEncoderType::FFMPEG_OPUS,
+        AudioEncoder::Custom(encoder_type) => encoder_type.clone(),
     }
 }
+
+/// Sets CBR rate control with the given bitrate in Kbps.
+pub(super) fn configure_audio_encoder(
+    settings: &mut ObsData,
+    bitrate: u32,
+) -> Result<(), ObsError> {
+    settings.set_string("rate_control", "CBR")?;
+    settings.set_int("bitrate", bitrate as i64)?;
+
+    Ok(())
+}
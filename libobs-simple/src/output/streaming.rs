@@ -0,0 +1,298 @@
+//! Streaming output builder for OBS.
+//!
+//! This module mirrors `SimpleOutputBuilder`, but instead of writing to a file
+//! the output sends its data to a streaming service (RTMP, SRT, RIST or WHIP).
+//! The output type is chosen from the protocol of the service.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use libobs_simple::output::simple::ObsContextSimpleExt;
+//! use libobs_simple::services::RtmpCustomServiceBuilder;
+//! use libobs_wrapper::{context::ObsContext, services::ObsServiceBuilder, utils::StartupInfo};
+//!
+//! let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+//! let service = context
+//!     .service_builder::<RtmpCustomServiceBuilder, _>("stream_service")
+//!     .unwrap()
+//!     .set_server("rtmp://127.0.0.1/live")
+//!     .set_key("stream_key")
+//!     .add_to_context(&mut context)
+//!     .unwrap();
+//!
+//! let output = context
+//!     .streaming_output_builder("stream_output", service)
+//!     .video_bitrate(4500)
+//!     .build()
+//!     .unwrap();
+//!
+//! output.start().unwrap();
+//! ```
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::output::ObsOutputRef,
+    encoders::ObsVideoEncoderType,
+    services::ObsServiceRef,
+    utils::{AudioEncoderInfo, ObsError, ObsString, OutputInfo, VideoEncoderInfo},
+};
+
+use super::simple::{
+    audio_encoder_type, configure_audio_encoder, configure_video_encoder,
+    select_video_encoder_type, AudioEncoder, HardwareCodec, HardwarePreset, VideoEncoder,
+    X264Preset,
+};
+
+/// Returns the output type that OBS Studio uses for the given service protocol.
+fn output_type_for_protocol(protocol: &str) -> Option<&'static str> {
+    match protocol {
+        "RTMP" | "RTMPS" => Some("rtmp_output"),
+        "SRT" | "RIST" => Some("ffmpeg_mpegts_muxer"),
+        "WHIP" => Some("whip_output"),
+        _ => None,
+    }
+}
+
+/// Settings of a streaming output
+#[derive(Debug)]
+pub struct StreamingOutputSettings {
+    name: ObsString,
+    video_bitrate: u32,
+    audio_bitrate: u32,
+    video_encoder: VideoEncoder,
+    /// `None` picks Opus for WHIP and AAC for every other protocol
+    audio_encoder: Option<AudioEncoder>,
+    custom_encoder_settings: Option<String>,
+    keyframe_interval_sec: u32,
+    output_type: Option<String>,
+    apply_service_settings: bool,
+}
+
+impl StreamingOutputSettings {
+    /// Creates settings for an output with the given name, using x264 at 2500 Kbps
+    /// and the audio encoder that fits the protocol of the service.
+    pub fn new<T: Into<ObsString>>(name: T) -> Self {
+        StreamingOutputSettings {
+            name: name.into(),
+            video_bitrate: 2500,
+            audio_bitrate: 160,
+            video_encoder: VideoEncoder::X264(X264Preset::VeryFast),
+            audio_encoder: None,
+            custom_encoder_settings: None,
+            keyframe_interval_sec: 2,
+            output_type: None,
+            apply_service_settings: true,
+        }
+    }
+
+    /// Sets the video bitrate in Kbps.
+    pub fn with_video_bitrate(mut self, bitrate: u32) -> Self {
+        self.video_bitrate = bitrate;
+        self
+    }
+
+    /// Sets the audio bitrate in Kbps.
+    pub fn with_audio_bitrate(mut self, bitrate: u32) -> Self {
+        self.audio_bitrate = bitrate;
+        self
+    }
+
+    /// Sets the video encoder to use x264 software encoding.
+    pub fn with_x264_encoder(mut self, preset: X264Preset) -> Self {
+        self.video_encoder = VideoEncoder::X264(preset);
+        self
+    }
+
+    /// Sets the video encoder to use a generic hardware encoder for the given codec.
+    /// The builder will choose an available backend (NVENC/AMF/QSV) at runtime.
+    pub fn with_hardware_encoder(mut self, codec: HardwareCodec, preset: HardwarePreset) -> Self {
+        self.video_encoder = VideoEncoder::Hardware { codec, preset };
+        self
+    }
+
+    /// Sets a custom video encoder.
+    pub fn with_custom_video_encoder(mut self, encoder: ObsVideoEncoderType) -> Self {
+        self.video_encoder = VideoEncoder::Custom(encoder);
+        self
+    }
+
+    /// Sets custom x264 encoder settings.
+    pub fn with_custom_settings<S: Into<String>>(mut self, settings: S) -> Self {
+        self.custom_encoder_settings = Some(settings.into());
+        self
+    }
+
+    /// Sets the audio encoder.
+    pub fn with_audio_encoder(mut self, encoder: AudioEncoder) -> Self {
+        self.audio_encoder = Some(encoder);
+        self
+    }
+
+    /// Sets the keyframe interval in seconds, 0 lets the encoder decide.
+    pub fn with_keyframe_interval(mut self, seconds: u32) -> Self {
+        self.keyframe_interval_sec = seconds;
+        self
+    }
+
+    /// Overrides the output type that would be chosen from the protocol of the service.
+    pub fn with_output_type<S: Into<String>>(mut self, output_type: S) -> Self {
+        self.output_type = Some(output_type.into());
+        self
+    }
+
+    /// Whether the service may adjust the encoder settings to its limits
+    /// (e.g. the maximum bitrate of a platform). Enabled by default.
+    pub fn with_apply_service_settings(mut self, apply: bool) -> Self {
+        self.apply_service_settings = apply;
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct StreamingOutputBuilder {
+    settings: StreamingOutputSettings,
+    service: ObsServiceRef,
+    context: ObsContext,
+}
+
+impl StreamingOutputBuilder {
+    /// Creates a new StreamingOutputBuilder with default settings.
+    pub fn new<T: Into<ObsString>>(context: ObsContext, name: T, service: ObsServiceRef) -> Self {
+        StreamingOutputBuilder {
+            settings: StreamingOutputSettings::new(name),
+            service,
+            context,
+        }
+    }
+
+    /// Sets the output settings.
+    pub fn settings(mut self, settings: StreamingOutputSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Sets the video bitrate in Kbps.
+    pub fn video_bitrate(mut self, bitrate: u32) -> Self {
+        self.settings.video_bitrate = bitrate;
+        self
+    }
+
+    /// Sets the audio bitrate in Kbps.
+    pub fn audio_bitrate(mut self, bitrate: u32) -> Self {
+        self.settings.audio_bitrate = bitrate;
+        self
+    }
+
+    /// Sets the video encoder to x264.
+    pub fn x264_encoder(mut self, preset: X264Preset) -> Self {
+        self.settings.video_encoder = VideoEncoder::X264(preset);
+        self
+    }
+
+    /// Sets the video encoder to a generic hardware encoder.
+    pub fn hardware_encoder(mut self, codec: HardwareCodec, preset: HardwarePreset) -> Self {
+        self.settings.video_encoder = VideoEncoder::Hardware { codec, preset };
+        self
+    }
+
+    /// Sets the audio encoder.
+    pub fn audio_encoder(mut self, encoder: AudioEncoder) -> Self {
+        self.settings.audio_encoder = Some(encoder);
+        self
+    }
+
+    /// Sets the keyframe interval in seconds.
+    pub fn keyframe_interval(mut self, seconds: u32) -> Self {
+        self.settings.keyframe_interval_sec = seconds;
+        self
+    }
+
+    /// Sets the service the output streams to.
+    pub fn service(mut self, service: ObsServiceRef) -> Self {
+        self.service = service;
+        self
+    }
+
+    /// Builds and returns the configured output with the service attached.
+    pub fn build(mut self) -> Result<ObsOutputRef, ObsError> {
+        let protocol = self.service.protocol()?.unwrap_or_default();
+
+        // Same order as OBS Studio: the preference of the service, then the protocol
+        let output_id = match self.settings.output_type.take() {
+            Some(output_type) => output_type,
+            None => match self.service.preferred_output_type()? {
+                Some(output_type) => output_type,
+                None => output_type_for_protocol(&protocol)
+                    .ok_or_else(|| {
+                        ObsError::InvalidOperation(format!(
+                            "No output type is known for the protocol '{}' of service {}",
+                            protocol,
+                            self.service.name()
+                        ))
+                    })?
+                    .to_string(),
+            },
+        };
+
+        log::trace!(
+            "Creating streaming output {} for protocol {}",
+            output_id,
+            protocol
+        );
+
+        let output_info = OutputInfo::new(output_id, self.settings.name.clone(), None, None);
+        let mut output = self.context.output(output_info)?;
+
+        // Create and configure video encoder (with hardware fallback)
+        let video_encoder_type =
+            select_video_encoder_type(&self.context, &self.settings.video_encoder)?;
+        let mut video_settings = self.context.data()?;
+
+        configure_video_encoder(
+            &mut video_settings,
+            &self.settings.video_encoder,
+            self.settings.video_bitrate,
+            self.settings.custom_encoder_settings.as_deref(),
+        )?;
+        video_settings.set_int("keyint_sec", self.settings.keyframe_interval_sec as i64)?;
+
+        // WebRTC only supports Opus
+        let audio_encoder = match &self.settings.audio_encoder {
+            Some(encoder) => encoder.clone(),
+            None if protocol == "WHIP" => AudioEncoder::Opus,
+            None => AudioEncoder::AAC,
+        };
+
+        let audio_encoder_type = audio_encoder_type(&audio_encoder);
+        log::trace!("Selected audio encoder: {:?}", audio_encoder_type);
+
+        let mut audio_settings = self.context.data()?;
+        configure_audio_encoder(&mut audio_settings, self.settings.audio_bitrate)?;
+
+        if self.settings.apply_service_settings {
+            self.service
+                .apply_encoder_settings(Some(&mut video_settings), Some(&mut audio_settings))?;
+        }
+
+        let video_encoder_info = VideoEncoderInfo::new(
+            video_encoder_type,
+            "streaming_video",
+            Some(video_settings),
+            None,
+        );
+
+        output.create_and_set_video_encoder(video_encoder_info)?;
+
+        let audio_encoder_info = AudioEncoderInfo::new(
+            audio_encoder_type,
+            "streaming_audio",
+            Some(audio_settings),
+            None,
+        );
+
+        output.create_and_set_audio_encoder(audio_encoder_info, 0)?;
+        output.set_service(self.service)?;
+
+        Ok(output)
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! Builders for the streaming services that ship with OBS (the `rtmp-services`
+//! and `obs-webrtc` plugins).
+//!
+//! A service is created using `ObsContext::service_builder` and is then used by a
+//! streaming output, for example one that is created by `StreamingOutputBuilder`:
+//!
+//! ```no_run
+//! use libobs_simple::services::RtmpCustomServiceBuilder;
+//! use libobs_wrapper::{context::ObsContext, services::ObsServiceBuilder, utils::StartupInfo};
+//!
+//! let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+//! let service = context
+//!     .service_builder::<RtmpCustomServiceBuilder, _>("stream_service")
+//!     .unwrap()
+//!     .set_server("rtmp://127.0.0.1/live")
+//!     .set_key("stream_key")
+//!     .add_to_context(&mut context)
+//!     .unwrap();
+//! ```
+
+mod rtmp_common;
+pub use rtmp_common::*;
+
+mod rtmp_custom;
+pub use rtmp_custom::*;
+
+mod whip_custom;
+pub use whip_custom::*;
+
+pub use libobs_wrapper::services::ObsServiceBuilder;
@@ -0,0 +1,28 @@
+use libobs_wrapper::services::{ObsServiceBuilder, ObsServiceRef};
+
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Streams to one of the platforms that are known to OBS (e.g. `Twitch`
+    /// or `YouTube - RTMPS`). Server urls and encoder limits are taken from
+    /// the `services.json` of the `rtmp-services` plugin.
+    struct RtmpCommonService("rtmp_common") for ObsServiceRef {
+        /// Name of the platform as listed in `services.json`
+        #[obs_property(type_t = "string")]
+        service: String,
+
+        /// Url of the server, or `auto` if the platform supports automatic server selection
+        #[obs_property(type_t = "string")]
+        server: String,
+
+        #[obs_property(type_t = "string")]
+        key: String,
+
+        /// Only tests the bandwidth without the stream going live (if supported by the platform)
+        #[obs_property(type_t = "bool")]
+        bwtest: bool,
+    }
+);
+
+impl ObsServiceBuilder for RtmpCommonServiceBuilder {}
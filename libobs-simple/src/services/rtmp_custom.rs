@@ -0,0 +1,35 @@
+use libobs_wrapper::services::{ObsServiceBuilder, ObsServiceRef};
+
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Streams to a custom server. Besides RTMP and RTMPS, `srt://` and
+    /// `rist://` urls are supported as well, in which case the output has to
+    /// be an `ffmpeg_mpegts_muxer`.
+    struct RtmpCustomService("rtmp_custom") for ObsServiceRef {
+        /// Url of the server, e.g. `rtmp://127.0.0.1/live`
+        #[obs_property(type_t = "string")]
+        server: String,
+
+        /// The stream key, for SRT and RIST this is the stream id
+        #[obs_property(type_t = "string")]
+        key: String,
+
+        /// Authenticate with `username` and `password`
+        #[obs_property(type_t = "bool")]
+        use_auth: bool,
+
+        #[obs_property(type_t = "string")]
+        username: String,
+
+        #[obs_property(type_t = "string")]
+        password: String,
+
+        /// Only tests the bandwidth without the stream going live (if supported by the server)
+        #[obs_property(type_t = "bool")]
+        bwtest: bool,
+    }
+);
+
+impl ObsServiceBuilder for RtmpCustomServiceBuilder {}
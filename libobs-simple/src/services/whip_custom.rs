@@ -0,0 +1,20 @@
+use libobs_wrapper::services::{ObsServiceBuilder, ObsServiceRef};
+
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Streams to a WebRTC server using WHIP. Requires the `obs-webrtc`
+    /// plugin and a `whip_output` with an Opus audio encoder.
+    struct WhipCustomService("whip_custom") for ObsServiceRef {
+        /// Url of the WHIP endpoint
+        #[obs_property(type_t = "string")]
+        server: String,
+
+        /// Token that is sent in the `Authorization` header
+        #[obs_property(type_t = "string")]
+        bearer_token: String,
+    }
+);
+
+impl ObsServiceBuilder for WhipCustomServiceBuilder {}
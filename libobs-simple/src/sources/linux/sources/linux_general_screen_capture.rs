@@ -177,7 +177,8 @@ impl LinuxGeneralScreenCapture {
         self,
         scene: &mut libobs_wrapper::scenes::ObsSceneRef,
     ) -> Result<ObsSourceRef, ObsError> {
-        scene.add_source(self.info)
+        let item = scene.add_source(self.info)?;
+        Ok(item.source().clone())
     }
 
     /// Get the type of capture being used.
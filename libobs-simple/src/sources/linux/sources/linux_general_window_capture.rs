@@ -145,7 +145,8 @@ impl LinuxGeneralWindowCapture {
         self,
         scene: &mut libobs_wrapper::scenes::ObsSceneRef,
     ) -> Result<ObsSourceRef, ObsError> {
-        scene.add_source(self.info)
+        let item = scene.add_source(self.info)?;
+        Ok(item.source().clone())
     }
 
     /// Get the type of capture being used.
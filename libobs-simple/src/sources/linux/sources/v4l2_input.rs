@@ -70,7 +70,7 @@ define_object_manager!(
         auto_reset: bool,
 
         /// Frames until timeout
-        #[obs_property(type_t = "int")]
+        #[obs_property(type_t = "int", min = 0)]
         timeout_frames: i64,
     }
 );
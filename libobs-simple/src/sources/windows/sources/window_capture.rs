@@ -144,7 +144,7 @@ impl ObsSourceBuilder for WindowCaptureSourceBuilder {
         let runtime = self.runtime.clone();
 
         let b = self.build()?;
-        let mut res = scene.add_source(b)?;
+        let mut res = scene.add_source(b)?.source().clone();
 
         if let Some(method) = method_to_set {
             WindowCaptureSourceUpdater::create_update(runtime, &mut res)?
@@ -0,0 +1,11 @@
+use libobs_wrapper::transitions::{ObsTransitionBuilder, ObsTransitionRef};
+
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Switches to the new scene instantly. This transition has a fixed duration of zero.
+    struct CutTransition("cut_transition") for ObsTransitionRef {}
+);
+
+impl ObsTransitionBuilder for CutTransitionBuilder {}
@@ -0,0 +1,11 @@
+use libobs_wrapper::transitions::{ObsTransitionBuilder, ObsTransitionRef};
+
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Cross-fades between the old and the new scene.
+    struct FadeTransition("fade_transition") for ObsTransitionRef {}
+);
+
+impl ObsTransitionBuilder for FadeTransitionBuilder {}
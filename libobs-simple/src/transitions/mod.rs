@@ -0,0 +1,58 @@
+//!
+//! Builders for the transitions that ship with OBS (the `obs-transitions` plugin).
+//!
+//! A transition is created using `ObsContext::transition_builder` and can then be bound
+//! to the output using `ObsContext::set_output_transition`:
+//!
+//! ```no_run
+//! use libobs_simple::transitions::FadeTransitionBuilder;
+//! use libobs_wrapper::{context::ObsContext, transitions::ObsTransitionBuilder, utils::StartupInfo};
+//!
+//! let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+//! let fade = context
+//!     .transition_builder::<FadeTransitionBuilder, _>("Fade")
+//!     .unwrap()
+//!     .add_to_context(&mut context)
+//!     .unwrap();
+//!
+//! context.set_output_transition(&fade).unwrap();
+//! ```
+
+mod cut;
+pub use cut::*;
+
+mod fade;
+pub use fade::*;
+
+mod swipe;
+pub use swipe::*;
+
+mod slide;
+pub use slide::*;
+
+mod stinger;
+pub use stinger::*;
+
+use libobs_wrapper::data::StringEnum;
+
+pub use libobs_wrapper::transitions::ObsTransitionBuilder;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Direction in which the swipe and slide transitions move.
+pub enum ObsTransitionDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl StringEnum for ObsTransitionDirection {
+    fn to_str(&self) -> &str {
+        match self {
+            ObsTransitionDirection::Left => "left",
+            ObsTransitionDirection::Right => "right",
+            ObsTransitionDirection::Up => "up",
+            ObsTransitionDirection::Down => "down",
+        }
+    }
+}
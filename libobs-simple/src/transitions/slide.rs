@@ -0,0 +1,16 @@
+use libobs_wrapper::transitions::{ObsTransitionBuilder, ObsTransitionRef};
+
+use super::ObsTransitionDirection;
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Slides the old scene out while the new scene slides in.
+    struct SlideTransition("slide_transition") for ObsTransitionRef {
+        /// Direction the scenes slide in
+        #[obs_property(type_t = "enum_string")]
+        direction: ObsTransitionDirection,
+    }
+);
+
+impl ObsTransitionBuilder for SlideTransitionBuilder {}
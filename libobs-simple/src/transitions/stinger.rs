@@ -0,0 +1,90 @@
+use libobs_wrapper::transitions::{ObsTransitionBuilder, ObsTransitionRef};
+use num_derive::{FromPrimitive, ToPrimitive};
+
+use crate::define_object_manager;
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Unit of the `transition_point` of a stinger transition
+pub enum ObsStingerTimingType {
+    /// The transition point is given in milliseconds
+    Milliseconds = 0,
+    /// The transition point is given in frames
+    Frames = 1,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// How audio of the old and new scene is faded while the stinger plays
+pub enum ObsStingerAudioFadeStyle {
+    /// Fade out the old audio to the transition point, then fade in the new audio
+    FadeOutFadeIn = 0,
+    /// Crossfade the audio over the whole transition
+    Crossfade = 1,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Audio monitoring of the stinger video
+pub enum ObsStingerAudioMonitoring {
+    MonitorOff = 0,
+    MonitorOnly = 1,
+    MonitorAndOutput = 2,
+}
+
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Where the track matte is located
+pub enum ObsStingerMatteLayout {
+    /// Stinger and matte are side by side in the same file
+    Horizontal = 0,
+    /// Stinger and matte are stacked in the same file
+    Vertical = 1,
+    /// The matte is a separate file, see `track_matte_path`
+    Separate = 2,
+}
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Plays a video file on top of the scenes and switches to the new
+    /// scene at the given transition point.
+    struct StingerTransition("obs_stinger_transition") for ObsTransitionRef {
+        /// Path to the stinger video file
+        #[obs_property(type_t = "string")]
+        path: String,
+
+        /// Unit of `transition_point`
+        #[obs_property(type_t = "enum", settings_key = "tp_type")]
+        transition_point_type: ObsStingerTimingType,
+
+        /// Point at which the scene is switched, either in milliseconds or frames
+        #[obs_property(type_t = "int")]
+        transition_point: i64,
+
+        #[obs_property(type_t = "enum")]
+        audio_monitoring: ObsStingerAudioMonitoring,
+
+        #[obs_property(type_t = "enum")]
+        audio_fade_style: ObsStingerAudioFadeStyle,
+
+        /// Use a track matte to reveal the new scene
+        #[obs_property(type_t = "bool")]
+        track_matte_enabled: bool,
+
+        #[obs_property(type_t = "enum")]
+        track_matte_layout: ObsStingerMatteLayout,
+
+        /// Path to the matte video, only used with `ObsStingerMatteLayout::Separate`
+        #[obs_property(type_t = "string")]
+        track_matte_path: String,
+
+        #[obs_property(type_t = "bool")]
+        invert_matte: bool,
+
+        /// Use hardware decoding for the stinger video
+        #[obs_property(type_t = "bool")]
+        hw_decode: bool,
+    }
+);
+
+impl ObsTransitionBuilder for StingerTransitionBuilder {}
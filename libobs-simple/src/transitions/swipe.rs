@@ -0,0 +1,21 @@
+use libobs_wrapper::transitions::{ObsTransitionBuilder, ObsTransitionRef};
+
+use super::ObsTransitionDirection;
+use crate::define_object_manager;
+
+define_object_manager!(
+    #[derive(Debug)]
+    /// Swipes the new scene over the old one (or the old one away, see `swipe_in`).
+    struct SwipeTransition("swipe_transition") for ObsTransitionRef {
+        /// Direction the swipe moves in
+        #[obs_property(type_t = "enum_string")]
+        direction: ObsTransitionDirection,
+
+        /// If enabled, the new scene swipes in over the old scene.
+        /// Otherwise the old scene swipes out and reveals the new scene.
+        #[obs_property(type_t = "bool")]
+        swipe_in: bool,
+    }
+);
+
+impl ObsTransitionBuilder for SwipeTransitionBuilder {}
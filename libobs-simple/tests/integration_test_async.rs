@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use libobs_simple::output::simple::{ObsContextSimpleExt, OutputFormat};
+use libobs_wrapper::{
+    context::ObsContext,
+    data::video::ObsVideoInfoBuilder,
+    utils::{ObsError, ObsPath, ObsString, OutputInfo, SourceInfo, StartupInfo},
+};
+
+/// Integration test: Test the async variants of the runtime, scene and context operations
+#[tokio::test]
+pub async fn test_async_operations() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let version = context
+        .runtime()
+        .run_with_obs_async(|| unsafe { libobs::obs_get_version() })
+        .await
+        .unwrap();
+    assert!(version > 0, "Version should be returned by the runtime");
+
+    let mut scene = context.scene_async("async_scene").await.unwrap();
+    assert!(context.get_scene("async_scene").unwrap().is_some());
+    let scene_item = scene
+        .add_source_async(SourceInfo::new(
+            "color_source_v3",
+            "async_color",
+            None,
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(scene_item.source().name(), "async_color");
+    assert!(scene.get_source_mut("async_color").unwrap().is_some());
+
+    let mut settings = context.data().unwrap();
+    settings
+        .set_string("path", ObsString::new("async_output.mp4"))
+        .unwrap();
+    let output = context
+        .output_async(OutputInfo::new(
+            "ffmpeg_muxer",
+            "async_output",
+            Some(settings),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(output.name().to_string(), "async_output");
+    assert!(context.get_output("async_output").unwrap().is_some());
+
+    let video_info = ObsVideoInfoBuilder::new()
+        .base_width(1280)
+        .base_height(720)
+        .output_width(1280)
+        .output_height(720)
+        .build();
+    context.reset_video_async(video_info).await.unwrap();
+}
+
+/// Integration test: Start and stop a recording with the async output operations
+#[tokio::test]
+pub async fn test_async_output_start_stop() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut scene = context.scene_async("async_recording_scene").await.unwrap();
+    scene
+        .add_source_async(SourceInfo::new(
+            "color_source_v3",
+            "async_recording_color",
+            None,
+            None,
+        ))
+        .await
+        .unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let path = ObsPath::from_relative("async_recording_test.mkv");
+    let mut output = context
+        .simple_output_builder("async_recording_output", path.clone())
+        .format(OutputFormat::MatroskaVideo)
+        .build()
+        .unwrap();
+
+    // Stopping an output that was never started fails without waiting for signals
+    assert!(matches!(
+        output.stop_async().await,
+        Err(ObsError::OutputStopFailure(_))
+    ));
+
+    output.start_async().await.unwrap();
+
+    // The output becomes active once the muxer started capturing
+    let start = Instant::now();
+    while !output.is_active().unwrap() {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Output did not become active"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    assert!(matches!(
+        output.start_async().await,
+        Err(ObsError::OutputAlreadyActive)
+    ));
+
+    std::thread::sleep(Duration::from_millis(500));
+    output.stop_async().await.unwrap();
+    assert!(!output.is_active().unwrap());
+
+    let path: std::path::PathBuf = path.into();
+    assert!(path.exists(), "Recording was not written");
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::ObsMonitoringType,
+    sources::{ObsSourceRef, ALL_AUDIO_MIXERS},
+    utils::{ObsError, StartupInfo},
+};
+
+/// Integration test: Every audio setter is read back by its getter
+#[test]
+pub fn test_source_audio_controls() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let source = ObsSourceRef::new(
+        "color_source_v3",
+        "audio_controls",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    source.set_volume(0.5).unwrap();
+    assert_eq!(source.volume().unwrap(), 0.5);
+
+    source.set_volume_db(-6.0).unwrap();
+    assert!((source.volume_db().unwrap() + 6.0).abs() < 0.01);
+
+    source.set_muted(true).unwrap();
+    assert!(source.is_muted().unwrap());
+    source.set_muted(false).unwrap();
+    assert!(!source.is_muted().unwrap());
+
+    source.set_balance(0.25).unwrap();
+    assert_eq!(source.balance().unwrap(), 0.25);
+
+    source.set_sync_offset(-150_000_000).unwrap();
+    assert_eq!(source.sync_offset().unwrap(), -150_000_000);
+
+    source.set_audio_mixers(0b101).unwrap();
+    assert_eq!(source.audio_mixers().unwrap(), 0b101);
+    source.set_audio_mixers(ALL_AUDIO_MIXERS).unwrap();
+    assert_eq!(source.audio_mixers().unwrap(), ALL_AUDIO_MIXERS);
+
+    for monitoring_type in [
+        ObsMonitoringType::MonitorOnly,
+        ObsMonitoringType::MonitorAndOutput,
+        ObsMonitoringType::None,
+    ] {
+        source.set_monitoring_type(monitoring_type).unwrap();
+        assert_eq!(source.monitoring_type().unwrap(), monitoring_type);
+    }
+
+    source.set_push_to_talk(true).unwrap();
+    assert!(source.push_to_talk_enabled().unwrap());
+    source
+        .set_push_to_talk_delay(Duration::from_millis(300))
+        .unwrap();
+    assert_eq!(
+        source.push_to_talk_delay().unwrap(),
+        Duration::from_millis(300)
+    );
+
+    source.set_push_to_mute(true).unwrap();
+    assert!(source.push_to_mute_enabled().unwrap());
+    source
+        .set_push_to_mute_delay(Duration::from_millis(200))
+        .unwrap();
+    assert_eq!(
+        source.push_to_mute_delay().unwrap(),
+        Duration::from_millis(200)
+    );
+
+    // Out of range values are rejected and leave the previous value in place
+    assert!(matches!(
+        source.set_volume(-1.0),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        source.set_volume(f32::NAN),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        source.set_balance(1.5),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        source.set_audio_mixers(ALL_AUDIO_MIXERS + 1),
+        Err(ObsError::InvalidOperation(_))
+    ));
+
+    assert!((source.volume_db().unwrap() + 6.0).abs() < 0.01);
+    assert_eq!(source.balance().unwrap(), 0.25);
+    assert_eq!(source.audio_mixers().unwrap(), ALL_AUDIO_MIXERS);
+}
@@ -0,0 +1,138 @@
+use std::time::{Duration, Instant};
+
+use libobs_simple::output::simple::{ObsContextSimpleExt, OutputFormat};
+use libobs_wrapper::{
+    context::ObsContext,
+    data::video::ObsVideoInfoBuilder,
+    utils::{ObsError, ObsPath, ObsString, OutputInfo, SourceInfo, StartupInfo},
+};
+
+/// Integration test: Test a vertical canvas that shares a source with the main scene
+#[test]
+pub fn test_canvas_with_shared_source() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut main_scene = context.scene("landscape").unwrap();
+    main_scene.set_to_channel(0).unwrap();
+    let item = main_scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "shared_color",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let video_info = ObsVideoInfoBuilder::new()
+        .base_width(1080)
+        .base_height(1920)
+        .output_width(1080)
+        .output_height(1920)
+        .build();
+    let canvas = context.canvas("vertical", video_info).unwrap();
+    assert_eq!(canvas.base_size().unwrap(), (1080, 1920));
+    assert!(!canvas.uuid().unwrap().is_empty());
+
+    let mut vertical_scene = canvas.scene("vertical_scene").unwrap();
+    vertical_scene.add_existing_source(item.source()).unwrap();
+    canvas.set_channel(0, &vertical_scene).unwrap();
+
+    assert_eq!(canvas.get_channel(0).unwrap(), Some(vertical_scene.clone()));
+    assert!(canvas.get_scene("vertical_scene").unwrap().is_some());
+    assert!(context.get_canvas("vertical").unwrap().is_some());
+
+    let mut settings = context.data().unwrap();
+    settings
+        .set_string("path", ObsString::new("canvas_test.mp4"))
+        .unwrap();
+    let info = OutputInfo::new("ffmpeg_muxer", "canvas_output", Some(settings), None);
+    let mut output = context.output(info).unwrap();
+    output.set_canvas(Some(canvas.clone())).unwrap();
+    assert_eq!(output.get_canvas().unwrap(), Some(canvas.clone()));
+
+    // Scenes of a canvas are assigned to the channels of their canvas
+    vertical_scene.set_to_channel(1).unwrap();
+    assert_eq!(canvas.get_channel(1).unwrap(), Some(vertical_scene.clone()));
+    vertical_scene.remove_from_channel(1).unwrap();
+    assert!(canvas.get_channel(1).unwrap().is_none());
+
+    canvas.clear_channel(0).unwrap();
+    assert!(canvas.get_channel(0).unwrap().is_none());
+
+    output.set_canvas(None).unwrap();
+    drop(output);
+    context.remove_canvas(&canvas).unwrap();
+    assert!(context.get_canvas("vertical").unwrap().is_none());
+
+    // The scenes of a canvas don't keep it alive
+    drop(canvas);
+    assert!(matches!(
+        vertical_scene.set_to_channel(0),
+        Err(ObsError::InvalidOperation(_))
+    ));
+}
+
+/// Integration test: Record the video of a canvas
+#[test]
+pub fn test_canvas_recording() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let video_info = ObsVideoInfoBuilder::new()
+        .base_width(720)
+        .base_height(1280)
+        .output_width(720)
+        .output_height(1280)
+        .build();
+    let canvas = context.canvas("recorded_vertical", video_info).unwrap();
+
+    let mut scene = canvas.scene("recorded_vertical_scene").unwrap();
+    scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "recorded_color",
+            None,
+            None,
+        ))
+        .unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let path = ObsPath::from_relative("canvas_recording_test.mkv");
+    let mut output = context
+        .simple_output_builder("canvas_recording_output", path.clone())
+        .format(OutputFormat::MatroskaVideo)
+        .build()
+        .unwrap();
+    output.set_canvas(Some(canvas.clone())).unwrap();
+
+    output.start().unwrap();
+    let start = Instant::now();
+    while !output.is_active().unwrap() {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Canvas output did not become active"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    // The canvas can't be changed while it is recorded
+    assert!(matches!(
+        output.set_canvas(None),
+        Err(ObsError::OutputAlreadyActive)
+    ));
+
+    std::thread::sleep(Duration::from_millis(500));
+    output.stop().unwrap();
+    assert!(!output.is_active().unwrap());
+
+    let path: std::path::PathBuf = path.into();
+    assert!(path.exists(), "Canvas recording was not written");
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,57 @@
+use libobs_simple::codegen::{generate_object_module, ObsObjectKind};
+use libobs_wrapper::{context::ObsContext, utils::StartupInfo};
+
+/// Integration test: Test generating builder and updater structs from the properties of plugins
+#[test]
+pub fn test_generate_object_module() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let code = generate_object_module(
+        &context,
+        ObsObjectKind::Source,
+        "color_source_v3",
+        "ColorSource",
+    )
+    .unwrap();
+
+    assert!(code.contains("#[libobs_simple_macro::obs_object_builder(\"color_source_v3\")]"));
+    assert!(code.contains("pub struct ColorSourceBuilder {"));
+    assert!(code
+        .contains("#[libobs_simple_macro::obs_object_updater(\"color_source_v3\", ObsSourceRef)]"));
+    assert!(code.contains("pub struct ColorSourceUpdater {"));
+    assert!(code.contains("    width: i64,"));
+    assert!(code.contains("#[obs_property(type_t = \"int\", min = "));
+    assert!(code.contains("/// Range: `"));
+    assert!(code.contains("impl ObsSourceBuilder for ColorSourceBuilder {}"));
+
+    let code = generate_object_module(
+        &context,
+        ObsObjectKind::Transition,
+        "swipe_transition",
+        "SwipeTransition",
+    )
+    .unwrap();
+
+    assert!(code.contains("pub enum SwipeTransitionDirection {"));
+    assert!(code.contains("impl StringEnum for SwipeTransitionDirection {"));
+    assert!(code.contains("#[obs_property(type_t = \"enum_string\")]"));
+    assert!(code.contains("impl ObsTransitionBuilder for SwipeTransitionBuilder {}"));
+
+    let code = generate_object_module(
+        &context,
+        ObsObjectKind::Service,
+        "rtmp_custom",
+        "RtmpCustomService",
+    )
+    .unwrap();
+    assert!(code.contains("    server: String,"));
+
+    assert!(
+        generate_object_module(&context, ObsObjectKind::Source, "does_not_exist", "Missing")
+            .is_err()
+    );
+}
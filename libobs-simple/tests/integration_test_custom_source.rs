@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use libobs_simple_macro::obs_object_builder;
+use libobs_wrapper::{
+    context::ObsContext,
+    data::{
+        properties::{ObsProperty, ObsPropertyObject},
+        ObsDataGetters, ObsObjectBuilder,
+    },
+    sources::{
+        ObsCustomSource, ObsCustomSourceContext, ObsPropertiesDefinition, ObsSourceBuilder,
+        ObsSourceRenderContext, ObsSourceSettings,
+    },
+    utils::{ObsError, SourceInfo, StartupInfo},
+};
+
+/// A source that fills its area with a single color
+struct SolidColorSource {
+    color: u32,
+    width: u32,
+    height: u32,
+}
+
+impl ObsCustomSource for SolidColorSource {
+    const ID: &'static str = "rust_solid_color_source";
+    const NAME: &'static str = "Solid Color (Rust)";
+    const OUTPUT_FLAGS: u32 = libobs::OBS_SOURCE_VIDEO | libobs::OBS_SOURCE_CUSTOM_DRAW;
+
+    fn create(settings: &ObsSourceSettings, _context: ObsCustomSourceContext) -> Self {
+        let mut source = Self {
+            color: 0,
+            width: 0,
+            height: 0,
+        };
+        source.update(settings);
+        source
+    }
+
+    fn update(&mut self, settings: &ObsSourceSettings) {
+        self.color = settings.get_int("color") as u32;
+        self.width = settings.get_int("width") as u32;
+        self.height = settings.get_int("height") as u32;
+    }
+
+    fn video_render(&mut self, render: &ObsSourceRenderContext) {
+        render.draw_solid_color(self.color, self.width, self.height);
+    }
+
+    fn get_width(&self) -> u32 {
+        self.width
+    }
+
+    fn get_height(&self) -> u32 {
+        self.height
+    }
+
+    fn get_properties(properties: &mut ObsPropertiesDefinition) {
+        properties.add_color("color", "Color");
+        properties.add_int("width", "Width", 1, 4096, 1);
+        properties.add_int("height", "Height", 1, 4096, 1);
+    }
+
+    fn get_defaults(settings: &mut ObsSourceSettings) {
+        settings.set_default_int("color", 0xFFFF0000);
+        settings.set_default_int("width", 320);
+        settings.set_default_int("height", 240);
+    }
+}
+
+#[derive(Debug)]
+#[obs_object_builder("rust_solid_color_source")]
+pub struct SolidColorSourceBuilder {
+    #[obs_property(type_t = "color")]
+    color: u32,
+    #[obs_property(type_t = "int", default = 480, min = 1, max = 4096)]
+    width: i64,
+    #[obs_property(type_t = "int")]
+    height: Option<i64>,
+}
+
+impl ObsSourceBuilder for SolidColorSourceBuilder {}
+
+/// Integration test: Register a source implemented in Rust and create it with a builder
+#[test]
+pub fn test_custom_source() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    context.register_source::<SolidColorSource>().unwrap();
+
+    // Registering the same id twice is rejected
+    assert!(context.register_source::<SolidColorSource>().is_err());
+
+    let mut scene = context.scene("custom_source_scene").unwrap();
+    let source = context
+        .source_builder::<SolidColorSourceBuilder, _>("solid_color")
+        .unwrap()
+        .set_color(0xFFFF0000)
+        .set_width(640)
+        .unwrap()
+        .set_height(None)
+        .add_to_scene(&mut scene)
+        .unwrap();
+
+    assert_eq!(source.id(), "rust_solid_color_source");
+    assert_eq!(source.width().unwrap(), 640);
+    // Unset by the builder, so the default is used
+    assert_eq!(source.height().unwrap(), 240);
+    // Passed as ARGB, libobs stores colors as ABGR
+    assert_eq!(source.settings().get_int("color"), Ok(Some(0xFF0000FF)));
+
+    let properties = source.get_properties().unwrap();
+    assert!(matches!(
+        properties.get("color"),
+        Some(ObsProperty::Color(_))
+    ));
+    assert!(matches!(properties.get("width"), Some(ObsProperty::Int(_))));
+}
+
+/// Integration test: Defaults and ranges declared on the builder fields
+#[test]
+pub fn test_custom_source_builder_ranges() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    context.register_source::<SolidColorSource>().unwrap();
+
+    let mut scene = context.scene("custom_source_ranges_scene").unwrap();
+
+    // The default of the builder replaces the one of the source
+    let source = context
+        .source_builder::<SolidColorSourceBuilder, _>("solid_color_default")
+        .unwrap()
+        .add_to_scene(&mut scene)
+        .unwrap();
+    assert_eq!(source.width().unwrap(), 480);
+
+    let builder = context
+        .source_builder::<SolidColorSourceBuilder, _>("solid_color_invalid")
+        .unwrap();
+    assert!(matches!(
+        builder.set_width(0),
+        Err(ObsError::InvalidSettingValue(_))
+    ));
+
+    let source = context
+        .source_builder::<SolidColorSourceBuilder, _>("solid_color_clamped")
+        .unwrap()
+        .set_width_clamped(10_000)
+        .add_to_scene(&mut scene)
+        .unwrap();
+    assert_eq!(source.width().unwrap(), 4096);
+
+    // Values that bypass the setters are checked when building
+    let mut builder = context
+        .source_builder::<SolidColorSourceBuilder, _>("solid_color_raw")
+        .unwrap();
+    builder.get_settings_updater().set_int_ref("width", -5);
+    assert!(matches!(
+        builder.build(),
+        Err(ObsError::InvalidSettingValue(_))
+    ));
+}
+
+/// A source that panics every frame
+struct PanickingSource;
+
+impl ObsCustomSource for PanickingSource {
+    const ID: &'static str = "rust_panicking_source";
+    const NAME: &'static str = "Panicking (Rust)";
+
+    fn create(_settings: &ObsSourceSettings, _context: ObsCustomSourceContext) -> Self {
+        Self
+    }
+
+    fn video_tick(&mut self, _seconds: f32) {
+        panic!("video_tick of the panicking source");
+    }
+
+    fn get_width(&self) -> u32 {
+        16
+    }
+
+    fn get_height(&self) -> u32 {
+        9
+    }
+}
+
+/// Integration test: Panics in callbacks are caught instead of unwinding into libobs
+#[test]
+pub fn test_custom_source_panic() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    context.register_source::<PanickingSource>().unwrap();
+
+    let mut scene = context.scene("custom_source_panic_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+    let item = scene
+        .add_source(SourceInfo::new(
+            PanickingSource::ID,
+            "panicking",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    // Let a few frames tick, every one of them panics
+    std::thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(item.source().width().unwrap(), 16);
+    assert_eq!(item.source().height().unwrap(), 9);
+}
@@ -1,4 +1,12 @@
-use libobs_wrapper::{context::ObsContext, data::ObsDataGetters, utils::StartupInfo};
+use libobs_wrapper::{
+    context::ObsContext,
+    data::{
+        serde::{from_obs_data, to_obs_data},
+        ObsDataGetters, ObsFont,
+    },
+    enums::{ObsDataNumberType, ObsDataType},
+    utils::StartupInfo,
+};
 
 /// Integration test: Test encoder settings manipulation
 #[test]
@@ -52,3 +60,173 @@ pub fn test_data_get_nonexistent() {
     let nonexistent_double = data.get_double("nonexistent_key");
     assert_eq!(nonexistent_double, Ok(None));
 }
+
+/// Integration test: Nested objects, arrays, default values and key iteration
+#[test]
+pub fn test_data_objects_and_arrays() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut data = context.data().unwrap();
+
+    let mut crop = context.data().unwrap();
+    crop.set_int("left", 10).unwrap();
+    data.set_obj("crop", &crop).unwrap();
+
+    let mut list = context.data_array().unwrap();
+    for value in ["first", "third"] {
+        let mut entry = context.data().unwrap();
+        entry.set_string("value", value).unwrap();
+        list.push(&entry).unwrap();
+    }
+
+    let mut second = context.data().unwrap();
+    second.set_string("value", "second").unwrap();
+    list.insert(1, &second).unwrap();
+    data.set_array("list", &list).unwrap();
+
+    let loaded_crop = data.get_obj("crop").unwrap().unwrap();
+    assert_eq!(loaded_crop.get_int("left"), Ok(Some(10)));
+
+    let loaded_list = data.get_array("list").unwrap().unwrap();
+    let values = loaded_list
+        .to_vec()
+        .unwrap()
+        .iter()
+        .map(|entry| entry.get_string("value").unwrap().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["first", "second", "third"]);
+
+    let mut loaded_list = loaded_list;
+    loaded_list.remove(0).unwrap();
+    assert_eq!(data.get_array("list").unwrap().unwrap().len(), Ok(2));
+    assert!(loaded_list.remove(5).is_err());
+
+    // Defaults are only used until a user value is set
+    data.set_default_int("volume", 100).unwrap();
+    assert_eq!(data.get_int("volume"), Ok(Some(100)));
+    assert_eq!(data.has_user_value("volume"), Ok(false));
+
+    data.set_int("volume", 50).unwrap();
+    assert_eq!(data.get_int("volume"), Ok(Some(50)));
+    assert_eq!(data.get_default_int("volume"), Ok(Some(100)));
+
+    data.unset_user_value("volume").unwrap();
+    assert_eq!(data.get_int("volume"), Ok(Some(100)));
+
+    data.set_double("ratio", 0.5).unwrap();
+    let items = data.items().unwrap();
+    let ratio = items.iter().find(|item| item.name == "ratio").unwrap();
+    assert_eq!(ratio.data_type, ObsDataType::Number);
+    assert_eq!(ratio.number_type, ObsDataNumberType::Double);
+    let crop_item = items.iter().find(|item| item.name == "crop").unwrap();
+    assert_eq!(crop_item.data_type, ObsDataType::Object);
+    assert!(items.iter().any(|item| item.name == "list"));
+
+    data.erase("crop").unwrap();
+    assert!(data.get_obj("crop").unwrap().is_none());
+    assert_eq!(data.has_default_value("volume"), Ok(true));
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct CropSettings {
+    left: i32,
+    top: i32,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SourceSettings {
+    url: String,
+    volume: f64,
+    looping: bool,
+    crop: CropSettings,
+    playlist: Vec<String>,
+    hotkey: Option<String>,
+}
+
+/// Integration test: Convert typed settings to ObsData and back
+#[test]
+pub fn test_data_serde() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let settings = SourceSettings {
+        url: "https://example.com/stream".to_string(),
+        volume: 0.75,
+        looping: true,
+        crop: CropSettings { left: 10, top: 20 },
+        playlist: vec!["intro.mp4".to_string(), "main.mp4".to_string()],
+        hotkey: None,
+    };
+
+    let data = to_obs_data(&settings, context.runtime().clone()).unwrap();
+    assert_eq!(
+        data.get_string("url").unwrap().as_deref(),
+        Some("https://example.com/stream")
+    );
+    assert_eq!(
+        data.get_obj("crop").unwrap().unwrap().get_int("top"),
+        Ok(Some(20))
+    );
+    assert_eq!(data.get_array("playlist").unwrap().unwrap().len(), Ok(2));
+    assert_eq!(data.has_user_value("hotkey"), Ok(false));
+
+    let loaded: SourceSettings = from_obs_data(&data).unwrap();
+    assert_eq!(loaded, settings);
+
+    // Defaults are used for keys without a user value
+    let mut partial = context.data().unwrap();
+    partial.set_string("url", "rtmp://localhost").unwrap();
+    partial.set_default_double("volume", 1.0).unwrap();
+    partial.set_bool("looping", false).unwrap();
+    partial
+        .set_obj("crop", &data.get_obj("crop").unwrap().unwrap())
+        .unwrap();
+    partial
+        .set_array("playlist", &context.data_array().unwrap())
+        .unwrap();
+
+    let loaded: SourceSettings = from_obs_data(&partial).unwrap();
+    assert_eq!(loaded.volume, 1.0);
+    assert!(loaded.playlist.is_empty());
+
+    let missing: Result<SourceSettings, _> = from_obs_data(&context.data().unwrap());
+    assert!(missing.is_err());
+}
+
+#[test]
+pub fn test_data_updater_property_formats() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut data = context.data().unwrap();
+    data.set_int("unset", 5).unwrap();
+
+    let mut font = ObsFont::new("Sans Serif", 48);
+    font.flags = libobs::OBS_FONT_BOLD;
+
+    data.bulk_update()
+        .set_font("font", font)
+        .set_string_array("files", ["a.png", "b.png"])
+        .set_frame_rate("fps", 30000, 1001)
+        .unset_user_value("unset")
+        .update()
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&data.get_json().unwrap()).unwrap();
+    assert_eq!(json["font"]["face"], "Sans Serif");
+    assert_eq!(json["font"]["size"], 48);
+    assert_eq!(json["font"]["flags"], libobs::OBS_FONT_BOLD);
+    assert_eq!(json["files"][0]["value"], "a.png");
+    assert_eq!(json["files"][1]["value"], "b.png");
+    assert_eq!(json["fps"]["numerator"], 30000);
+    assert_eq!(json["fps"]["denominator"], 1001);
+    assert_eq!(data.get_int("unset"), Ok(None));
+}
@@ -0,0 +1,123 @@
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::ObsOrderMovement,
+    sources::ObsSourceRef,
+    utils::{FilterInfo, ObsError, StartupInfo},
+};
+use tokio::sync::broadcast::error::TryRecvError;
+
+/// Integration test: Manage the filter chain of a single source
+#[test]
+pub fn test_source_filters() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let source = ObsSourceRef::new(
+        "color_source_v3",
+        "filtered_color",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    let signals = source.signal_manager();
+    let mut filter_add = signals.on_filter_add().unwrap();
+    let mut filter_remove = signals.on_filter_remove().unwrap();
+    let mut reorder_filters = signals.on_reorder_filters().unwrap();
+
+    let correction = context
+        .obs_filter(FilterInfo::new("color_filter_v2", "correction", None, None))
+        .unwrap();
+    let crop = context
+        .obs_filter(FilterInfo::new("crop_filter", "crop", None, None))
+        .unwrap();
+
+    source.add_filter(&correction).unwrap();
+    source.add_filter(&crop).unwrap();
+    assert_eq!(filter_add.try_recv().unwrap().filter.0, correction.as_ptr());
+    assert_eq!(filter_add.try_recv().unwrap().filter.0, crop.as_ptr());
+
+    // A filter can only be attached to one source, and sources are not filters
+    assert!(matches!(
+        source.add_filter(&crop),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(source.add_filter(&source).is_err());
+
+    assert_eq!(
+        source.filters().unwrap(),
+        vec![correction.clone(), crop.clone()]
+    );
+    assert_eq!(source.filter_index(&crop).unwrap(), Some(1));
+
+    source
+        .set_filter_order(&crop, ObsOrderMovement::MoveTop)
+        .unwrap();
+    assert!(reorder_filters.try_recv().is_ok());
+    assert_eq!(
+        source.filters().unwrap(),
+        vec![crop.clone(), correction.clone()]
+    );
+
+    let found = source.get_filter_by_name("correction").unwrap().unwrap();
+    assert_eq!(found, correction);
+    assert!(source.get_filter_by_name("missing").unwrap().is_none());
+
+    assert!(correction.enabled().unwrap());
+    source.set_filter_enabled(&correction, false).unwrap();
+    assert!(!correction.enabled().unwrap());
+
+    source.remove_filter(&crop).unwrap();
+    assert_eq!(filter_remove.try_recv().unwrap().filter.0, crop.as_ptr());
+    assert_eq!(source.filters().unwrap(), vec![correction.clone()]);
+    assert_eq!(source.filter_index(&crop).unwrap(), None);
+
+    // Removed filters can no longer be changed through this source
+    assert!(source.set_filter_enabled(&crop, false).is_err());
+    assert!(source.remove_filter(&crop).is_err());
+
+    // The source does not keep the filters it handed out alive
+    let untracked = ObsSourceRef::new(
+        "crop_filter",
+        "untracked_crop",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+    let mut untracked_update = untracked.signal_manager().on_update().unwrap();
+    source.add_filter(&untracked).unwrap();
+    drop(untracked);
+    assert!(matches!(
+        untracked_update.try_recv(),
+        Err(TryRecvError::Closed)
+    ));
+
+    // Filters that are still attached are wrapped again when listed
+    let untracked = source
+        .get_filter_by_name("untracked_crop")
+        .unwrap()
+        .unwrap();
+    let mut untracked_enable = untracked.signal_manager().on_enable().unwrap();
+    assert_eq!(
+        source.filters().unwrap(),
+        vec![correction.clone(), untracked.clone()]
+    );
+
+    // Dropping the listed wrappers keeps the signals of the others working
+    source.set_filter_enabled(&untracked, false).unwrap();
+    assert!(!untracked_enable.try_recv().unwrap().enabled);
+
+    let mut untracked_update = untracked.signal_manager().on_update().unwrap();
+    source.remove_filter(&untracked).unwrap();
+    drop(untracked);
+    assert!(matches!(
+        untracked_update.try_recv(),
+        Err(TryRecvError::Closed)
+    ));
+    assert_eq!(source.filters().unwrap(), vec![correction]);
+}
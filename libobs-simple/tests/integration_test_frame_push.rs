@@ -0,0 +1,72 @@
+use std::time::{Duration, Instant};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::{raw_audio::ObsRawAudioFrame, raw_video::ObsRawVideoFrame},
+    enums::{ObsSamplesPerSecond, ObsSpeakerLayout},
+    utils::StartupInfo,
+};
+
+/// Integration test: Push synthetic video and audio into a scene
+#[test]
+pub fn test_frame_push_source() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("frame_push_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let source = context
+        .frame_push_source(&mut scene, "test_pattern")
+        .unwrap();
+
+    // A second source reuses the already registered source type
+    context
+        .frame_push_source(&mut scene, "second_pattern")
+        .unwrap();
+
+    let (width, height) = (64, 32);
+    let pixels = (0..width * height)
+        .flat_map(|i| [(i % 256) as u8, 0, 255, 255])
+        .collect::<Vec<u8>>();
+
+    let start = Instant::now();
+    let timestamp = 0;
+    source
+        .push_video(ObsRawVideoFrame::rgba(width, height, pixels, timestamp))
+        .unwrap();
+
+    let silence = vec![vec![0.0; 1024]; 2];
+    source
+        .push_audio(ObsRawAudioFrame::new(
+            silence,
+            ObsSamplesPerSecond::F44100,
+            ObsSpeakerLayout::Stereo,
+            timestamp,
+        ))
+        .unwrap();
+
+    // The size of an async source is updated once libobs picked up the frame
+    while source.as_source().width().unwrap() != width {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "Frame was not shown"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(source.as_source().height().unwrap(), height);
+
+    // Frames with missing data are rejected before reaching libobs
+    assert!(source
+        .push_video(ObsRawVideoFrame::rgba(
+            width,
+            height,
+            vec![0; 16],
+            timestamp
+        ))
+        .is_err());
+
+    source.clear_video().unwrap();
+}
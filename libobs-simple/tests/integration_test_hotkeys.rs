@@ -0,0 +1,84 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::ObsHotkeyRegistererType,
+    hotkeys::{ObsKey, ObsKeyCombination, ObsKeyModifiers},
+    utils::StartupInfo,
+};
+
+/// Integration test: Register a frontend hotkey, bind it and trigger it
+#[test]
+pub fn test_frontend_hotkey() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    let manager = context.hotkey_manager();
+
+    let presses = Arc::new(AtomicUsize::new(0));
+    let presses_clone = presses.clone();
+    let hotkey = manager
+        .register_frontend("test_hotkey", "Test Hotkey", move |pressed| {
+            if pressed {
+                presses_clone.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .unwrap();
+
+    let info = manager
+        .get_hotkey_by_name("test_hotkey")
+        .unwrap()
+        .expect("Registered hotkey should be enumerated");
+    assert_eq!(info.id, hotkey.id());
+    assert_eq!(info.description, "Test Hotkey");
+    assert_eq!(info.registerer_type, ObsHotkeyRegistererType::Frontend);
+
+    let key = ObsKey::from_name("OBS_KEY_F13");
+    assert!(!key.is_none());
+    assert_eq!(key.name(), "OBS_KEY_F13");
+
+    let combination = ObsKeyCombination::new(
+        key,
+        ObsKeyModifiers {
+            control: true,
+            ..Default::default()
+        },
+    );
+    manager.set_bindings(hotkey.id(), &[combination]).unwrap();
+    assert_eq!(
+        manager.get_bindings(hotkey.id()).unwrap(),
+        vec![combination]
+    );
+
+    manager.inject_press(combination).unwrap();
+    assert_eq!(presses.load(Ordering::SeqCst), 1);
+
+    // A panicking closure is logged instead of aborting the process
+    let panicking = manager
+        .register_frontend("panicking_hotkey", "Panicking Hotkey", |_| {
+            panic!("Hotkey closure panicked")
+        })
+        .unwrap();
+    let panic_combination =
+        ObsKeyCombination::new(ObsKey::from_name("OBS_KEY_F14"), ObsKeyModifiers::default());
+    manager
+        .set_bindings(panicking.id(), &[panic_combination])
+        .unwrap();
+    manager.inject_press(panic_combination).unwrap();
+
+    manager.inject_press(combination).unwrap();
+    assert_eq!(presses.load(Ordering::SeqCst), 2);
+
+    manager.set_bindings(hotkey.id(), &[]).unwrap();
+    assert!(manager.get_bindings(hotkey.id()).unwrap().is_empty());
+
+    let id = hotkey.id();
+    drop(hotkey);
+    manager.unregister_frontend(id).unwrap();
+    assert!(manager.get_hotkey_by_name("test_hotkey").unwrap().is_none());
+}
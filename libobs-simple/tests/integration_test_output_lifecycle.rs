@@ -1,8 +1,11 @@
 mod common;
 
+use std::time::Duration;
+
+use libobs_simple::output::simple::{ObsContextSimpleExt, OutputFormat};
 use libobs_wrapper::{
     context::ObsContext,
-    utils::{ObsString, OutputInfo, StartupInfo},
+    utils::{ObsError, ObsPath, ObsString, OutputInfo, StartupInfo},
 };
 
 /// Integration test: Test output creation
@@ -107,3 +110,98 @@ pub fn test_output_different_settings() {
     let info2 = OutputInfo::new("ffmpeg_muxer", "configured_output", Some(settings2), None);
     assert!(context.output(info2).is_ok());
 }
+
+/// Integration test: Test output and render statistics
+#[test]
+pub fn test_output_and_render_stats() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut settings = context.data().unwrap();
+    settings
+        .set_string("path", ObsString::new("stats_test.mp4"))
+        .unwrap();
+    let info = OutputInfo::new("ffmpeg_muxer", "stats_output", Some(settings), None);
+    let output = context.output(info).unwrap();
+
+    // The output was never started, so nothing was written yet
+    let stats = output.stats().unwrap();
+    assert_eq!(stats.total_bytes, 0);
+    assert_eq!(stats.dropped_frames, 0);
+    assert_eq!(stats.bitrate_kbps, 0.0);
+    assert_eq!(stats.dropped_frames_percent(), 0.0);
+
+    let mut stats_rx = output.stats_broadcast(Duration::from_millis(50));
+    let stats = stats_rx.blocking_recv().unwrap();
+    assert_eq!(stats.total_bytes, 0);
+
+    let render_stats = context.render_stats().unwrap();
+    assert!(render_stats.frame_interval > Duration::ZERO);
+    assert!(render_stats.lagged_frames <= render_stats.total_frames);
+
+    let mut render_rx = context.render_stats_broadcast(Duration::from_millis(50));
+    let first = render_rx.blocking_recv().unwrap();
+    let second = render_rx.blocking_recv().unwrap();
+    assert!(second.total_frames >= first.total_frames);
+
+    drop(stats_rx);
+    drop(render_rx);
+}
+
+/// Integration test: Test recording separate mixers as separate audio tracks
+#[test]
+pub fn test_multi_track_audio() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let output = context
+        .simple_output_builder(
+            "multi_track_output",
+            ObsPath::from_relative("multi_track_test.mkv"),
+        )
+        .format(OutputFormat::MatroskaVideo)
+        .audio_tracks([1, 3])
+        .build()
+        .unwrap();
+
+    let tracks = output.get_audio_encoders().unwrap();
+    assert_eq!(tracks.len(), 2);
+    assert_eq!(tracks[0].0, 0);
+    assert_eq!(tracks[0].1.mixer_index().unwrap(), 1);
+    assert_eq!(tracks[1].0, 1);
+    assert_eq!(tracks[1].1.mixer_index().unwrap(), 3);
+
+    // Invalid and duplicate mixers are rejected before the output is created
+    let result = context
+        .simple_output_builder(
+            "invalid_track_output",
+            ObsPath::from_relative("invalid_track_test.mkv"),
+        )
+        .audio_tracks([0, 6])
+        .build();
+    assert!(matches!(result, Err(ObsError::InvalidOperation(_))));
+
+    let result = context
+        .simple_output_builder(
+            "duplicate_track_output",
+            ObsPath::from_relative("duplicate_track_test.mkv"),
+        )
+        .audio_tracks([2, 2])
+        .build();
+    assert!(matches!(result, Err(ObsError::InvalidOperation(_))));
+
+    assert!(context
+        .get_output("invalid_track_output")
+        .unwrap()
+        .is_none());
+    assert!(context
+        .get_output("duplicate_track_output")
+        .unwrap()
+        .is_none());
+}
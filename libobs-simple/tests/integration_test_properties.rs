@@ -0,0 +1,149 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::properties::{ObsPropertiesOwner, ObsProperty},
+    sources::{
+        ObsCustomSource, ObsCustomSourceContext, ObsPropertiesDefinition, ObsSourceRef,
+        ObsSourceSettings,
+    },
+    utils::StartupInfo,
+};
+
+/// Names and values the modified callback was called with
+static MODIFIED: Mutex<Vec<(String, bool)>> = Mutex::new(Vec::new());
+static CLICKS: AtomicUsize = AtomicUsize::new(0);
+
+/// A source with a property that has a modified callback and a button
+struct ButtonSource {
+    clicks: usize,
+}
+
+impl ObsCustomSource for ButtonSource {
+    const ID: &'static str = "rust_button_source";
+    const NAME: &'static str = "Button (Rust)";
+
+    fn create(_settings: &ObsSourceSettings, _context: ObsCustomSourceContext) -> Self {
+        Self { clicks: 0 }
+    }
+
+    fn get_properties(properties: &mut ObsPropertiesDefinition) {
+        properties
+            .add_bool("advanced", "Advanced")
+            .set_modified_callback::<Self>();
+        properties.add_button::<Self>("count", "Count");
+    }
+
+    fn property_modified(name: &str, settings: &ObsSourceSettings) -> bool {
+        let advanced = settings.get_bool(name);
+        MODIFIED.lock().unwrap().push((name.to_string(), advanced));
+        advanced
+    }
+
+    fn button_clicked(&mut self, name: &str) -> bool {
+        self.clicks += 1;
+        CLICKS.store(self.clicks, Ordering::SeqCst);
+        name == "count"
+    }
+}
+
+/// Integration test: Test interacting with the live properties of a source
+#[test]
+pub fn test_properties_handle() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let source = ObsSourceRef::new(
+        "color_source_v3",
+        "properties_color",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    let mut handle = source.properties_handle().unwrap();
+    assert_eq!(handle.object(), &source);
+
+    let properties = handle.get_properties().unwrap();
+    assert!(matches!(properties.get("width"), Some(ObsProperty::Int(_))));
+    assert!(matches!(
+        handle.get_property("width").unwrap(),
+        Some(ObsProperty::Int(_))
+    ));
+    assert!(handle.get_property("missing").unwrap().is_none());
+
+    let mut settings = context.data().unwrap();
+    settings.set_int("width", 128).unwrap();
+    handle.apply_settings(&settings).unwrap();
+
+    // The color source has no modified callbacks, so nothing has to be refreshed
+    assert!(!handle.modified("width", &settings).unwrap());
+    assert!(handle.modified("missing", &settings).is_err());
+
+    // Only buttons can be clicked
+    assert!(handle.button_clicked("width").is_err());
+
+    let properties = handle.refetch().unwrap();
+    assert!(properties.contains_key("height"));
+}
+
+/// Integration test: Test modified callbacks and buttons of a custom source
+#[test]
+pub fn test_properties_callbacks() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+    context.register_source::<ButtonSource>().unwrap();
+
+    let source = ObsSourceRef::new(
+        ButtonSource::ID,
+        "properties_button",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    let handle = source.properties_handle().unwrap();
+    assert!(matches!(
+        handle.get_property("count").unwrap(),
+        Some(ObsProperty::Button(_))
+    ));
+
+    // Creating the properties already applies the settings of the source
+    MODIFIED.lock().unwrap().clear();
+
+    let mut settings = context.data().unwrap();
+    settings.set_bool("advanced", true).unwrap();
+    assert!(handle.modified("advanced", &settings).unwrap());
+    assert_eq!(
+        MODIFIED.lock().unwrap().as_slice(),
+        &[("advanced".to_string(), true)]
+    );
+
+    settings.set_bool("advanced", false).unwrap();
+    handle.apply_settings(&settings).unwrap();
+    assert_eq!(
+        MODIFIED.lock().unwrap().last(),
+        Some(&("advanced".to_string(), false))
+    );
+
+    // The click reaches the instance of the source
+    assert!(handle.button_clicked("count").unwrap());
+    assert!(handle.button_clicked("count").unwrap());
+    assert_eq!(CLICKS.load(Ordering::SeqCst), 2);
+
+    // Properties without a button or callback don't call into the source
+    assert!(handle.button_clicked("advanced").is_err());
+    assert!(!handle.modified("count", &settings).unwrap());
+    assert_eq!(MODIFIED.lock().unwrap().len(), 2);
+}
@@ -0,0 +1,105 @@
+use std::time::{Duration, Instant};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::raw_audio::{ObsAudioConversion, ObsRawAudioFrame},
+    enums::{ObsSamplesPerSecond, ObsSpeakerLayout},
+    utils::{ObsError, StartupInfo},
+};
+
+/// Integration test: Tap a mixer and check the channels and sample format of the audio
+#[test]
+pub fn test_raw_audio_stream() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("raw_audio_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let source = context.frame_push_source(&mut scene, "raw_tone").unwrap();
+
+    let mut mono = context
+        .raw_audio_stream(
+            0,
+            Some(ObsAudioConversion::new(
+                ObsSamplesPerSecond::F48000,
+                ObsSpeakerLayout::Mono,
+            )),
+            8,
+        )
+        .unwrap();
+    assert_eq!(mono.mix_idx(), 0);
+    assert_eq!(mono.samples_per_sec(), 48000);
+    assert_eq!(mono.speakers(), ObsSpeakerLayout::Mono);
+
+    // A 440 Hz tone at half volume is pushed until it shows up in the tapped audio
+    const CHUNK: usize = 1024;
+    let sample_rate = 44100.0;
+    let start = Instant::now();
+    let mut offset = 0;
+    let mut peak = 0.0f32;
+    while peak < 0.1 {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "The tone did not reach the mixer"
+        );
+
+        let tone = (offset..offset + CHUNK)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / sample_rate).sin() * 0.5)
+            .collect::<Vec<f32>>();
+        source
+            .push_audio(ObsRawAudioFrame::new(
+                vec![tone.clone(), tone],
+                ObsSamplesPerSecond::F44100,
+                ObsSpeakerLayout::Stereo,
+                (offset as f64 / sample_rate as f64 * 1e9) as u64,
+            ))
+            .unwrap();
+        offset += CHUNK;
+
+        while let Some(frame) = mono.try_recv() {
+            assert_eq!(frame.samples_per_sec, 48000);
+            assert_eq!(frame.speakers, ObsSpeakerLayout::Mono);
+            assert_eq!(frame.planes.len(), 1);
+            assert!(frame.frames > 0);
+            assert_eq!(frame.planes[0].len(), frame.frames as usize);
+
+            // Samples are normalized floats, so the tone never exceeds its amplitude
+            for sample in &frame.planes[0] {
+                assert!(sample.is_finite() && sample.abs() <= 1.0);
+                peak = peak.max(sample.abs());
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    // Without a conversion the audio has the layout the context was started with
+    let mut default = context.raw_audio_stream(1, None, 4).unwrap();
+    let start = Instant::now();
+    let frame = loop {
+        if let Some(frame) = default.try_recv() {
+            break frame;
+        }
+
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "No audio was received"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    };
+    assert_eq!(frame.speakers, default.speakers());
+    assert_eq!(frame.planes.len(), default.speakers().channels());
+    assert_eq!(frame.samples_per_sec, default.samples_per_sec());
+
+    assert!(matches!(
+        context.raw_audio_stream(0, None, 0),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        context.raw_audio_stream(libobs::MAX_AUDIO_MIXES as usize, None, 4),
+        Err(ObsError::InvalidOperation(_))
+    ));
+}
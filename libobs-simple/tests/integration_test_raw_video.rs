@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::raw_video::{ObsRawVideoFrame, ObsRawVideoStream, ObsVideoConversion},
+    enums::ObsVideoFormat,
+    utils::{ObsError, StartupInfo},
+};
+
+fn next_frame(stream: &mut ObsRawVideoStream) -> ObsRawVideoFrame {
+    let start = Instant::now();
+    loop {
+        if let Some(frame) = stream.try_recv() {
+            return frame;
+        }
+
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "No frame was received"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Integration test: Tap the program output and check the size and format of the frames
+#[test]
+pub fn test_raw_video_stream() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut bgra = context
+        .raw_video_stream(
+            Some(ObsVideoConversion::new(ObsVideoFormat::BGRA, 320, 180)),
+            1,
+            4,
+        )
+        .unwrap();
+    let mut nv12 = context
+        .raw_video_stream(
+            Some(ObsVideoConversion::new(ObsVideoFormat::NV12, 256, 144)),
+            2,
+            4,
+        )
+        .unwrap();
+    assert_eq!(bgra.format(), ObsVideoFormat::BGRA);
+    assert_eq!(bgra.size(), (320, 180));
+
+    let frame = next_frame(&mut bgra);
+    assert_eq!(frame.format, ObsVideoFormat::BGRA);
+    assert_eq!((frame.width, frame.height), (320, 180));
+    assert_eq!(frame.planes.len(), 1);
+    assert!(frame.linesize[0] >= 320 * 4);
+    assert_eq!(frame.planes[0].len(), frame.linesize[0] as usize * 180);
+
+    let frame = next_frame(&mut nv12);
+    assert_eq!(frame.format, ObsVideoFormat::NV12);
+    assert_eq!((frame.width, frame.height), (256, 144));
+    assert_eq!(frame.planes.len(), 2);
+    assert!(frame.linesize[0] >= 256);
+    assert_eq!(frame.planes[0].len(), frame.linesize[0] as usize * 144);
+    assert_eq!(frame.planes[1].len(), frame.linesize[1] as usize * 72);
+
+    // Without a conversion the frames have the output format and size of the context
+    let mut output = context.raw_video_stream(None, 1, 1).unwrap();
+    let frame = next_frame(&mut output);
+    assert_eq!(frame.format, output.format());
+    assert_eq!((frame.width, frame.height), output.size());
+
+    assert!(matches!(
+        context.raw_video_stream(None, 0, 1),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    assert!(matches!(
+        context.raw_video_stream(None, 1, 0),
+        Err(ObsError::InvalidOperation(_))
+    ));
+}
@@ -0,0 +1,52 @@
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::{ObsIconType, ObsSourceType},
+    utils::StartupInfo,
+};
+
+/// Integration test: Test listing the object types registered by the loaded plugins
+#[test]
+pub fn test_available_types() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let sources = context.available_source_types().unwrap();
+    let scene = sources.iter().find(|t| t.id == "scene").unwrap();
+    assert_eq!(scene.source_type, ObsSourceType::Scene);
+    assert!(scene.output_flags.is_composite());
+
+    let inputs = context.available_input_types().unwrap();
+    assert!(inputs.iter().all(|t| t.source_type == ObsSourceType::Input));
+    let color = inputs.iter().find(|t| t.id == "color_source_v3").unwrap();
+    assert!(color.output_flags.has_video());
+    assert!(!color.output_flags.has_audio());
+    assert_eq!(color.icon_type, ObsIconType::Color);
+    assert!(!color.display_name.is_empty());
+
+    let properties = color.get_properties(context.runtime()).unwrap();
+    assert!(properties.contains_key("color"));
+
+    let filters = context.available_filter_types().unwrap();
+    assert!(filters
+        .iter()
+        .all(|t| t.source_type == ObsSourceType::Filter));
+    assert!(!filters.is_empty());
+
+    let transitions = context.available_transition_types().unwrap();
+    assert!(transitions.iter().any(|t| t.id == "fade_transition"));
+
+    let outputs = context.available_output_types().unwrap();
+    let muxer = outputs.iter().find(|t| t.id == "ffmpeg_muxer").unwrap();
+    assert!(muxer.flags.is_encoded());
+    assert!(!muxer.flags.uses_service());
+
+    let services = context.available_service_types().unwrap();
+    let custom = services.iter().find(|t| t.id == "rtmp_custom").unwrap();
+    assert!(custom
+        .get_properties(context.runtime())
+        .unwrap()
+        .contains_key("server"));
+}
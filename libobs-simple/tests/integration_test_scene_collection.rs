@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use libobs_wrapper::{
+    context::ObsContext,
+    utils::{FilterInfo, ObsError, SourceInfo, StartupInfo, TransitionInfo},
+};
+
+/// Integration test: Save a scene collection and load it into a fresh context
+#[test]
+pub fn test_scene_collection_roundtrip() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let path = std::env::temp_dir().join("libobs_scene_collection_test.json");
+
+    // Everything of the first context is dropped before loading, so nothing is left over
+    {
+        let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+        let mut scene = context.scene("collection_scene").unwrap();
+        let item = scene
+            .add_source(SourceInfo::new(
+                "color_source_v3",
+                "collection_color",
+                None,
+                None,
+            ))
+            .unwrap();
+        item.set_locked(true).unwrap();
+
+        let filter = context
+            .obs_filter(FilterInfo::new(
+                "color_filter_v2",
+                "collection_filter",
+                None,
+                None,
+            ))
+            .unwrap();
+        scene.add_scene_filter(item.source(), &filter).unwrap();
+        scene.set_to_channel(0).unwrap();
+
+        let transition = context
+            .transition(TransitionInfo::new(
+                "fade_transition",
+                "collection_fade",
+                None,
+                None,
+            ))
+            .unwrap();
+        transition.set_duration(Duration::from_millis(500));
+        context.set_output_transition(&transition).unwrap();
+
+        let json = context.save_collection_json("Test Collection").unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["name"], "Test Collection");
+        assert_eq!(parsed["current_scene"], "collection_scene");
+        assert_eq!(parsed["current_transition"], "collection_fade");
+        assert_eq!(parsed["transition_duration"], 500);
+        assert_eq!(parsed["scene_order"][0]["name"], "collection_scene");
+
+        let sources = parsed["sources"].as_array().unwrap();
+        let color = sources
+            .iter()
+            .find(|s| s["name"] == "collection_color")
+            .expect("Color source was not saved");
+        assert_eq!(color["filters"][0]["name"], "collection_filter");
+        assert!(sources.iter().any(|s| s["name"] == "collection_scene"));
+
+        context.save_collection("Test Collection", &path).unwrap();
+
+        // The collection was saved from this context, so all of its names are taken
+        assert!(matches!(
+            context.load_collection(&path),
+            Err(ObsError::InvalidOperation(_))
+        ));
+    }
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let collection = context.load_collection(&path).unwrap();
+
+    assert_eq!(collection.name(), "Test Collection");
+    assert_eq!(collection.scenes().len(), 1);
+    assert_eq!(collection.sources().len(), 1);
+    assert_eq!(collection.transitions().len(), 1);
+
+    let loaded_transition = collection.current_transition().as_ref().unwrap();
+    assert_eq!(loaded_transition.name(), "collection_fade");
+    assert_eq!(loaded_transition.duration(), Duration::from_millis(500));
+    assert!(context.get_transition("collection_fade").unwrap().is_some());
+
+    let loaded_scene = collection.current_scene().as_ref().unwrap();
+    assert_eq!(loaded_scene.name().to_string(), "collection_scene");
+    assert_eq!(collection.scenes()[0], *loaded_scene);
+
+    let loaded_color = loaded_scene
+        .get_source_mut("collection_color")
+        .unwrap()
+        .expect("Scene item was not restored");
+    assert!(collection.sources().contains(&loaded_color));
+    assert!(loaded_scene
+        .get_scene_item(&loaded_color)
+        .unwrap()
+        .is_locked()
+        .unwrap());
+
+    let loaded_filters = loaded_color.filters().unwrap();
+    assert_eq!(loaded_filters.len(), 1);
+    assert_eq!(loaded_filters[0].name(), "collection_filter");
+    assert_eq!(loaded_filters[0].id(), "color_filter_v2");
+
+    // Loading the same collection twice would duplicate every name
+    assert!(matches!(
+        context.load_collection(&path),
+        Err(ObsError::InvalidOperation(_))
+    ));
+    let _ = std::fs::remove_file(&path);
+}
@@ -1,6 +1,14 @@
 mod common;
 
-use libobs_wrapper::{context::ObsContext, utils::StartupInfo};
+use std::time::Duration;
+
+use libobs_wrapper::{
+    context::ObsContext,
+    enums::{ObsBlendingMethod, ObsBlendingType, ObsOrderMovement, ObsScaleType},
+    scenes::ObsSceneItemCrop,
+    sources::ObsSourceRef,
+    utils::{SourceInfo, StartupInfo, TransitionInfo},
+};
 
 /// Integration test: Test creating a scene
 #[test]
@@ -91,3 +99,144 @@ pub fn test_scene_different_channels() {
     let scene1 = context.scene("channel_1_scene").unwrap();
     assert!(scene1.set_to_channel(1).is_ok());
 }
+
+/// Integration test: Test reordering, visibility, crop and blending of scene items
+#[test]
+pub fn test_scene_items() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("scene_item_scene").unwrap();
+
+    let bottom = scene
+        .add_source(SourceInfo::new("color_source_v3", "bottom", None, None))
+        .unwrap();
+    let top = scene
+        .add_source(SourceInfo::new("color_source_v3", "top", None, None))
+        .unwrap();
+
+    // New items are added on top
+    assert_eq!(bottom.index().unwrap(), 0);
+    assert_eq!(top.index().unwrap(), 1);
+
+    top.move_to_index(0).unwrap();
+    assert_eq!(top.index().unwrap(), 0);
+    assert_eq!(bottom.index().unwrap(), 1);
+
+    top.set_order(ObsOrderMovement::MoveTop).unwrap();
+    assert_eq!(top.index().unwrap(), 1);
+
+    assert_eq!(scene.get_scene_item(bottom.source()).unwrap(), bottom);
+
+    top.set_visible(false).unwrap();
+    assert!(!top.is_visible().unwrap());
+
+    top.set_locked(true).unwrap();
+    assert!(top.is_locked().unwrap());
+
+    let crop = ObsSceneItemCrop {
+        left: 10,
+        top: 20,
+        right: 30,
+        bottom: 40,
+    };
+    top.set_crop(crop).unwrap();
+    assert_eq!(top.get_crop().unwrap(), crop);
+
+    top.set_blending_mode(ObsBlendingType::Multiply).unwrap();
+    assert_eq!(top.get_blending_mode().unwrap(), ObsBlendingType::Multiply);
+
+    top.set_blending_method(ObsBlendingMethod::SrgbOff).unwrap();
+    assert_eq!(
+        top.get_blending_method().unwrap(),
+        ObsBlendingMethod::SrgbOff
+    );
+
+    top.set_scale_filter(ObsScaleType::Point).unwrap();
+    assert_eq!(top.get_scale_filter().unwrap(), ObsScaleType::Point);
+
+    let fade = context
+        .transition(TransitionInfo::new(
+            "fade_transition",
+            "item_fade",
+            None,
+            None,
+        ))
+        .unwrap();
+    top.set_transition(true, Some(&fade)).unwrap();
+    top.set_transition_duration(true, Duration::from_millis(500))
+        .unwrap();
+    assert_eq!(
+        top.get_transition_duration(true).unwrap(),
+        Duration::from_millis(500)
+    );
+    top.set_transition(true, None).unwrap();
+
+    // The item keeps its own reference after it was removed from the scene
+    scene.remove_source(bottom.source()).unwrap();
+    assert!(scene.get_scene_item(bottom.source()).is_err());
+    drop(bottom);
+}
+
+/// Integration test: Test looking up sources that exist in libobs
+#[test]
+pub fn test_enumerate_existing_sources() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut scene = context.scene("lookup_scene").unwrap();
+    let item = scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "tracked_color",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    // Not added to any scene, so the wrapper does not track it
+    let untracked = ObsSourceRef::new(
+        "color_source_v3",
+        "untracked_color",
+        None,
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    let sources = context.sources().unwrap();
+    assert!(sources.iter().any(|s| s.name() == "tracked_color"));
+    assert!(sources.iter().any(|s| s.name() == "untracked_color"));
+
+    let tracked = context
+        .get_source_by_name("tracked_color")
+        .unwrap()
+        .unwrap();
+    assert_eq!(&tracked, item.source());
+
+    let uuid = untracked.uuid().unwrap();
+    let found = context.get_source_by_uuid(&uuid).unwrap().unwrap();
+    assert_eq!(found, untracked);
+    assert_eq!(found.id(), "color_source_v3");
+
+    let scene_source = context.get_source_by_name("lookup_scene").unwrap();
+    assert!(scene_source.is_some());
+
+    assert!(context.get_source_by_name("missing").unwrap().is_none());
+    assert!(context.get_source_by_uuid("missing").unwrap().is_none());
+
+    // Dropping a second wrapper of a source keeps the signals of the first one working
+    let mut muted = untracked.signal_manager().on_mute().unwrap();
+    drop(found);
+    drop(context.sources().unwrap());
+    drop(context.get_source_by_name("untracked_color").unwrap());
+
+    untracked.set_muted(true).unwrap();
+    assert!(muted.try_recv().unwrap().muted);
+    assert!(untracked.signal_manager().on_mute().is_ok());
+}
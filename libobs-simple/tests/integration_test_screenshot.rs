@@ -0,0 +1,67 @@
+use libobs_wrapper::{
+    context::ObsContext,
+    sources::ObsSourceRef,
+    utils::{SourceInfo, StartupInfo},
+};
+
+/// Integration test: Test taking a screenshot of a single source
+#[test]
+pub fn test_source_screenshot() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut settings = context.data().unwrap();
+    settings.set_int("width", 64).unwrap();
+    settings.set_int("height", 32).unwrap();
+    // Colors are stored as ABGR
+    settings.set_int("color", 0xFF0000FF).unwrap();
+
+    let source = ObsSourceRef::new(
+        "color_source_v3",
+        "screenshot_color",
+        Some(settings),
+        None,
+        context.runtime().clone(),
+    )
+    .unwrap();
+
+    let screenshot = source.screenshot().unwrap();
+    assert_eq!(screenshot.width, 64);
+    assert_eq!(screenshot.height, 32);
+    assert_eq!(screenshot.data.len(), 64 * 32 * 4);
+    assert_eq!(&screenshot.data[..4], &[255, 0, 0, 255]);
+
+    let png = screenshot.to_png().unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+}
+
+/// Integration test: Test taking a screenshot of the program view
+#[test]
+pub fn test_program_screenshot() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let mut scene = context.scene("screenshot_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+    scene
+        .add_source(SourceInfo::new(
+            "color_source_v3",
+            "program_color",
+            None,
+            None,
+        ))
+        .unwrap();
+
+    let screenshot = context.screenshot_program().unwrap();
+    assert!(screenshot.width > 0 && screenshot.height > 0);
+    assert_eq!(
+        screenshot.data.len(),
+        (screenshot.width * screenshot.height * 4) as usize
+    );
+}
@@ -0,0 +1,219 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use libobs_simple::{
+    output::{simple::ObsContextSimpleExt, streaming::StreamingOutputSettings},
+    services::{ObsServiceBuilder, RtmpCustomServiceBuilder},
+};
+use libobs_wrapper::{context::ObsContext, enums::ObsServiceConnectInfo, utils::StartupInfo};
+
+const RTMP_SIG_SIZE: usize = 1536;
+
+fn amf_string(out: &mut Vec<u8>, value: &str) {
+    out.push(0x02);
+    out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn amf_number(out: &mut Vec<u8>, value: f64) {
+    out.push(0x00);
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn amf_object(out: &mut Vec<u8>, properties: &[(&str, &str)]) {
+    out.push(0x03);
+    for (key, value) in properties {
+        out.extend_from_slice(&(key.len() as u16).to_be_bytes());
+        out.extend_from_slice(key.as_bytes());
+        amf_string(out, value);
+    }
+    out.extend_from_slice(&[0x00, 0x00, 0x09]);
+}
+
+/// Writes an AMF0 command message as a single chunk, so it must fit into the default chunk size
+fn write_command(stream: &mut TcpStream, message_stream_id: u32, payload: &[u8]) {
+    assert!(payload.len() <= 128);
+
+    let mut chunk = vec![0x03, 0, 0, 0];
+    chunk.extend_from_slice(&(payload.len() as u32).to_be_bytes()[1..]);
+    chunk.push(0x14);
+    chunk.extend_from_slice(&message_stream_id.to_le_bytes());
+    chunk.extend_from_slice(payload);
+    stream.write_all(&chunk).unwrap();
+}
+
+/// Accepts a single client, checks its RTMP handshake and answers just enough
+/// commands for it to start publishing. Everything the client sends afterwards is discarded.
+fn serve_rtmp(listener: TcpListener, handshake_done: mpsc::Sender<()>) -> usize {
+    listener.set_nonblocking(true).unwrap();
+    let start = Instant::now();
+    let mut stream = loop {
+        match listener.accept() {
+            Ok((stream, _)) => break stream,
+            Err(_) => {
+                assert!(
+                    start.elapsed() < Duration::from_secs(10),
+                    "Output did not connect"
+                );
+                thread::sleep(Duration::from_millis(20));
+            }
+        }
+    };
+    stream.set_nonblocking(false).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+
+    // C0 carries the RTMP version, C1 the signature that S2 echoes back
+    let mut c0_c1 = vec![0; 1 + RTMP_SIG_SIZE];
+    stream.read_exact(&mut c0_c1).unwrap();
+    assert_eq!(c0_c1[0], 3, "Client did not request RTMP version 3");
+
+    // A zero version in S1 makes the client use the plain handshake
+    let mut response = vec![3];
+    response.extend_from_slice(&[0; RTMP_SIG_SIZE]);
+    response.extend_from_slice(&c0_c1[1..]);
+    stream.write_all(&response).unwrap();
+
+    let mut c2 = vec![0; RTMP_SIG_SIZE];
+    stream.read_exact(&mut c2).unwrap();
+    handshake_done.send(()).unwrap();
+
+    // The client numbers its commands: connect is 1, createStream is 4
+    // after releaseStream and FCPublish
+    let mut connect_result = Vec::new();
+    amf_string(&mut connect_result, "_result");
+    amf_number(&mut connect_result, 1.0);
+    connect_result.push(0x05);
+    amf_object(
+        &mut connect_result,
+        &[
+            ("level", "status"),
+            ("code", "NetConnection.Connect.Success"),
+        ],
+    );
+    write_command(&mut stream, 0, &connect_result);
+
+    let mut create_stream_result = Vec::new();
+    amf_string(&mut create_stream_result, "_result");
+    amf_number(&mut create_stream_result, 4.0);
+    create_stream_result.push(0x05);
+    amf_number(&mut create_stream_result, 1.0);
+    write_command(&mut stream, 0, &create_stream_result);
+
+    let mut publish_start = Vec::new();
+    amf_string(&mut publish_start, "onStatus");
+    amf_number(&mut publish_start, 0.0);
+    publish_start.push(0x05);
+    amf_object(
+        &mut publish_start,
+        &[("level", "status"), ("code", "NetStream.Publish.Start")],
+    );
+    write_command(&mut stream, 1, &publish_start);
+
+    // Drain until the output closes the connection
+    let mut received = 0;
+    let mut buffer = [0; 4096];
+    while let Ok(read) = stream.read(&mut buffer) {
+        if read == 0 {
+            break;
+        }
+        received += read;
+    }
+
+    received
+}
+
+/// Integration test: Create custom services for RTMP and SRT and stream to a local RTMP server
+#[test]
+pub fn test_streaming_output() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let rtmp_url = format!("rtmp://127.0.0.1:{}/live", port);
+    let rtmp_service = context
+        .service_builder::<RtmpCustomServiceBuilder, _>("rtmp_service")
+        .unwrap()
+        .set_server(rtmp_url.as_str())
+        .set_key("test_key")
+        .add_to_context(&mut context)
+        .unwrap();
+
+    assert_eq!(rtmp_service.protocol().unwrap().as_deref(), Some("RTMP"));
+    assert_eq!(
+        rtmp_service
+            .connect_info(ObsServiceConnectInfo::ServerUrl)
+            .unwrap(),
+        Some(rtmp_url)
+    );
+    assert_eq!(
+        rtmp_service
+            .connect_info(ObsServiceConnectInfo::StreamKey)
+            .unwrap()
+            .as_deref(),
+        Some("test_key")
+    );
+    assert!(rtmp_service.can_try_to_connect().unwrap());
+    assert!(context.get_service("rtmp_service").unwrap().is_some());
+
+    let mut rtmp_output = context
+        .streaming_output_builder("rtmp_output", rtmp_service)
+        .video_bitrate(2500)
+        .build()
+        .unwrap();
+
+    assert_eq!(rtmp_output.id().to_string(), "rtmp_output");
+    assert!(rtmp_output.get_current_service().unwrap().is_some());
+
+    let (handshake_tx, handshake_rx) = mpsc::channel();
+    let server = thread::spawn(move || serve_rtmp(listener, handshake_tx));
+
+    rtmp_output.start().unwrap();
+    handshake_rx
+        .recv_timeout(Duration::from_secs(10))
+        .expect("Output did not complete the RTMP handshake");
+
+    // The output becomes active once the server accepted the stream
+    let start = Instant::now();
+    while !rtmp_output.is_active().unwrap() {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Output did not start publishing"
+        );
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    rtmp_output.stop().unwrap();
+    assert!(!rtmp_output.is_active().unwrap());
+
+    let received = server.join().unwrap();
+    assert!(received > 0, "Output did not send any data");
+
+    let srt_service = context
+        .service_builder::<RtmpCustomServiceBuilder, _>("srt_service")
+        .unwrap()
+        .set_server(format!("srt://127.0.0.1:{}", port))
+        .add_to_context(&mut context)
+        .unwrap();
+
+    assert_eq!(srt_service.protocol().unwrap().as_deref(), Some("SRT"));
+
+    let srt_output = context
+        .streaming_output_builder("unused_name", srt_service)
+        .settings(StreamingOutputSettings::new("srt_output").with_keyframe_interval(1))
+        .build()
+        .unwrap();
+
+    assert_eq!(srt_output.id().to_string(), "ffmpeg_mpegts_muxer");
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use libobs_simple::transitions::{
+    CutTransitionBuilder, FadeTransitionBuilder, ObsTransitionDirection, SwipeTransitionBuilder,
+};
+use libobs_wrapper::{context::ObsContext, transitions::ObsTransitionBuilder, utils::StartupInfo};
+
+/// Integration test: Create a transition and switch scenes with it
+#[test]
+pub fn test_transition_to_scene() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let first = context.scene("first_scene").unwrap();
+    let second = context.scene("second_scene").unwrap();
+    first.set_to_channel(0).unwrap();
+
+    let fade = context
+        .transition_builder::<FadeTransitionBuilder, _>("fade")
+        .unwrap()
+        .add_to_context(&mut context)
+        .unwrap();
+
+    fade.set_duration(Duration::from_millis(500));
+    assert_eq!(fade.duration(), Duration::from_millis(500));
+    assert!(!fade.is_fixed().unwrap());
+
+    // Transitioning without an output transition must fail
+    assert!(context
+        .transition_to(&second, Duration::from_millis(100))
+        .is_err());
+
+    context.set_output_transition(&fade).unwrap();
+    assert_eq!(context.output_transition().unwrap(), Some(fade.clone()));
+
+    context
+        .transition_to(&second, Duration::from_millis(100))
+        .unwrap();
+    fade.force_stop().unwrap();
+}
+
+/// Integration test: Create every builder-backed transition
+#[test]
+pub fn test_transition_builders() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+
+    let cut = context
+        .transition_builder::<CutTransitionBuilder, _>("cut")
+        .unwrap()
+        .add_to_context(&mut context)
+        .unwrap();
+    assert!(cut.is_fixed().unwrap());
+
+    let swipe = context
+        .transition_builder::<SwipeTransitionBuilder, _>("swipe")
+        .unwrap()
+        .set_direction(ObsTransitionDirection::Up)
+        .set_swipe_in(true)
+        .add_to_context(&mut context)
+        .unwrap();
+
+    assert_eq!(context.get_transition("swipe").unwrap(), Some(swipe));
+}
@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use libobs_wrapper::{
+    context::ObsContext,
+    data::raw_audio::ObsRawAudioFrame,
+    enums::{ObsFaderType, ObsSamplesPerSecond, ObsSpeakerLayout},
+    utils::StartupInfo,
+};
+use tokio::sync::broadcast::error::TryRecvError;
+
+/// Integration test: Attach a volume meter to a source, receive its levels and detach it again
+#[test]
+pub fn test_volume_meter_levels() {
+    let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug"))
+        .is_test(true)
+        .try_init();
+
+    let mut context = ObsContext::new(StartupInfo::default()).unwrap();
+    let mut scene = context.scene("volume_meter_scene").unwrap();
+    scene.set_to_channel(0).unwrap();
+
+    let source = context
+        .frame_push_source(&mut scene, "metered_tone")
+        .unwrap();
+
+    let meter = source.as_source().volume_meter(ObsFaderType::IEC).unwrap();
+    let mut levels = meter.subscribe().unwrap();
+
+    // A 440 Hz tone, pushed in chunks until the meter reported the first levels
+    const CHUNK: usize = 1024;
+    let sample_rate = ObsSamplesPerSecond::F44100 as u32 as f32;
+    let chunk_duration = Duration::from_secs_f32(CHUNK as f32 / sample_rate);
+
+    let start = Instant::now();
+    let mut offset = 0;
+    let received = loop {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "No levels were reported"
+        );
+
+        let tone = (offset..offset + CHUNK)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / sample_rate).sin() * 0.5)
+            .collect::<Vec<f32>>();
+        let timestamp = (offset as f64 / sample_rate as f64 * 1e9) as u64;
+        source
+            .push_audio(ObsRawAudioFrame::new(
+                vec![tone.clone(), tone],
+                ObsSamplesPerSecond::F44100,
+                ObsSpeakerLayout::Stereo,
+                timestamp,
+            ))
+            .unwrap();
+        offset += CHUNK;
+
+        match levels.try_recv() {
+            Ok(received) => break received,
+            Err(TryRecvError::Empty) | Err(TryRecvError::Lagged(_)) => {
+                std::thread::sleep(chunk_duration)
+            }
+            Err(e) => panic!("Volume meter stopped unexpectedly: {:?}", e),
+        }
+    };
+
+    let channels = meter.channels().unwrap();
+    assert!(channels > 0);
+    assert_eq!(received.magnitude.len(), channels);
+    assert_eq!(received.peak.len(), channels);
+    assert_eq!(received.input_peak.len(), channels);
+
+    // Dropping the last handle detaches the meter and closes the receivers
+    drop(meter);
+    let start = Instant::now();
+    loop {
+        match levels.try_recv() {
+            Err(TryRecvError::Closed) => break,
+            Ok(_) | Err(TryRecvError::Lagged(_)) => {}
+            Err(TryRecvError::Empty) => {
+                assert!(
+                    start.elapsed() < Duration::from_secs(5),
+                    "Volume meter was not detached"
+                );
+                std::thread::sleep(Duration::from_millis(20));
+            }
+        }
+    }
+}
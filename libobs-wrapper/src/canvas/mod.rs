@@ -0,0 +1,365 @@
+//! Additional canvases with their own video mix
+//!
+//! Every canvas renders its own channels at its own resolution and frame
+//! rate, independently from the main video of the context. This allows for
+//! example recording a vertical composition alongside the landscape one,
+//! reusing the same sources in both.
+//!
+//! Scenes of a canvas are created with `ObsCanvasRef::scene` and assigned to
+//! the channels of the canvas with `ObsCanvasRef::set_channel` or
+//! `ObsSceneRef::set_to_channel`. Outputs use the video of the canvas after
+//! calling `ObsOutputRef::set_canvas`.
+use std::{
+    collections::HashMap,
+    ffi::CStr,
+    hash::Hash,
+    sync::{Arc, RwLock, Weak},
+};
+
+use libobs::{obs_canvas_t, video_output};
+
+use crate::{
+    context::ObsContext,
+    data::video::ObsVideoInfo,
+    enums::ObsResetVideoStatus,
+    impl_obs_drop,
+    macros::impl_eq_of_ptr,
+    run_with_obs,
+    runtime::ObsRuntime,
+    scenes::ObsSceneRef,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+#[derive(Debug)]
+struct _ObsCanvasDropGuard {
+    canvas: Sendable<*mut obs_canvas_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsCanvasDropGuard, (canvas), move || unsafe {
+    libobs::obs_canvas_remove(canvas);
+    libobs::obs_canvas_release(canvas);
+});
+
+/// A canvas with its own video mix. The canvas is reference counted,
+/// so it is safe to clone this struct.
+#[derive(Debug, Clone)]
+pub struct ObsCanvasRef {
+    canvas: Sendable<*mut obs_canvas_t>,
+    name: ObsString,
+
+    /// Kept alive, as the graphics module string is not copied by libobs
+    video_info: Arc<RwLock<ObsVideoInfo>>,
+
+    /// Scenes that were created on this canvas
+    scenes: Arc<RwLock<Vec<ObsSceneRef>>>,
+
+    /// Scenes assigned to the channels of this canvas
+    channels: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
+
+    _guard: Arc<_ObsCanvasDropGuard>,
+    runtime: ObsRuntime,
+}
+
+impl_eq_of_ptr!(ObsCanvasRef, canvas);
+
+impl ObsCanvasRef {
+    pub(crate) fn new(
+        name: ObsString,
+        video_info: ObsVideoInfo,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        // Same flags as the main canvas, except that it is not the main one.
+        // The flags are signed on windows, but the function takes an u32
+        #[allow(clippy::unnecessary_cast)]
+        let flags = libobs::obs_canvas_flags_PROGRAM as u32;
+
+        let name_ptr = name.as_ptr();
+        let ovi_ptr = Sendable(video_info.as_ptr());
+        let canvas = run_with_obs!(runtime, (name_ptr, ovi_ptr), move || unsafe {
+            Sendable(libobs::obs_canvas_create(name_ptr, ovi_ptr, flags))
+        })?;
+
+        if canvas.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        Ok(Self {
+            canvas: canvas.clone(),
+            name,
+            video_info: Arc::new(RwLock::new(video_info)),
+            scenes: Default::default(),
+            channels: Default::default(),
+            _guard: Arc::new(_ObsCanvasDropGuard {
+                canvas,
+                runtime: runtime.clone(),
+            }),
+            runtime,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    /// The UUID libobs assigned to this canvas.
+    pub fn uuid(&self) -> Result<String, ObsError> {
+        let canvas_ptr = self.canvas.clone();
+        run_with_obs!(self.runtime, (canvas_ptr), move || unsafe {
+            let uuid = libobs::obs_canvas_get_uuid(canvas_ptr);
+            if uuid.is_null() {
+                return String::new();
+            }
+
+            CStr::from_ptr(uuid).to_string_lossy().to_string()
+        })
+    }
+
+    /// Returns the width and height scenes of this canvas are composed in.
+    pub fn base_size(&self) -> Result<(u32, u32), ObsError> {
+        let ovi = self
+            .video_info
+            .read()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        Ok((ovi.get_base_width(), ovi.get_base_height()))
+    }
+
+    /// Returns the scaled width and height that is passed to encoders.
+    pub fn output_size(&self) -> Result<(u32, u32), ObsError> {
+        let ovi = self
+            .video_info
+            .read()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        Ok((ovi.get_output_width(), ovi.get_output_height()))
+    }
+
+    /// Creates a new scene that belongs to this canvas. The scene has to be
+    /// assigned to a channel of this canvas using `set_channel` to be rendered.
+    pub fn scene<T: Into<ObsString> + Send + Sync>(
+        &self,
+        name: T,
+    ) -> Result<ObsSceneRef, ObsError> {
+        let scene =
+            ObsSceneRef::new_in_canvas(name.into(), self.downgrade(), self.runtime.clone())?;
+
+        self.scenes
+            .write()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+            .push(scene.clone());
+
+        Ok(scene)
+    }
+
+    /// Gets a scene of this canvas by name.
+    pub fn get_scene(&self, name: &str) -> Result<Option<ObsSceneRef>, ObsError> {
+        let r = self
+            .scenes
+            .read()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+            .iter()
+            .find(|s| s.name().to_string().as_str() == name)
+            .cloned();
+
+        Ok(r)
+    }
+
+    /// Assigns the scene to the given channel of this canvas. Like the
+    /// channels of the main video, channels are drawn on top of each
+    /// other in ascending order.
+    pub fn set_channel(&self, channel: u32, scene: &ObsSceneRef) -> Result<(), ObsError> {
+        set_channel(
+            &self.runtime,
+            &self.canvas,
+            &self.channels,
+            channel,
+            Some(scene),
+        )
+    }
+
+    /// Removes whatever is assigned to the given channel of this canvas.
+    pub fn clear_channel(&self, channel: u32) -> Result<(), ObsError> {
+        set_channel(&self.runtime, &self.canvas, &self.channels, channel, None)
+    }
+
+    /// Returns the scene that is assigned to the given channel, if any.
+    pub fn get_channel(&self, channel: u32) -> Result<Option<ObsSceneRef>, ObsError> {
+        let r = self
+            .channels
+            .read()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+            .get(&channel)
+            .cloned();
+
+        Ok(r)
+    }
+
+    /// Changes the resolution and frame rate of this canvas. This fails
+    /// if an output is currently using the video of this canvas.
+    pub fn reset_video(&self, video_info: ObsVideoInfo) -> Result<(), ObsError> {
+        let canvas_ptr = self.canvas.clone();
+        let ovi_ptr = Sendable(video_info.as_ptr());
+        let success = run_with_obs!(self.runtime, (canvas_ptr, ovi_ptr), move || unsafe {
+            libobs::obs_canvas_reset_video(canvas_ptr, ovi_ptr)
+        })?;
+
+        if !success {
+            return Err(ObsError::ResetVideoFailure(ObsResetVideoStatus::Failure));
+        }
+
+        *self
+            .video_info
+            .write()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))? = video_info;
+
+        Ok(())
+    }
+
+    /// Returns a pointer to the video output of this canvas.
+    ///
+    /// # Safety
+    /// The pointer is only valid as long as this canvas exists
+    /// and its video has not been reset.
+    pub unsafe fn get_video_ptr(&self) -> Result<Sendable<*mut video_output>, ObsError> {
+        let canvas_ptr = self.canvas.clone();
+        run_with_obs!(self.runtime, (canvas_ptr), move || unsafe {
+            Sendable(libobs::obs_canvas_get_video(canvas_ptr))
+        })
+    }
+
+    pub fn as_ptr(&self) -> Sendable<*mut obs_canvas_t> {
+        self.canvas.clone()
+    }
+
+    fn downgrade(&self) -> ObsCanvasWeakRef {
+        ObsCanvasWeakRef {
+            canvas: self.canvas.clone(),
+            channels: Arc::downgrade(&self.channels),
+            _guard: Arc::downgrade(&self._guard),
+        }
+    }
+}
+
+/// The canvas a scene was created on. The canvas holds its scenes,
+/// so scenes only keep a weak reference to it.
+#[derive(Debug, Clone)]
+pub(crate) struct ObsCanvasWeakRef {
+    canvas: Sendable<*mut obs_canvas_t>,
+    channels: Weak<RwLock<HashMap<u32, ObsSceneRef>>>,
+    _guard: Weak<_ObsCanvasDropGuard>,
+}
+
+impl ObsCanvasWeakRef {
+    pub(crate) fn as_ptr(&self) -> Sendable<*mut obs_canvas_t> {
+        self.canvas.clone()
+    }
+
+    /// Same as `ObsCanvasRef::set_channel`, `None` clears the channel.
+    pub(crate) fn set_channel(
+        &self,
+        runtime: &ObsRuntime,
+        channel: u32,
+        scene: Option<&ObsSceneRef>,
+    ) -> Result<(), ObsError> {
+        // The guard keeps the canvas alive until the channel is set
+        let (Some(_guard), Some(channels)) = (self._guard.upgrade(), self.channels.upgrade())
+        else {
+            return Err(ObsError::InvalidOperation(
+                "The canvas of this scene has already been dropped".to_string(),
+            ));
+        };
+
+        set_channel(runtime, &self.canvas, &channels, channel, scene)
+    }
+}
+
+fn set_channel(
+    runtime: &ObsRuntime,
+    canvas: &Sendable<*mut obs_canvas_t>,
+    channels: &RwLock<HashMap<u32, ObsSceneRef>>,
+    channel: u32,
+    scene: Option<&ObsSceneRef>,
+) -> Result<(), ObsError> {
+    check_channel(channel)?;
+
+    let canvas_ptr = canvas.clone();
+    let scene_source_ptr = match scene {
+        Some(scene) => scene.get_scene_source_ptr()?,
+        None => Sendable(std::ptr::null_mut()),
+    };
+    run_with_obs!(runtime, (canvas_ptr, scene_source_ptr), move || unsafe {
+        libobs::obs_canvas_set_channel(canvas_ptr, channel, scene_source_ptr);
+    })?;
+
+    let mut channels = channels
+        .write()
+        .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+    match scene {
+        Some(scene) => channels.insert(channel, scene.clone()),
+        None => channels.remove(&channel),
+    };
+
+    Ok(())
+}
+
+fn check_channel(channel: u32) -> Result<(), ObsError> {
+    if channel >= libobs::MAX_CHANNELS {
+        return Err(ObsError::InvalidOperation(format!(
+            "Channel {} is out of bounds (max {})",
+            channel,
+            libobs::MAX_CHANNELS - 1
+        )));
+    }
+
+    Ok(())
+}
+
+impl ObsContext {
+    /// Creates a new canvas with its own video mix. Sources can be shared
+    /// between the main video and all canvases.
+    pub fn canvas<T: Into<ObsString> + Send + Sync>(
+        &mut self,
+        name: T,
+        video_info: ObsVideoInfo,
+    ) -> Result<ObsCanvasRef, ObsError> {
+        let canvas = ObsCanvasRef::new(name.into(), video_info, self.runtime.clone())?;
+
+        self.canvases
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on canvases".to_string())
+            })?
+            .push(canvas.clone());
+
+        Ok(canvas)
+    }
+
+    pub fn get_canvas(&self, name: &str) -> Result<Option<ObsCanvasRef>, ObsError> {
+        let r = self
+            .canvases
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on canvases".to_string())
+            })?
+            .iter()
+            .find(|c| c.name.to_string().as_str() == name)
+            .cloned();
+
+        Ok(r)
+    }
+
+    /// Removes the canvas from the context. It is destroyed once
+    /// all references to it are dropped.
+    pub fn remove_canvas(&mut self, canvas: &ObsCanvasRef) -> Result<(), ObsError> {
+        self.canvases
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on canvases".to_string())
+            })?
+            .retain(|c| c != canvas);
+
+        Ok(())
+    }
+}
@@ -43,17 +43,26 @@ use std::{
     thread::ThreadId,
 };
 
+use crate::canvas::ObsCanvasRef;
 use crate::display::{ObsDisplayCreationData, ObsDisplayRef};
+#[cfg(feature = "async")]
+use crate::run_with_obs_async;
 use crate::{
-    data::{output::ObsOutputRef, video::ObsVideoInfo, ObsData},
+    data::{output::ObsOutputRef, video::ObsVideoInfo, ObsData, ObsDataArray},
     enums::{ObsLogLevel, ObsResetVideoStatus},
+    hotkeys::ObsHotkeyManager,
     logger::LOGGER,
     run_with_obs,
     runtime::ObsRuntime,
     scenes::ObsSceneRef,
+    services::{ObsServiceBuilder, ObsServiceRef},
     sources::{ObsFilterRef, ObsSourceBuilder},
+    transitions::{ObsTransitionBuilder, ObsTransitionRef},
     unsafe_send::Sendable,
-    utils::{FilterInfo, ObsError, ObsModules, ObsString, OutputInfo, StartupInfo},
+    utils::{
+        FilterInfo, ObsError, ObsModules, ObsString, OutputInfo, ServiceInfo, StartupInfo,
+        TransitionInfo,
+    },
 };
 use getters0::Getters;
 use libobs::{audio_output, video_output};
@@ -88,13 +97,33 @@ pub struct ObsContext {
     #[get_mut]
     pub(crate) outputs: Arc<RwLock<Vec<ObsOutputRef>>>,
 
+    /// Services are released after the outputs that use them
+    #[get_mut]
+    pub(crate) services: Arc<RwLock<Vec<ObsServiceRef>>>,
+
     #[get_mut]
     pub(crate) scenes: Arc<RwLock<Vec<ObsSceneRef>>>,
 
+    /// Canvases are released after the outputs that render them
+    #[get_mut]
+    pub(crate) canvases: Arc<RwLock<Vec<ObsCanvasRef>>>,
+
     // Filters are on the level of the context because they are not scene-specific
     #[get_mut]
     pub(crate) filters: Arc<RwLock<Vec<ObsFilterRef>>>,
 
+    #[get_mut]
+    pub(crate) transitions: Arc<RwLock<Vec<ObsTransitionRef>>>,
+
+    #[skip_getter]
+    /// The transition that is currently bound to output channel 0
+    pub(crate) output_transition: Arc<RwLock<Option<ObsTransitionRef>>>,
+
+    /// Frontend hotkeys have to be unregistered before
+    /// the runtime shuts down.
+    #[skip_getter]
+    pub(crate) hotkey_manager: ObsHotkeyManager,
+
     #[skip_getter]
     /// Contains active scenes mapped by their channel they are bound to
     pub(crate) active_scenes: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
@@ -183,8 +212,13 @@ impl ObsContext {
             active_scenes: active_scenes.clone(),
             displays: Default::default(),
             outputs: Default::default(),
+            services: Default::default(),
             scenes: Default::default(),
+            canvases: Default::default(),
             filters: Default::default(),
+            transitions: Default::default(),
+            output_transition: Default::default(),
+            hotkey_manager: ObsHotkeyManager::new(runtime.clone()),
             runtime: runtime.clone(),
             startup_info: Arc::new(RwLock::new(info)),
             #[cfg(target_os = "linux")]
@@ -227,6 +261,38 @@ impl ObsContext {
     /// without destroying the entire OBS context. Trying
     /// so will result in an error.
     pub fn reset_video(&mut self, ovi: ObsVideoInfo) -> Result<(), ObsError> {
+        self.check_reset_video(&ovi)?;
+
+        // Resets the video context. Note that this
+        // is similar to Self::reset_video, but it
+        // does not call that function because the
+        // ObsContext struct is not created yet,
+        // and also because there is no need to free
+        // anything tied to the OBS context.
+        let vid_ptr = Sendable(ovi.as_ptr());
+        let reset_video_status = run_with_obs!(self.runtime, (vid_ptr), move || unsafe {
+            libobs::obs_reset_video(vid_ptr)
+        })?;
+
+        self.finish_reset_video(reset_video_status, ovi)
+    }
+
+    /// Async version of `reset_video`. Resetting the video
+    /// context recreates the render textures, which is awaited
+    /// instead of blocking.
+    #[cfg(feature = "async")]
+    pub async fn reset_video_async(&mut self, ovi: ObsVideoInfo) -> Result<(), ObsError> {
+        self.check_reset_video(&ovi)?;
+
+        let vid_ptr = Sendable(ovi.as_ptr());
+        let reset_video_status = run_with_obs_async!(self.runtime, (vid_ptr), move || unsafe {
+            libobs::obs_reset_video(vid_ptr)
+        })?;
+
+        self.finish_reset_video(reset_video_status, ovi)
+    }
+
+    fn check_reset_video(&self, ovi: &ObsVideoInfo) -> Result<(), ObsError> {
         // You cannot change the graphics module without
         // completely destroying the entire OBS context.
         if self
@@ -256,17 +322,14 @@ impl ObsContext {
             return Err(ObsError::ResetVideoFailureOutputActive);
         }
 
-        // Resets the video context. Note that this
-        // is similar to Self::reset_video, but it
-        // does not call that function because the
-        // ObsContext struct is not created yet,
-        // and also because there is no need to free
-        // anything tied to the OBS context.
-        let vid_ptr = Sendable(ovi.as_ptr());
-        let reset_video_status = run_with_obs!(self.runtime, (vid_ptr), move || unsafe {
-            libobs::obs_reset_video(vid_ptr)
-        })?;
+        Ok(())
+    }
 
+    fn finish_reset_video(
+        &self,
+        reset_video_status: i32,
+        ovi: ObsVideoInfo,
+    ) -> Result<(), ObsError> {
         let reset_video_status = num_traits::FromPrimitive::from_i32(reset_video_status);
 
         let reset_video_status = match reset_video_status {
@@ -314,6 +377,10 @@ impl ObsContext {
         ObsData::new(self.runtime.clone())
     }
 
+    pub fn data_array(&self) -> Result<ObsDataArray, ObsError> {
+        ObsDataArray::new(self.runtime.clone())
+    }
+
     pub fn output(&mut self, info: OutputInfo) -> Result<ObsOutputRef, ObsError> {
         let output = ObsOutputRef::new(info, self.runtime.clone());
 
@@ -333,6 +400,21 @@ impl ObsContext {
         }
     }
 
+    /// Async version of `output`, creating the output is awaited instead of blocking.
+    #[cfg(feature = "async")]
+    pub async fn output_async(&mut self, info: OutputInfo) -> Result<ObsOutputRef, ObsError> {
+        let output = ObsOutputRef::new_async(info, self.runtime.clone()).await?;
+
+        self.outputs
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on outputs".to_string())
+            })?
+            .push(output.clone());
+
+        Ok(output)
+    }
+
     pub fn obs_filter(&mut self, info: FilterInfo) -> Result<ObsFilterRef, ObsError> {
         let filter = ObsFilterRef::new(
             info.id,
@@ -519,6 +601,41 @@ impl ObsContext {
         }
     }
 
+    /// Creates a streaming service, attach it to an output using `ObsOutputRef::set_service`.
+    pub fn service(&mut self, info: ServiceInfo) -> Result<ObsServiceRef, ObsError> {
+        let service = ObsServiceRef::new(info, self.runtime.clone())?;
+
+        self.services
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on services".to_string())
+            })?
+            .push(service.clone());
+
+        Ok(service)
+    }
+
+    pub fn get_service(&self, name: &str) -> Result<Option<ObsServiceRef>, ObsError> {
+        let s = self
+            .services
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on services".to_string())
+            })?
+            .iter()
+            .find(|x| x.name() == name)
+            .cloned();
+
+        Ok(s)
+    }
+
+    pub fn service_builder<T: ObsServiceBuilder, K: Into<ObsString> + Send + Sync>(
+        &self,
+        name: K,
+    ) -> Result<T, ObsError> {
+        T::new(name.into(), self.runtime.clone())
+    }
+
     pub fn get_filter(&mut self, name: &str) -> Result<Option<ObsFilterRef>, ObsError> {
         let f = self
             .filters
@@ -550,6 +667,28 @@ impl ObsContext {
         Ok(tmp)
     }
 
+    /// Async version of `scene`, creating the scene is awaited instead of blocking.
+    #[cfg(feature = "async")]
+    pub async fn scene_async<T: Into<ObsString> + Send + Sync>(
+        &mut self,
+        name: T,
+    ) -> Result<ObsSceneRef, ObsError> {
+        let scene = ObsSceneRef::new_async(
+            name.into(),
+            self.active_scenes.clone(),
+            self.runtime.clone(),
+        )
+        .await?;
+
+        let tmp = scene.clone();
+        self.scenes
+            .write()
+            .map_err(|_| ObsError::LockError("Failed to acquire write lock on scenes".to_string()))?
+            .push(scene);
+
+        Ok(tmp)
+    }
+
     pub fn get_scene(&mut self, name: &str) -> Result<Option<ObsSceneRef>, ObsError> {
         let r = self
             .scenes
@@ -561,6 +700,115 @@ impl ObsContext {
         Ok(r)
     }
 
+    pub fn transition(&mut self, info: TransitionInfo) -> Result<ObsTransitionRef, ObsError> {
+        let transition = ObsTransitionRef::new(info, self.runtime.clone())?;
+
+        self.transitions
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on transitions".to_string())
+            })?
+            .push(transition.clone());
+
+        Ok(transition)
+    }
+
+    pub fn get_transition(&self, name: &str) -> Result<Option<ObsTransitionRef>, ObsError> {
+        let t = self
+            .transitions
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on transitions".to_string())
+            })?
+            .iter()
+            .find(|x| x.name() == name)
+            .cloned();
+
+        Ok(t)
+    }
+
+    pub fn transition_builder<T: ObsTransitionBuilder, K: Into<ObsString> + Send + Sync>(
+        &self,
+        name: K,
+    ) -> Result<T, ObsError> {
+        T::new(name.into(), self.runtime.clone())
+    }
+
+    /// Binds the given transition to output channel 0. Whatever is currently
+    /// shown on channel 0 is kept as the active source of the transition, so
+    /// the output does not change until `transition_to` is called.
+    ///
+    /// Note that calling `ObsSceneRef::set_to_channel(0)` afterwards replaces
+    /// the transition again.
+    pub fn set_output_transition(&mut self, transition: &ObsTransitionRef) -> Result<(), ObsError> {
+        let transition_ptr = Sendable(transition.as_ptr());
+        run_with_obs!(self.runtime, (transition_ptr), move || unsafe {
+            let old = libobs::obs_get_output_source(0);
+            if !old.is_null() {
+                let active = if libobs::obs_source_get_type(old)
+                    == libobs::obs_source_type_OBS_SOURCE_TYPE_TRANSITION
+                {
+                    libobs::obs_transition_get_active_source(old)
+                } else {
+                    libobs::obs_source_get_ref(old)
+                };
+
+                if active != transition_ptr {
+                    libobs::obs_transition_set(transition_ptr, active);
+                }
+
+                libobs::obs_source_release(active);
+                libobs::obs_source_release(old);
+            }
+
+            libobs::obs_set_output_source(0, transition_ptr);
+        })?;
+
+        self.output_transition
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on output transition".to_string())
+            })?
+            .replace(transition.clone());
+
+        Ok(())
+    }
+
+    /// Returns the transition bound to output channel 0, if any.
+    pub fn output_transition(&self) -> Result<Option<ObsTransitionRef>, ObsError> {
+        let t = self
+            .output_transition
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on output transition".to_string())
+            })?
+            .clone();
+
+        Ok(t)
+    }
+
+    /// Transitions output channel 0 to the given scene using the transition
+    /// set with `set_output_transition`.
+    pub fn transition_to(
+        &self,
+        scene: &ObsSceneRef,
+        duration: std::time::Duration,
+    ) -> Result<(), ObsError> {
+        let transition = self.output_transition()?.ok_or_else(|| {
+            ObsError::InvalidOperation(
+                "No output transition set, call set_output_transition first".to_string(),
+            )
+        })?;
+
+        transition.start(scene, Some(duration))
+    }
+
+    /// Returns the hotkey manager of this context, which can be used to
+    /// enumerate, register, bind and trigger hotkeys.
+    pub fn hotkey_manager(&self) -> ObsHotkeyManager {
+        self.hotkey_manager.clone()
+    }
+
     pub fn source_builder<T: ObsSourceBuilder, K: Into<ObsString> + Send + Sync>(
         &self,
         name: K,
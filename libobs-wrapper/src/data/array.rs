@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use libobs::obs_data_array_t;
+
+use crate::{
+    impl_obs_drop, run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError,
+};
+
+use super::ObsData;
+
+#[derive(Debug)]
+struct _ObsDataArrayDropGuard {
+    array: Sendable<*mut obs_data_array_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsDataArrayDropGuard, (array), move || unsafe {
+    libobs::obs_data_array_release(array)
+});
+
+/// Wrapper around `obs_data_array`, a list of `obs_data` objects.
+/// Used by settings like editable lists, the filters of a source or
+/// the items of a scene.
+///
+/// Cloning this struct does not copy the array, both clones refer
+/// to the same `obs_data_array`.
+#[derive(Debug, Clone)]
+pub struct ObsDataArray {
+    array: Sendable<*mut obs_data_array_t>,
+    pub(crate) runtime: ObsRuntime,
+    _drop_guard: Arc<_ObsDataArrayDropGuard>,
+}
+
+impl ObsDataArray {
+    /// Creates a new empty array.
+    pub fn new(runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let array = run_with_obs!(runtime, move || unsafe {
+            Sendable(libobs::obs_data_array_create())
+        })?;
+
+        Ok(Self::from_raw(array, runtime))
+    }
+
+    /// Takes ownership of one reference of the given array.
+    pub fn from_raw(array: Sendable<*mut obs_data_array_t>, runtime: ObsRuntime) -> Self {
+        ObsDataArray {
+            array: array.clone(),
+            runtime: runtime.clone(),
+            _drop_guard: Arc::new(_ObsDataArrayDropGuard { array, runtime }),
+        }
+    }
+
+    pub fn as_ptr(&self) -> Sendable<*mut obs_data_array_t> {
+        self.array.clone()
+    }
+
+    pub fn len(&self) -> Result<usize, ObsError> {
+        let array_ptr = self.array.clone();
+        run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            libobs::obs_data_array_count(array_ptr)
+        })
+    }
+
+    pub fn is_empty(&self) -> Result<bool, ObsError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns the object at the given index. The object is shared with
+    /// the array, so changes to it are visible in the array as well.
+    pub fn get(&self, index: usize) -> Result<Option<ObsData>, ObsError> {
+        let array_ptr = self.array.clone();
+        let item = run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            Sendable(libobs::obs_data_array_item(array_ptr, index))
+        })?;
+
+        if item.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsData::from_raw(item, self.runtime.clone())))
+    }
+
+    /// Appends the object to the array and returns its index.
+    pub fn push(&mut self, item: &ObsData) -> Result<usize, ObsError> {
+        let array_ptr = self.array.clone();
+        let item_ptr = item.as_ptr();
+        run_with_obs!(self.runtime, (array_ptr, item_ptr), move || unsafe {
+            libobs::obs_data_array_push_back(array_ptr, item_ptr)
+        })
+    }
+
+    /// Inserts the object at the given index, shifting all objects after it.
+    pub fn insert(&mut self, index: usize, item: &ObsData) -> Result<(), ObsError> {
+        let len = self.len()?;
+        if index > len {
+            return Err(ObsError::InvalidOperation(format!(
+                "Insertion index {} is out of bounds for an array of length {}",
+                index, len
+            )));
+        }
+
+        let array_ptr = self.array.clone();
+        let item_ptr = item.as_ptr();
+        run_with_obs!(self.runtime, (array_ptr, item_ptr), move || unsafe {
+            libobs::obs_data_array_insert(array_ptr, index, item_ptr)
+        })
+    }
+
+    /// Removes the object at the given index.
+    pub fn remove(&mut self, index: usize) -> Result<(), ObsError> {
+        let len = self.len()?;
+        if index >= len {
+            return Err(ObsError::InvalidOperation(format!(
+                "Index {} is out of bounds for an array of length {}",
+                index, len
+            )));
+        }
+
+        let array_ptr = self.array.clone();
+        run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            libobs::obs_data_array_erase(array_ptr, index)
+        })
+    }
+
+    /// Appends all objects of `other` to this array.
+    pub fn extend(&mut self, other: &ObsDataArray) -> Result<(), ObsError> {
+        let array_ptr = self.array.clone();
+        let other_ptr = other.as_ptr();
+        run_with_obs!(self.runtime, (array_ptr, other_ptr), move || unsafe {
+            libobs::obs_data_array_push_back_array(array_ptr, other_ptr)
+        })
+    }
+
+    /// Returns all objects of the array.
+    pub fn to_vec(&self) -> Result<Vec<ObsData>, ObsError> {
+        let array_ptr = self.array.clone();
+        let items = run_with_obs!(self.runtime, (array_ptr), move || unsafe {
+            let count = libobs::obs_data_array_count(array_ptr);
+            (0..count)
+                .map(|i| Sendable(libobs::obs_data_array_item(array_ptr, i)))
+                .collect::<Vec<_>>()
+        })?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| ObsData::from_raw(item, self.runtime.clone()))
+            .collect())
+    }
+}
@@ -38,6 +38,14 @@ impl ObsAudioInfo {
         Self::new(samples_per_second, speakers, 20, true)
     }
 
+    pub fn samples_per_sec(&self) -> ObsSamplesPerSecond {
+        self.samples_per_sec
+    }
+
+    pub fn speakers(&self) -> ObsSpeakerLayout {
+        self.speakers
+    }
+
     pub fn as_ptr(&self) -> Sendable<*const obs_audio_info2> {
         Sendable(self as *const Self as *const obs_audio_info2)
     }
@@ -53,3 +61,19 @@ impl Default for ObsAudioInfo {
         }
     }
 }
+
+impl ObsSpeakerLayout {
+    /// Number of audio channels of this layout, same as `get_audio_channels`.
+    pub fn channels(&self) -> usize {
+        match self {
+            ObsSpeakerLayout::Unknown => 0,
+            ObsSpeakerLayout::Mono => 1,
+            ObsSpeakerLayout::Stereo => 2,
+            ObsSpeakerLayout::S2Point1 => 3,
+            ObsSpeakerLayout::S4Point0 => 4,
+            ObsSpeakerLayout::S4Point1 => 5,
+            ObsSpeakerLayout::S5Point1 => 6,
+            ObsSpeakerLayout::S7Point1 => 8,
+        }
+    }
+}
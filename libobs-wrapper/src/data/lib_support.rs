@@ -1,5 +1,7 @@
 //! Use the `libobs-source` crate to create sources like `window_capture` for obs
 
+use std::fmt::Display;
+
 use crate::{
     data::ObsData,
     runtime::ObsRuntime,
@@ -25,6 +27,12 @@ pub trait ObsObjectBuilder {
     /// Returns the name of the source.
     fn get_name(&self) -> ObsString;
 
+    /// Checks the settings against the ranges of their properties.
+    /// Called by `build`, so invalid values are rejected before they reach the plugin.
+    fn validate(&mut self) -> Result<(), ObsError> {
+        Ok(())
+    }
+
     fn build(self) -> Result<ObjectInfo, ObsError>
     where
         Self: Sized;
@@ -51,8 +59,141 @@ pub trait ObsObjectUpdater<'a> {
     fn get_settings(&self) -> &ObsData;
     fn get_settings_updater(&mut self) -> &mut ObsDataUpdater;
 
+    /// Checks the changed settings against the ranges of their properties.
+    /// Called by `update` before the settings are applied.
+    fn validate(&mut self) -> Result<(), ObsError> {
+        Ok(())
+    }
+
     fn update(self) -> Result<(), ObsError>;
 
     /// Returns the ID of the object
     fn get_id() -> ObsString;
 }
+
+/// Allowed range of a numeric setting, as declared with
+/// `#[obs_property(min = ..., max = ..., step = ...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ObsSettingRange<T> {
+    pub min: Option<T>,
+    pub max: Option<T>,
+    /// Values must be a multiple of `step` away from `min`, or from zero if
+    /// there is no minimum
+    pub step: Option<T>,
+}
+
+impl<T: Copy + PartialOrd + Display> ObsSettingRange<T> {
+    fn check_bounds(&self, key: &str, value: T) -> Result<(), ObsError> {
+        let below = self.min.filter(|min| value < *min);
+        let above = self.max.filter(|max| value > *max);
+        if below.is_none() && above.is_none() {
+            return Ok(());
+        }
+
+        let range = match (self.min, self.max) {
+            (Some(min), Some(max)) => format!("between {} and {}", min, max),
+            (Some(min), None) => format!("at least {}", min),
+            (None, Some(max)) => format!("at most {}", max),
+            (None, None) => unreachable!(),
+        };
+
+        Err(ObsError::InvalidSettingValue(format!(
+            "`{}` must be {}, got {}",
+            key, range, value
+        )))
+    }
+
+    fn clamp_bounds(&self, mut value: T) -> T {
+        if let Some(min) = self.min.filter(|min| value < *min) {
+            value = min;
+        }
+        if let Some(max) = self.max.filter(|max| value > *max) {
+            value = max;
+        }
+
+        value
+    }
+
+    fn step_error(&self, key: &str, value: T, step: T) -> ObsError {
+        let base = self
+            .min
+            .map(|min| format!(" starting at {}", min))
+            .unwrap_or_default();
+
+        ObsError::InvalidSettingValue(format!(
+            "`{}` must be in steps of {}{}, got {}",
+            key, step, base, value
+        ))
+    }
+}
+
+impl ObsSettingRange<i64> {
+    /// Returns an error if the value is outside of the range or not on a step.
+    pub fn check(&self, key: &str, value: i64) -> Result<(), ObsError> {
+        self.check_bounds(key, value)?;
+
+        if let Some(step) = self.step.filter(|step| *step > 0) {
+            let base = self.min.unwrap_or(0);
+            if (value - base) % step != 0 {
+                return Err(self.step_error(key, value, step));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the value into the range and onto the nearest step.
+    pub fn clamp(&self, value: i64) -> i64 {
+        let mut value = self.clamp_bounds(value);
+
+        if let Some(step) = self.step.filter(|step| *step > 0) {
+            let base = self.min.unwrap_or(0);
+            let remainder = (value - base).rem_euclid(step);
+            value -= remainder;
+            if remainder * 2 >= step && self.max.is_none_or(|max| value + step <= max) {
+                value += step;
+            }
+        }
+
+        value
+    }
+}
+
+impl ObsSettingRange<f64> {
+    /// Returns an error if the value is outside of the range or not on a step.
+    /// Small rounding errors of the step are accepted.
+    pub fn check(&self, key: &str, value: f64) -> Result<(), ObsError> {
+        if value.is_nan() {
+            return Err(ObsError::InvalidSettingValue(format!(
+                "`{}` must be a number",
+                key
+            )));
+        }
+
+        self.check_bounds(key, value)?;
+
+        if let Some(step) = self.step.filter(|step| *step > 0.0) {
+            let steps = (value - self.min.unwrap_or(0.0)) / step;
+            if (steps - steps.round()).abs() > 1e-6 {
+                return Err(self.step_error(key, value, step));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves the value into the range and onto the nearest step.
+    pub fn clamp(&self, value: f64) -> f64 {
+        let mut value = self.clamp_bounds(value);
+
+        if let Some(step) = self.step.filter(|step| *step > 0.0) {
+            let base = self.min.unwrap_or(0.0);
+            value = base + ((value - base) / step).round() * step;
+            if self.max.is_some_and(|max| value > max) {
+                value -= step;
+            }
+        }
+
+        value
+    }
+}
@@ -0,0 +1,69 @@
+use crate::utils::ObsError;
+
+use super::ObsSettingRange;
+
+#[test]
+fn test_int_range_check() {
+    let range = ObsSettingRange::<i64> {
+        min: Some(0),
+        max: Some(100),
+        step: Some(10),
+    };
+
+    assert!(range.check("value", 0).is_ok());
+    assert!(range.check("value", 50).is_ok());
+    assert!(range.check("value", 100).is_ok());
+    assert!(matches!(
+        range.check("value", -10),
+        Err(ObsError::InvalidSettingValue(_))
+    ));
+    assert!(range.check("value", 110).is_err());
+    assert!(range.check("value", 55).is_err());
+}
+
+#[test]
+fn test_int_range_clamp() {
+    let range = ObsSettingRange::<i64> {
+        min: Some(2),
+        max: Some(21),
+        step: Some(5),
+    };
+
+    assert_eq!(range.clamp(-4), 2);
+    assert_eq!(range.clamp(8), 7);
+    assert_eq!(range.clamp(10), 12);
+    // 22 would be the nearest step, but it is above the maximum
+    assert_eq!(range.clamp(21), 17);
+    assert_eq!(range.clamp(100), 17);
+}
+
+#[test]
+fn test_open_range() {
+    let range = ObsSettingRange::<i64> {
+        min: Some(0),
+        ..Default::default()
+    };
+
+    assert!(range.check("value", i64::MAX).is_ok());
+    assert!(range.check("value", -1).is_err());
+    assert_eq!(range.clamp(-1), 0);
+}
+
+#[test]
+fn test_float_range() {
+    let range = ObsSettingRange::<f64> {
+        min: Some(0.0),
+        max: Some(1.0),
+        step: Some(0.1),
+    };
+
+    // Steps are not exact in floating point
+    assert!(range.check("value", 0.3).is_ok());
+    assert!(range.check("value", 0.35).is_err());
+    assert!(range.check("value", 1.5).is_err());
+    assert!(range.check("value", f64::NAN).is_err());
+
+    assert_eq!(range.clamp(-1.0), 0.0);
+    assert_eq!(range.clamp(2.0), 1.0);
+    assert!((range.clamp(0.34) - 0.3).abs() < 1e-9);
+}
@@ -8,11 +8,22 @@ use crate::{
 };
 use libobs::obs_data;
 
+mod array;
+pub use array::*;
 pub mod audio;
 pub mod immutable;
 mod lib_support;
+#[cfg(test)]
+mod lib_support_tests;
 pub mod output;
 pub mod properties;
+pub mod raw_audio;
+pub mod raw_video;
+#[cfg(test)]
+mod raw_video_tests;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stats;
 pub mod video;
 pub use lib_support::*;
 mod updater;
@@ -61,6 +72,15 @@ impl ObsData {
         })
     }
 
+    /// Takes ownership of one reference of the given `obs_data`.
+    pub fn from_raw(obs_data: Sendable<*mut obs_data>, runtime: ObsRuntime) -> Self {
+        ObsData {
+            obs_data: obs_data.clone(),
+            runtime: runtime.clone(),
+            _drop_guard: Arc::new(_ObsDataDropGuard { obs_data, runtime }),
+        }
+    }
+
     pub fn bulk_update(&mut self) -> ObsDataUpdater {
         ObsDataUpdater {
             changes: Vec::new(),
@@ -154,6 +174,219 @@ impl ObsData {
         Ok(self)
     }
 
+    /// Sets a nested object. The object is shared, changes made to it
+    /// later on are visible in this `ObsData` as well.
+    pub fn set_obj<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsData,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, value_ptr, data_ptr),
+            move || unsafe {
+                libobs::obs_data_set_obj(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Sets an array of objects. Like `set_obj`, the array is shared and not copied.
+    pub fn set_array<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsDataArray,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, value_ptr, data_ptr),
+            move || unsafe {
+                libobs::obs_data_set_array(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Sets the default value of a string. Defaults are returned by the
+    /// getters if no user value is set and are not written to JSON.
+    pub fn set_default_string<
+        T: Into<ObsString> + Send + Sync,
+        K: Into<ObsString> + Send + Sync,
+    >(
+        &mut self,
+        key: T,
+        value: K,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+        let value = value.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (data_ptr, key_ptr, value_ptr),
+            move || unsafe { libobs::obs_data_set_default_string(data_ptr, key_ptr, value_ptr) }
+        )?;
+
+        Ok(self)
+    }
+
+    pub fn set_default_int<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: i64,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_int(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    pub fn set_default_bool<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: bool,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_bool(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    pub fn set_default_double<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: f64,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_set_default_double(data_ptr, key_ptr, value);
+        })?;
+
+        Ok(self)
+    }
+
+    pub fn set_default_obj<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsData,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, value_ptr, data_ptr),
+            move || unsafe {
+                libobs::obs_data_set_default_obj(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    pub fn set_default_array<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+        value: &ObsDataArray,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let value_ptr = value.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(
+            self.runtime,
+            (key_ptr, value_ptr, data_ptr),
+            move || unsafe {
+                libobs::obs_data_set_default_array(data_ptr, key_ptr, value_ptr);
+            }
+        )?;
+
+        Ok(self)
+    }
+
+    /// Removes the key including its default value.
+    pub fn erase<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_erase(data_ptr, key_ptr);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes the user value of the key, so the default value is used again.
+    pub fn unset_user_value<T: Into<ObsString> + Sync + Send>(
+        &mut self,
+        key: T,
+    ) -> Result<&mut Self, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (key_ptr, data_ptr), move || unsafe {
+            libobs::obs_data_unset_user_value(data_ptr, key_ptr);
+        })?;
+
+        Ok(self)
+    }
+
+    /// Removes all user values. Default values are kept.
+    pub fn clear(&mut self) -> Result<&mut Self, ObsError> {
+        let data_ptr = self.obs_data.clone();
+
+        run_with_obs!(self.runtime, (data_ptr), move || unsafe {
+            libobs::obs_data_clear(data_ptr);
+        })?;
+
+        Ok(self)
+    }
+
     pub fn from_json(json: &str, runtime: ObsRuntime) -> Result<Self, ObsError> {
         let cstr = CString::new(json).map_err(|_| ObsError::JsonParseError)?;
 
@@ -1,11 +1,17 @@
+use std::collections::BTreeMap;
 use std::sync::{Arc, RwLock};
 use std::{ffi::CStr, ptr};
 
 use getters0::Getters;
 use libobs::obs_output;
 
+use crate::canvas::ObsCanvasRef;
 use crate::enums::ObsOutputStopSignal;
+#[cfg(feature = "async")]
+use crate::run_with_obs_async;
 use crate::runtime::ObsRuntime;
+use crate::services::ObsServiceRef;
+use crate::sources::ALL_AUDIO_MIXERS;
 use crate::unsafe_send::Sendable;
 use crate::utils::{AudioEncoderInfo, OutputInfo, VideoEncoderInfo};
 use crate::{impl_obs_drop, impl_signal_manager, run_with_obs};
@@ -18,7 +24,9 @@ use crate::{
 use super::ObsData;
 
 mod replay_buffer;
+mod stats;
 pub use replay_buffer::*;
+pub use stats::*;
 
 #[derive(Debug)]
 struct _ObsOutputDropGuard {
@@ -56,10 +64,17 @@ pub struct ObsOutputRef {
     #[get_mut]
     pub(crate) curr_video_encoder: Arc<RwLock<Option<Arc<ObsVideoEncoder>>>>,
 
-    /// Audio encoders attached to this output
+    /// Audio encoder of the first audio track of this output
     #[get_mut]
     pub(crate) audio_encoders: Arc<RwLock<Option<Arc<ObsAudioEncoder>>>>,
 
+    /// Audio encoders of all tracks, keyed by their track index in the output
+    #[skip_getter]
+    pub(crate) audio_tracks: Arc<RwLock<BTreeMap<usize, Arc<ObsAudioEncoder>>>>,
+
+    /// Streaming service attached to this output
+    pub(crate) service: Arc<RwLock<Option<ObsServiceRef>>>,
+
     /// Pointer to the underlying OBS output
     #[skip_getter]
     pub(crate) output: Sendable<*mut obs_output>,
@@ -73,9 +88,17 @@ pub struct ObsOutputRef {
     #[skip_getter]
     pub(crate) runtime: ObsRuntime,
 
+    /// Last sample of `stats`, used to calculate the bitrate
+    #[skip_getter]
+    last_stats_sample: Arc<RwLock<Option<ObsStatsSample>>>,
+
     /// RAII guard that ensures proper cleanup when the output is dropped
     #[skip_getter]
     _drop_guard: Arc<_ObsOutputDropGuard>,
+
+    /// Canvas whose video is encoded instead of the main video, released after the output
+    #[skip_getter]
+    canvas: Arc<RwLock<Option<ObsCanvasRef>>>,
 }
 
 impl ObsOutputRef {
@@ -88,60 +111,124 @@ impl ObsOutputRef {
     /// # Returns
     /// A Result containing the new ObsOutputRef or an error
     pub(crate) fn new(output: OutputInfo, runtime: ObsRuntime) -> Result<Self, ObsError> {
-        let (output, id, name, settings, hotkey_data) = runtime.run_with_obs_result(|| {
-            let OutputInfo {
-                id,
-                name,
-                settings,
-                hotkey_data,
-            } = output;
-
-            let settings_ptr = match settings.as_ref() {
-                Some(x) => x.as_ptr(),
-                None => Sendable(ptr::null_mut()),
-            };
-
-            let hotkey_data_ptr = match hotkey_data.as_ref() {
-                Some(x) => x.as_ptr(),
-                None => Sendable(ptr::null_mut()),
-            };
-
-            let output = unsafe {
-                libobs::obs_output_create(
-                    id.as_ptr().0,
-                    name.as_ptr().0,
-                    settings_ptr.0,
-                    hotkey_data_ptr.0,
-                )
-            };
-
-            (Sendable(output), id, name, settings, hotkey_data)
-        })?;
+        let (output, id, name, settings, hotkey_data) =
+            runtime.run_with_obs_result(|| Self::create(output))?;
 
         if output.0.is_null() {
             return Err(ObsError::NullPointer);
         }
 
         let signal_manager = ObsOutputSignals::new(&output, runtime.clone())?;
-        Ok(Self {
+        Ok(Self::from_created(
+            output,
+            id,
+            name,
+            settings,
+            hotkey_data,
+            signal_manager,
+            runtime,
+        ))
+    }
+
+    /// Async version of `new`, creating the output is awaited instead of blocking.
+    #[cfg(feature = "async")]
+    pub(crate) async fn new_async(
+        output: OutputInfo,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        let (output, id, name, settings, hotkey_data) = runtime
+            .run_with_obs_async(move || Self::create(output))
+            .await?;
+
+        if output.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let signal_manager = ObsOutputSignals::new_async(&output, runtime.clone()).await?;
+        Ok(Self::from_created(
+            output,
+            id,
+            name,
+            settings,
+            hotkey_data,
+            signal_manager,
+            runtime,
+        ))
+    }
+
+    /// Creates the output, must be called on the OBS thread.
+    #[allow(clippy::type_complexity)]
+    fn create(
+        output: OutputInfo,
+    ) -> (
+        Sendable<*mut obs_output>,
+        ObsString,
+        ObsString,
+        Option<ObsData>,
+        Option<ObsData>,
+    ) {
+        let OutputInfo {
+            id,
+            name,
+            settings,
+            hotkey_data,
+        } = output;
+
+        let settings_ptr = match settings.as_ref() {
+            Some(x) => x.as_ptr(),
+            None => Sendable(ptr::null_mut()),
+        };
+
+        let hotkey_data_ptr = match hotkey_data.as_ref() {
+            Some(x) => x.as_ptr(),
+            None => Sendable(ptr::null_mut()),
+        };
+
+        let output = unsafe {
+            libobs::obs_output_create(
+                id.as_ptr().0,
+                name.as_ptr().0,
+                settings_ptr.0,
+                hotkey_data_ptr.0,
+            )
+        };
+
+        (Sendable(output), id, name, settings, hotkey_data)
+    }
+
+    fn from_created(
+        output: Sendable<*mut obs_output>,
+        id: ObsString,
+        name: ObsString,
+        settings: Option<ObsData>,
+        hotkey_data: Option<ObsData>,
+        signal_manager: ObsOutputSignals,
+        runtime: ObsRuntime,
+    ) -> Self {
+        Self {
             settings: Arc::new(RwLock::new(settings)),
             hotkey_data: Arc::new(RwLock::new(hotkey_data)),
 
             curr_video_encoder: Arc::new(RwLock::new(None)),
             audio_encoders: Arc::new(RwLock::new(None)),
+            audio_tracks: Default::default(),
+            service: Arc::new(RwLock::new(None)),
 
             output: output.clone(),
             id,
             name,
 
+            last_stats_sample: Arc::new(RwLock::new(None)),
+
             _drop_guard: Arc::new(_ObsOutputDropGuard {
                 output,
                 runtime: runtime.clone(),
             }),
+            canvas: Arc::new(RwLock::new(None)),
 
             runtime,
             signal_manager: Arc::new(signal_manager),
-        })
+        }
     }
 
     /// Returns the current video encoder attached to this output, if any.
@@ -256,33 +343,59 @@ impl ObsOutputRef {
         info: AudioEncoderInfo,
         mixer_idx: usize,
     ) -> Result<Arc<ObsAudioEncoder>, ObsError> {
+        self.create_and_set_audio_track(info, mixer_idx, mixer_idx)
+    }
+
+    /// Creates an audio encoder that encodes the given mixer and attaches
+    /// it as the given audio track of this output.
+    ///
+    /// Track indices are counted per output, so an MKV with the mixers 1
+    /// and 3 uses the tracks 0 and 1. Most outputs only record the first
+    /// track, multiple tracks are supported by `ffmpeg_muxer`,
+    /// `mp4_output` and `mov_output`.
+    ///
+    /// # Arguments
+    /// * `info` - Information for creating the audio encoder
+    /// * `mixer_idx` - The mixer (0 to 5) whose audio is encoded
+    /// * `track_idx` - The audio track of this output
+    pub fn create_and_set_audio_track(
+        &mut self,
+        info: AudioEncoderInfo,
+        mixer_idx: usize,
+        track_idx: usize,
+    ) -> Result<Arc<ObsAudioEncoder>, ObsError> {
+        check_audio_index("Mixer", mixer_idx)?;
+        check_audio_index("Audio track", track_idx)?;
+
         // Fail early before creating the encoder if the output is active
         if self.is_active()? {
             return Err(ObsError::OutputAlreadyActive);
         }
 
         let audio_enc = ObsAudioEncoder::new_from_info(info, mixer_idx, self.runtime.clone())?;
-        self.set_audio_encoder(audio_enc.clone(), mixer_idx)?;
+        self.set_audio_encoder(audio_enc.clone(), track_idx)?;
         Ok(audio_enc)
     }
 
-    /// Attaches an existing audio encoder to this output at the specified mixer index.
+    /// Attaches an existing audio encoder to this output at the specified track index.
+    /// An encoder that was attached at the same index before is replaced.
     ///
     /// # Arguments
     /// * `encoder` - The audio encoder to attach
-    /// * `mixer_idx` - The mixer index to use (typically 0 for primary audio)
+    /// * `track_idx` - The audio track of this output (typically 0 for primary audio)
     ///
     /// # Returns
     /// A Result indicating success or an error
     pub fn set_audio_encoder(
         &mut self,
         encoder: Arc<ObsAudioEncoder>,
-        mixer_idx: usize,
+        track_idx: usize,
     ) -> Result<(), ObsError> {
         if encoder.encoder.0.is_null() {
             return Err(ObsError::NullPointer);
         }
 
+        check_audio_index("Audio track", track_idx)?;
         if self.is_active()? {
             return Err(ObsError::OutputAlreadyActive);
         }
@@ -290,14 +403,121 @@ impl ObsOutputRef {
         let encoder_ptr = encoder.encoder.clone();
         let output_ptr = self.output.clone();
         run_with_obs!(self.runtime, (output_ptr, encoder_ptr), move || unsafe {
-            libobs::obs_output_set_audio_encoder(output_ptr, encoder_ptr, mixer_idx)
+            libobs::obs_output_set_audio_encoder(output_ptr, encoder_ptr, track_idx)
         })?;
 
-        //TODO don't drop audio encoders if multiple are set
-        self.audio_encoders
+        let mut tracks = self
+            .audio_tracks
             .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))?;
+
+        tracks.insert(track_idx, encoder);
+        *self
+            .audio_encoders
+            .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))? = tracks.values().next().cloned();
+
+        Ok(())
+    }
+
+    /// Detaches the audio encoder of the given track from this output.
+    pub fn remove_audio_encoder(&mut self, track_idx: usize) -> Result<(), ObsError> {
+        check_audio_index("Audio track", track_idx)?;
+        if self.is_active()? {
+            return Err(ObsError::OutputAlreadyActive);
+        }
+
+        let output_ptr = self.output.clone();
+        run_with_obs!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_set_audio_encoder(output_ptr, ptr::null_mut(), track_idx)
+        })?;
+
+        let mut tracks = self
+            .audio_tracks
+            .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))?;
+
+        tracks.remove(&track_idx);
+        *self
+            .audio_encoders
+            .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))? = tracks.values().next().cloned();
+
+        Ok(())
+    }
+
+    /// Returns the audio encoders of this output with their track index, ordered by track.
+    pub fn get_audio_encoders(&self) -> Result<Vec<(usize, Arc<ObsAudioEncoder>)>, ObsError> {
+        let tracks = self
+            .audio_tracks
+            .read()
             .map_err(|e| ObsError::LockError(e.to_string()))?
-            .replace(encoder);
+            .iter()
+            .map(|(idx, encoder)| (*idx, encoder.clone()))
+            .collect();
+
+        Ok(tracks)
+    }
+
+    /// Sets which mixers are recorded by outputs that encode the audio
+    /// themselves, like `ffmpeg_output`. Bit `n` of the mask enables mixer `n`.
+    /// Outputs with audio encoders record the mixers of their encoders instead.
+    pub fn set_mixers(&mut self, mixers: u32) -> Result<(), ObsError> {
+        if mixers & !ALL_AUDIO_MIXERS != 0 {
+            return Err(ObsError::InvalidOperation(format!(
+                "Audio mixer mask {:#b} contains mixers above {}",
+                mixers,
+                libobs::MAX_AUDIO_MIXES - 1
+            )));
+        }
+
+        let output_ptr = self.output.clone();
+        run_with_obs!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_set_mixers(output_ptr, mixers as usize)
+        })
+    }
+
+    /// Returns the mixers set with `set_mixers`.
+    pub fn get_mixers(&self) -> Result<u32, ObsError> {
+        let output_ptr = self.output.clone();
+        run_with_obs!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_get_mixers(output_ptr) as u32
+        })
+    }
+
+    /// Returns the streaming service attached to this output, if any.
+    pub fn get_current_service(&self) -> Result<Option<ObsServiceRef>, ObsError> {
+        let curr = self
+            .service
+            .read()
+            .map_err(|e| ObsError::LockError(e.to_string()))?;
+
+        Ok(curr.clone())
+    }
+
+    /// Attaches a streaming service to this output. Streaming outputs such
+    /// as `rtmp_output` read the server and stream key from the service.
+    ///
+    /// # Arguments
+    /// * `service` - The service to attach
+    ///
+    /// # Returns
+    /// A Result indicating success or an error
+    pub fn set_service(&mut self, service: ObsServiceRef) -> Result<(), ObsError> {
+        if self.is_active()? {
+            return Err(ObsError::OutputAlreadyActive);
+        }
+
+        let output_ptr = self.output.clone();
+        let service_ptr = service.as_ptr();
+        run_with_obs!(self.runtime, (output_ptr, service_ptr), move || unsafe {
+            libobs::obs_output_set_service(output_ptr, service_ptr);
+        })?;
+
+        self.service
+            .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))?
+            .replace(service);
 
         Ok(())
     }
@@ -313,7 +533,49 @@ impl ObsOutputRef {
             return Err(ObsError::OutputAlreadyActive);
         }
 
-        // Set the video and audio encoders before starting (similar to https://github.com/obsproject/obs-studio/blob/0b1229632063a13dfd26cf1cd9dd43431d8c68f6/frontend/utility/SimpleOutput.cpp#L552)
+        let (vid_encoder_ptr, audio_encoder_ptrs) = self.encoder_ptrs()?;
+        let canvas_ptr = self.canvas_ptr()?;
+
+        let output_ptr = self.output.clone();
+        run_with_obs!(
+            self.runtime,
+            (output_ptr, vid_encoder_ptr, canvas_ptr),
+            move || unsafe {
+                start_output(output_ptr, vid_encoder_ptr, &audio_encoder_ptrs, canvas_ptr)
+            }
+        )?
+        .map_err(ObsError::OutputStartFailure)
+    }
+
+    /// Async version of `start`, which does not block while libobs starts the output.
+    #[cfg(feature = "async")]
+    pub async fn start_async(&self) -> Result<(), ObsError> {
+        let output_ptr = self.output.clone();
+        let output_active = run_with_obs_async!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_active(output_ptr)
+        })?;
+
+        if output_active {
+            return Err(ObsError::OutputAlreadyActive);
+        }
+
+        let (vid_encoder_ptr, audio_encoder_ptrs) = self.encoder_ptrs()?;
+        let canvas_ptr = self.canvas_ptr()?;
+        run_with_obs_async!(
+            self.runtime,
+            (output_ptr, vid_encoder_ptr, canvas_ptr),
+            move || unsafe {
+                start_output(output_ptr, vid_encoder_ptr, &audio_encoder_ptrs, canvas_ptr)
+            }
+        )?
+        .map_err(ObsError::OutputStartFailure)
+    }
+
+    /// The video and all audio encoders, which are connected to the video
+    /// and audio output of libobs when the output is started.
+    fn encoder_ptrs(
+        &self,
+    ) -> Result<(Sendable<*mut libobs::obs_encoder>, AudioEncoderPtrs), ObsError> {
         let vid_encoder_ptr = self
             .curr_video_encoder
             .read()
@@ -322,41 +584,54 @@ impl ObsOutputRef {
             .map(|enc| enc.as_ptr())
             .unwrap_or(Sendable(ptr::null_mut()));
 
-        let audio_encoder_ptr = self
-            .audio_encoders
+        let audio_encoder_ptrs = self
+            .audio_tracks
             .read()
             .map_err(|e| ObsError::LockError(e.to_string()))?
-            .as_ref()
+            .values()
             .map(|enc| enc.encoder.clone())
+            .collect();
+
+        Ok((vid_encoder_ptr, audio_encoder_ptrs))
+    }
+
+    /// The canvas set with `set_canvas`, null for the main video
+    fn canvas_ptr(&self) -> Result<Sendable<*mut libobs::obs_canvas_t>, ObsError> {
+        let canvas_ptr = self
+            .canvas
+            .read()
+            .map_err(|e| ObsError::LockError(e.to_string()))?
+            .as_ref()
+            .map(|canvas| canvas.as_ptr())
             .unwrap_or(Sendable(ptr::null_mut()));
 
-        let output_ptr = self.output.clone();
-        let res = run_with_obs!(
-            self.runtime,
-            (output_ptr, vid_encoder_ptr, audio_encoder_ptr),
-            move || unsafe {
-                libobs::obs_encoder_set_video(vid_encoder_ptr, libobs::obs_get_video());
-                libobs::obs_encoder_set_audio(audio_encoder_ptr, libobs::obs_get_audio());
-                libobs::obs_output_start(output_ptr)
-            }
-        )?;
+        Ok(canvas_ptr)
+    }
 
-        if res {
-            return Ok(());
+    /// Encodes the video of the given canvas instead of the main video,
+    /// `None` switches back to the main video. Takes effect on the next start.
+    pub fn set_canvas(&mut self, canvas: Option<ObsCanvasRef>) -> Result<(), ObsError> {
+        if self.is_active()? {
+            return Err(ObsError::OutputAlreadyActive);
         }
 
-        let err = run_with_obs!(self.runtime, (output_ptr), move || unsafe {
-            Sendable(libobs::obs_output_get_last_error(output_ptr))
-        })?;
+        *self
+            .canvas
+            .write()
+            .map_err(|e| ObsError::LockError(e.to_string()))? = canvas;
 
-        if err.0.is_null() {
-            return Err(ObsError::OutputStartFailure(None));
-        }
+        Ok(())
+    }
 
-        let c_str = unsafe { CStr::from_ptr(err.0) };
-        let err_str = c_str.to_str().ok().map(|x| x.to_string());
+    /// Returns the canvas this output encodes, `None` for the main video.
+    pub fn get_canvas(&self) -> Result<Option<ObsCanvasRef>, ObsError> {
+        let canvas = self
+            .canvas
+            .read()
+            .map_err(|e| ObsError::LockError(e.to_string()))?
+            .clone();
 
-        Err(ObsError::OutputStartFailure(err_str))
+        Ok(canvas)
     }
 
     /// This pauses or resumes the given output, and waits until the output is fully paused.
@@ -445,6 +720,42 @@ impl ObsOutputRef {
         Ok(())
     }
 
+    /// Async version of `stop`, which awaits the stop signal instead of blocking.
+    #[cfg(feature = "async")]
+    pub async fn stop_async(&mut self) -> Result<(), ObsError> {
+        let output_ptr = self.output.clone();
+        let output_active = run_with_obs_async!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_active(output_ptr)
+        })?;
+
+        if !output_active {
+            return Err(ObsError::OutputStopFailure(Some(
+                "Output is not active.".to_string(),
+            )));
+        }
+
+        let mut rx = self.signal_manager.on_stop()?;
+        let mut rx_deactivate = self.signal_manager.on_deactivate()?;
+
+        run_with_obs_async!(self.runtime, (output_ptr), move || unsafe {
+            libobs::obs_output_stop(output_ptr)
+        })?;
+
+        let signal = rx.recv().await.map_err(|_| ObsError::NoSenderError)?;
+
+        log::trace!("Received stop signal: {:?}", signal);
+        if signal != ObsOutputStopSignal::Success {
+            return Err(ObsError::OutputStopFailure(Some(signal.to_string())));
+        }
+
+        rx_deactivate
+            .recv()
+            .await
+            .map_err(|_| ObsError::NoSenderError)?;
+
+        Ok(())
+    }
+
     pub fn is_active(&self) -> Result<bool, ObsError> {
         let output_ptr = self.output.clone();
         let output_active = run_with_obs!(self.runtime, (output_ptr), move || unsafe {
@@ -459,6 +770,55 @@ impl ObsOutputRef {
     }
 }
 
+/// Audio encoders of all tracks, ordered by track index
+type AudioEncoderPtrs = Vec<Sendable<*mut libobs::obs_encoder>>;
+
+fn check_audio_index(kind: &str, idx: usize) -> Result<(), ObsError> {
+    if idx >= libobs::MAX_AUDIO_MIXES as usize {
+        return Err(ObsError::InvalidOperation(format!(
+            "{} {} is out of bounds (max {})",
+            kind,
+            idx,
+            libobs::MAX_AUDIO_MIXES - 1
+        )));
+    }
+
+    Ok(())
+}
+
+/// Starts the output, returning the last error of the output if that fails.
+///
+/// Has to be called on the OBS thread.
+unsafe fn start_output(
+    output: *mut obs_output,
+    video_encoder: *mut libobs::obs_encoder,
+    audio_encoders: &[Sendable<*mut libobs::obs_encoder>],
+    canvas: *mut libobs::obs_canvas_t,
+) -> Result<(), Option<String>> {
+    let video = if canvas.is_null() {
+        libobs::obs_get_video()
+    } else {
+        libobs::obs_canvas_get_video(canvas)
+    };
+
+    // Set the video and audio encoders before starting (similar to https://github.com/obsproject/obs-studio/blob/0b1229632063a13dfd26cf1cd9dd43431d8c68f6/frontend/utility/SimpleOutput.cpp#L552)
+    libobs::obs_encoder_set_video(video_encoder, video);
+    for audio_encoder in audio_encoders {
+        libobs::obs_encoder_set_audio(audio_encoder.0, libobs::obs_get_audio());
+    }
+
+    if libobs::obs_output_start(output) {
+        return Ok(());
+    }
+
+    let err = libobs::obs_output_get_last_error(output);
+    if err.is_null() {
+        return Err(None);
+    }
+
+    Err(CStr::from_ptr(err).to_str().ok().map(|x| x.to_string()))
+}
+
 impl_signal_manager!(|ptr| unsafe { libobs::obs_output_get_signal_handler(ptr) }, ObsOutputSignals for ObsOutputRef<*mut libobs::obs_output>, [
     "start": {},
     "stop": {code: crate::enums::ObsOutputStopSignal},
@@ -0,0 +1,119 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tokio::sync::broadcast;
+
+use crate::{
+    data::stats::{percent, spawn_stats_broadcast},
+    run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::Sendable,
+    utils::ObsError,
+};
+
+use super::ObsOutputRef;
+
+/// Statistics of an output, as shown in the stats dock of OBS Studio.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ObsOutputStats {
+    /// Bytes written or sent since the output was started
+    pub total_bytes: u64,
+    /// Frames passed to the output since it was started
+    pub total_frames: i32,
+    /// Frames dropped because of network congestion
+    pub dropped_frames: i32,
+    /// Network congestion between `0.0` and `1.0`, only reported by streaming outputs
+    pub congestion: f32,
+    /// Time it took to connect to the server, only reported by streaming outputs
+    pub connect_time: Duration,
+    /// Bitrate in kbit/s since the previous sample, `0.0` for the first one
+    pub bitrate_kbps: f64,
+    /// Whether the output is currently trying to reconnect
+    pub reconnecting: bool,
+}
+
+impl ObsOutputStats {
+    /// Percentage of frames dropped by the output
+    pub fn dropped_frames_percent(&self) -> f64 {
+        percent(self.dropped_frames as f64, self.total_frames as f64)
+    }
+}
+
+/// Total bytes of the output at a point in time, used to calculate the bitrate
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ObsStatsSample {
+    total_bytes: u64,
+    time: Instant,
+}
+
+fn read_output_stats(
+    output: Sendable<*mut libobs::obs_output>,
+    runtime: &ObsRuntime,
+    previous: &mut Option<ObsStatsSample>,
+) -> Result<ObsOutputStats, ObsError> {
+    let mut stats = run_with_obs!(runtime, (output), move || unsafe {
+        ObsOutputStats {
+            total_bytes: libobs::obs_output_get_total_bytes(output),
+            total_frames: libobs::obs_output_get_total_frames(output),
+            dropped_frames: libobs::obs_output_get_frames_dropped(output),
+            congestion: libobs::obs_output_get_congestion(output),
+            connect_time: Duration::from_millis(
+                libobs::obs_output_get_connect_time_ms(output).max(0) as u64,
+            ),
+            bitrate_kbps: 0.0,
+            reconnecting: libobs::obs_output_reconnecting(output),
+        }
+    })?;
+
+    let now = Instant::now();
+    if let Some(sample) = previous {
+        let elapsed = now.duration_since(sample.time).as_secs_f64();
+        // The byte counter is reset when the output is restarted
+        if elapsed > 0.0 && stats.total_bytes >= sample.total_bytes {
+            let bits = (stats.total_bytes - sample.total_bytes) as f64 * 8.0;
+            stats.bitrate_kbps = bits / elapsed / 1000.0;
+        }
+    }
+
+    *previous = Some(ObsStatsSample {
+        total_bytes: stats.total_bytes,
+        time: now,
+    });
+
+    Ok(stats)
+}
+
+impl ObsOutputRef {
+    /// Returns the current statistics of this output. The bitrate is
+    /// calculated from the bytes sent since the previous call.
+    pub fn stats(&self) -> Result<ObsOutputStats, ObsError> {
+        let mut previous = self
+            .last_stats_sample
+            .write()
+            .map_err(|e| ObsError::LockError(format!("{:?}", e)))?;
+
+        read_output_stats(self.output.clone(), &self.runtime, &mut previous)
+    }
+
+    /// Sends the statistics of this output to the returned receiver every `interval`.
+    ///
+    /// The broadcast stops once all receivers are dropped or the output is released.
+    /// It keeps its own bitrate sample, so it does not influence `stats`.
+    pub fn stats_broadcast(&self, interval: Duration) -> broadcast::Receiver<ObsOutputStats> {
+        let output = self.output.clone();
+        let runtime = self.runtime.downgrade();
+        let guard = Arc::downgrade(&self._drop_guard);
+
+        let mut previous = None;
+        spawn_stats_broadcast(interval, move || {
+            // Keeps the output alive while reading its stats
+            let (Some(_guard), Some(runtime)) = (guard.upgrade(), runtime.upgrade()) else {
+                return Ok(None);
+            };
+
+            read_output_stats(output.clone(), &runtime, &mut previous).map(Some)
+        })
+    }
+}
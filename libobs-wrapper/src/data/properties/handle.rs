@@ -0,0 +1,209 @@
+//! Live properties of an object
+//!
+//! `ObsPropertyObject::get_properties` returns a snapshot and destroys the
+//! underlying properties right away. Plugins however change their properties
+//! depending on the current settings, e.g. the resolutions of a camera are
+//! only listed after a device was chosen. An `ObsPropertiesHandle` keeps the
+//! properties alive, so settings can be applied, modified callbacks and
+//! buttons can be invoked and the changed properties read again.
+use std::{collections::HashMap, ffi::c_void};
+
+use libobs::obs_properties_t;
+
+use crate::{
+    data::{output::ObsOutputRef, ObsData},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    services::ObsServiceRef,
+    sources::ObsSourceRef,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+use super::{read_properties, ObsProperty, ObsPropertyObjectPrivate};
+
+#[derive(Debug)]
+struct _ObsPropertiesDropGuard {
+    properties: Sendable<*mut obs_properties_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsPropertiesDropGuard, (properties), move || unsafe {
+    libobs::obs_properties_destroy(properties);
+});
+
+/// Properties of an object that stay alive until this handle is dropped.
+/// Created with `ObsPropertiesOwner::properties_handle`.
+#[derive(Debug)]
+pub struct ObsPropertiesHandle<T: ObsPropertyObjectPrivate> {
+    properties: Sendable<*mut obs_properties_t>,
+    /// Passed to button callbacks, the object is kept alive by `object`
+    object_ptr: Sendable<*mut c_void>,
+    /// Destroys the properties before the object may be released
+    _guard: _ObsPropertiesDropGuard,
+    object: T,
+    runtime: ObsRuntime,
+}
+
+impl<T: ObsPropertyObjectPrivate> ObsPropertiesHandle<T> {
+    fn new(object: T, object_ptr: *mut c_void, runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let properties = object.get_properties_raw()?;
+        if properties.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        Ok(Self {
+            properties: properties.clone(),
+            object_ptr: Sendable(object_ptr),
+            _guard: _ObsPropertiesDropGuard {
+                properties,
+                runtime: runtime.clone(),
+            },
+            object,
+            runtime,
+        })
+    }
+
+    /// The object these properties belong to.
+    pub fn object(&self) -> &T {
+        &self.object
+    }
+
+    /// Reads the current state of all properties.
+    pub fn get_properties(&self) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        let properties = self.properties.clone();
+        run_with_obs!(self.runtime, (properties), move || unsafe {
+            read_properties(properties)
+        })
+    }
+
+    /// Reads the current state of a single property.
+    pub fn get_property(&self, name: &str) -> Result<Option<ObsProperty>, ObsError> {
+        Ok(self.get_properties()?.remove(name))
+    }
+
+    /// Calls the modified callbacks of all properties with the given
+    /// settings, which updates dependent properties the same way OBS Studio
+    /// does when the properties dialog is opened.
+    pub fn apply_settings(&self, settings: &ObsData) -> Result<(), ObsError> {
+        let properties = self.properties.clone();
+        let settings_ptr = settings.as_ptr();
+        run_with_obs!(self.runtime, (properties, settings_ptr), move || unsafe {
+            libobs::obs_properties_apply_settings(properties, settings_ptr);
+        })
+    }
+
+    /// Calls the modified callback of the property `name`, as if the user
+    /// changed it to the value in `settings`.
+    ///
+    /// Returns whether the properties changed and should be read again.
+    pub fn modified(&self, name: &str, settings: &ObsData) -> Result<bool, ObsError> {
+        let properties = self.properties.clone();
+        let settings_ptr = settings.as_ptr();
+        let name = ObsString::new(name);
+        let name_ptr = name.as_ptr();
+        let changed = run_with_obs!(
+            self.runtime,
+            (properties, settings_ptr, name_ptr),
+            move || unsafe {
+                let property = libobs::obs_properties_get(properties, name_ptr);
+                if property.is_null() {
+                    return None;
+                }
+
+                Some(libobs::obs_property_modified(property, settings_ptr))
+            }
+        )?;
+
+        changed.ok_or_else(|| property_not_found(&name))
+    }
+
+    /// Clicks the button property `name`.
+    ///
+    /// Returns whether the properties changed and should be read again.
+    pub fn button_clicked(&self, name: &str) -> Result<bool, ObsError> {
+        let properties = self.properties.clone();
+        let object_ptr = self.object_ptr.clone();
+        let name = ObsString::new(name);
+        let name_ptr = name.as_ptr();
+        let changed = run_with_obs!(
+            self.runtime,
+            (properties, object_ptr, name_ptr),
+            move || unsafe {
+                let property = libobs::obs_properties_get(properties, name_ptr);
+                if property.is_null()
+                    || libobs::obs_property_get_type(property)
+                        != libobs::obs_property_type_OBS_PROPERTY_BUTTON
+                {
+                    return None;
+                }
+
+                Some(libobs::obs_property_button_clicked(property, object_ptr))
+            }
+        )?;
+
+        changed.ok_or_else(|| property_not_found(&name))
+    }
+
+    /// Creates the properties of the object again. This is needed for
+    /// plugins that build different properties depending on their settings
+    /// instead of changing the existing ones in modified callbacks.
+    ///
+    /// Settings that were only passed to `apply_settings` or `modified` are
+    /// not kept, the new properties reflect the settings of the object.
+    pub fn refetch(&mut self) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        let properties = self.object.get_properties_raw()?;
+        if properties.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        self.properties = properties.clone();
+        // Destroys the previous properties
+        self._guard = _ObsPropertiesDropGuard {
+            properties,
+            runtime: self.runtime.clone(),
+        };
+
+        self.get_properties()
+    }
+}
+
+fn property_not_found(name: &ObsString) -> ObsError {
+    ObsError::InvalidOperation(format!("Property {} does not exist", name))
+}
+
+/// Objects whose properties can be kept alive in an `ObsPropertiesHandle`.
+pub trait ObsPropertiesOwner: ObsPropertyObjectPrivate + Sized {
+    /// Creates the properties of this object, with its current settings applied.
+    fn properties_handle(&self) -> Result<ObsPropertiesHandle<Self>, ObsError>;
+}
+
+impl ObsPropertiesOwner for ObsSourceRef {
+    fn properties_handle(&self) -> Result<ObsPropertiesHandle<Self>, ObsError> {
+        ObsPropertiesHandle::new(
+            self.clone(),
+            self.source.0 as *mut c_void,
+            self.runtime.clone(),
+        )
+    }
+}
+
+impl ObsPropertiesOwner for ObsOutputRef {
+    fn properties_handle(&self) -> Result<ObsPropertiesHandle<Self>, ObsError> {
+        ObsPropertiesHandle::new(
+            self.clone(),
+            self.output.0 as *mut c_void,
+            self.runtime.clone(),
+        )
+    }
+}
+
+impl ObsPropertiesOwner for ObsServiceRef {
+    fn properties_handle(&self) -> Result<ObsPropertiesHandle<Self>, ObsError> {
+        ObsPropertiesHandle::new(
+            self.clone(),
+            self.service.0 as *mut c_void,
+            self.runtime.clone(),
+        )
+    }
+}
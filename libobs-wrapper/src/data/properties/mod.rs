@@ -1,4 +1,5 @@
 mod enums;
+mod handle;
 mod macros;
 pub mod prop_impl;
 pub mod types;
@@ -9,6 +10,7 @@ use libobs::obs_properties;
 use macros::*;
 
 pub use enums::*;
+pub use handle::*;
 use types::*;
 
 use crate::{
@@ -72,37 +74,49 @@ pub(crate) fn get_properties_inner(
         return Ok(HashMap::new());
     }
 
-    run_with_obs!(runtime, (properties_raw), move || {
-        let mut result = HashMap::new();
-        let mut property = unsafe { libobs::obs_properties_first(properties_raw) };
-        while !property.is_null() {
-            let name = unsafe { libobs::obs_property_name(property) };
-            let name = unsafe { CStr::from_ptr(name as _) };
-            let name = name.to_string_lossy().to_string();
-
-            let p_type = unsafe { libobs::obs_property_get_type(property) };
-
-            let p_type = crate::macros::enum_from_number!(ObsPropertyType, p_type);
-
-            log::trace!("Property: {:?}", name);
-            match p_type {
-                Some(p_type) => {
-                    result.insert(name, unsafe { p_type.to_property_struct(property) });
-                }
-                None => {
-                    result.insert(name, ObsProperty::Invalid);
-                }
-            }
-
-            // Move to the next property
-            unsafe { libobs::obs_property_next(&mut property) };
-        }
+    run_with_obs!(runtime, (properties_raw), move || unsafe {
+        let result = read_properties(properties_raw);
+        libobs::obs_properties_destroy(properties_raw);
 
-        unsafe { libobs::obs_properties_destroy(properties_raw) };
         result
     })
 }
 
+/// Reads all properties without destroying them.
+///
+/// # Safety
+/// Must be called on the obs thread with a valid properties pointer.
+pub(crate) unsafe fn read_properties(
+    properties_raw: *mut obs_properties,
+) -> HashMap<String, ObsProperty> {
+    let mut result = HashMap::new();
+    let mut property = libobs::obs_properties_first(properties_raw);
+    while !property.is_null() {
+        let name = libobs::obs_property_name(property);
+        let name = CStr::from_ptr(name as _);
+        let name = name.to_string_lossy().to_string();
+
+        let p_type = libobs::obs_property_get_type(property);
+
+        let p_type = crate::macros::enum_from_number!(ObsPropertyType, p_type);
+
+        log::trace!("Property: {:?}", name);
+        match p_type {
+            Some(p_type) => {
+                result.insert(name, p_type.to_property_struct(property));
+            }
+            None => {
+                result.insert(name, ObsProperty::Invalid);
+            }
+        }
+
+        // Move to the next property
+        libobs::obs_property_next(&mut property);
+    }
+
+    result
+}
+
 /// This trait is implemented for all obs objects that can have properties
 pub trait ObsPropertyObject: ObsPropertyObjectPrivate {
     /// Returns the properties of the object
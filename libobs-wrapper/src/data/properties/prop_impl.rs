@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use crate::{
     data::output::ObsOutputRef,
     runtime::ObsRuntime,
+    services::ObsServiceRef,
     sources::ObsSourceRef,
     unsafe_send::Sendable,
     utils::{ObsError, ObsString},
@@ -78,3 +79,38 @@ impl ObsPropertyObjectPrivate for ObsOutputRef {
             .map_err(|e| ObsError::InvocationError(e.to_string()))
     }
 }
+
+impl ObsPropertyObject for ObsServiceRef {
+    fn get_properties(&self) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        let properties_raw = self.get_properties_raw()?;
+        get_properties_inner(properties_raw, self.runtime.clone())
+    }
+}
+
+impl ObsPropertyObjectPrivate for ObsServiceRef {
+    fn get_properties_raw(&self) -> Result<Sendable<*mut libobs::obs_properties_t>, ObsError> {
+        let service_ptr = self.service.clone();
+        self.runtime
+            .run_with_obs_result(move || unsafe {
+                let service_ptr = service_ptr;
+
+                Sendable(libobs::obs_service_properties(service_ptr.0))
+            })
+            .map_err(|e| ObsError::InvocationError(e.to_string()))
+    }
+
+    fn get_properties_by_id_raw<T: Into<ObsString> + Sync + Send>(
+        id: T,
+        runtime: ObsRuntime,
+    ) -> Result<Sendable<*mut libobs::obs_properties_t>, ObsError> {
+        let id: ObsString = id.into();
+        let id_ptr = id.as_ptr();
+        runtime
+            .run_with_obs_result(move || unsafe {
+                let id_ptr = id_ptr;
+
+                Sendable(libobs::obs_get_service_properties(id_ptr.0))
+            })
+            .map_err(|e| ObsError::InvocationError(e.to_string()))
+    }
+}
@@ -0,0 +1,328 @@
+//! Access to the raw audio of the mixers (tracks) of libobs.
+//!
+//! The audio is requested as planar `f32` samples through
+//! `obs_add_raw_audio_callback`, copied into owned buffers and sent
+//! through a bounded channel. Packets are dropped if they are not consumed
+//! fast enough, so the audio thread of libobs is never blocked.
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use lazy_static::lazy_static;
+use libobs::{audio_convert_info, audio_data};
+use tokio::sync::mpsc;
+
+use crate::{
+    context::ObsContext,
+    enums::{ObsSamplesPerSecond, ObsSpeakerLayout, OsEnumType},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::Sendable,
+    utils::ObsError,
+};
+
+static TAP_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
+struct RawAudioTap {
+    samples_per_sec: u32,
+    speakers: ObsSpeakerLayout,
+    sender: mpsc::Sender<ObsRawAudioFrame>,
+}
+
+lazy_static! {
+    /// Active audio taps, keyed by the id that is passed
+    /// as the `param` pointer to libobs.
+    static ref RAW_AUDIO_TAPS: RwLock<HashMap<usize, RawAudioTap>> = RwLock::new(HashMap::new());
+}
+
+/// The sample rate and speaker layout libobs should convert the audio to
+/// before it is handed to an audio tap. The sample format is always planar `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObsAudioConversion {
+    pub samples_per_sec: ObsSamplesPerSecond,
+    pub speakers: ObsSpeakerLayout,
+    /// Whether samples outside of `-1.0..=1.0` are passed through instead of being clamped
+    pub allow_clipping: bool,
+}
+
+impl ObsAudioConversion {
+    pub fn new(samples_per_sec: ObsSamplesPerSecond, speakers: ObsSpeakerLayout) -> Self {
+        Self {
+            samples_per_sec,
+            speakers,
+            allow_clipping: false,
+        }
+    }
+
+    fn as_convert_info(&self) -> audio_convert_info {
+        audio_convert_info {
+            samples_per_sec: self.samples_per_sec as u32,
+            format: libobs::audio_format_AUDIO_FORMAT_FLOAT_PLANAR,
+            speakers: self.speakers as OsEnumType,
+            allow_clipping: self.allow_clipping,
+        }
+    }
+}
+
+/// A packet of audio of a single mixer, copied out of libobs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObsRawAudioFrame {
+    /// Samples of every channel, each containing `frames` samples
+    pub planes: Vec<Vec<f32>>,
+    /// Number of samples per channel
+    pub frames: u32,
+    /// Timestamp of the first sample in nanoseconds
+    pub timestamp: u64,
+    pub samples_per_sec: u32,
+    pub speakers: ObsSpeakerLayout,
+}
+
+impl ObsRawAudioFrame {
+    /// Creates a packet from planar samples, one vector per channel of the speaker layout.
+    pub fn new(
+        planes: Vec<Vec<f32>>,
+        samples_per_sec: ObsSamplesPerSecond,
+        speakers: ObsSpeakerLayout,
+        timestamp: u64,
+    ) -> Self {
+        let frames = planes.first().map_or(0, |p| p.len()) as u32;
+        Self {
+            planes,
+            frames,
+            timestamp,
+            samples_per_sec: samples_per_sec as u32,
+            speakers,
+        }
+    }
+
+    /// Checks that there is one plane per channel with at least `frames` samples each.
+    pub fn validate(&self) -> Result<(), ObsError> {
+        let channels = self.speakers.channels();
+        if channels == 0 || self.planes.len() != channels {
+            return Err(ObsError::InvalidOperation(format!(
+                "Speaker layout {:?} requires {} planes, got {}",
+                self.speakers,
+                channels,
+                self.planes.len()
+            )));
+        }
+
+        if let Some(plane) = self.planes.iter().find(|p| p.len() < self.frames as usize) {
+            return Err(ObsError::InvalidOperation(format!(
+                "Every plane must contain {} samples, got {}",
+                self.frames,
+                plane.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn raw_audio_callback(
+    param: *mut c_void,
+    _mix_idx: usize,
+    data: *mut audio_data,
+) {
+    if data.is_null() {
+        return;
+    }
+
+    let key = param as usize;
+    let taps = match RAW_AUDIO_TAPS.read() {
+        Ok(t) => t,
+        Err(e) => {
+            log::warn!("Failed to acquire read lock for raw audio taps: {}", e);
+            return;
+        }
+    };
+
+    let tap = match taps.get(&key) {
+        Some(t) => t,
+        None => return,
+    };
+
+    // Don't copy the audio if it would be dropped anyway
+    if tap.sender.is_closed() || tap.sender.capacity() == 0 {
+        return;
+    }
+
+    let data = &*data;
+    let channels = tap.speakers.channels().min(data.data.len());
+    let planes = data.data[..channels]
+        .iter()
+        .map(|&plane| {
+            if plane.is_null() {
+                Vec::new()
+            } else {
+                std::slice::from_raw_parts(plane as *const f32, data.frames as usize).to_vec()
+            }
+        })
+        .collect();
+
+    let _ = tap.sender.try_send(ObsRawAudioFrame {
+        planes,
+        frames: data.frames,
+        timestamp: data.timestamp,
+        samples_per_sec: tap.samples_per_sec,
+        speakers: tap.speakers,
+    });
+}
+
+/// Receives the audio of an audio tap. The tap is removed from
+/// libobs as soon as this struct is dropped.
+#[derive(Debug)]
+pub struct ObsRawAudioStream {
+    receiver: mpsc::Receiver<ObsRawAudioFrame>,
+    mix_idx: usize,
+    samples_per_sec: u32,
+    speakers: ObsSpeakerLayout,
+    _guard: Arc<_ObsRawAudioTapGuard>,
+}
+
+impl ObsRawAudioStream {
+    /// The mixer (track) this stream receives audio from.
+    pub fn mix_idx(&self) -> usize {
+        self.mix_idx
+    }
+
+    pub fn samples_per_sec(&self) -> u32 {
+        self.samples_per_sec
+    }
+
+    pub fn speakers(&self) -> ObsSpeakerLayout {
+        self.speakers
+    }
+
+    /// Waits for the next audio packet.
+    pub async fn recv(&mut self) -> Option<ObsRawAudioFrame> {
+        self.receiver.recv().await
+    }
+
+    /// Blocks the current thread until the next audio packet is available.
+    ///
+    /// ## Panics
+    /// If called inside of an async runtime, use `recv` there instead.
+    pub fn blocking_recv(&mut self) -> Option<ObsRawAudioFrame> {
+        self.receiver.blocking_recv()
+    }
+
+    /// Returns the next audio packet if one is already queued.
+    pub fn try_recv(&mut self) -> Option<ObsRawAudioFrame> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+#[derive(Debug)]
+struct _ObsRawAudioTapGuard {
+    mix_idx: Sendable<usize>,
+    tap_key: Sendable<usize>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsRawAudioTapGuard, (mix_idx, tap_key), move || unsafe {
+    libobs::obs_remove_raw_audio_callback(
+        mix_idx,
+        Some(raw_audio_callback),
+        tap_key as *mut c_void,
+    );
+
+    if let Ok(mut taps) = RAW_AUDIO_TAPS.write() {
+        taps.remove(&tap_key);
+    }
+});
+
+impl ObsContext {
+    /// Registers an audio tap on the given mixer (track) and returns a stream
+    /// of its audio as planar `f32` samples.
+    ///
+    /// * `mix_idx` - The mixer (track) to receive audio from.
+    /// * `conversion` - Sample rate and speaker layout the audio is converted to. If `None`,
+    ///   the sample rate and speaker layout of the `ObsAudioInfo` the context was started with are used.
+    /// * `capacity` - How many packets are queued before new packets are dropped.
+    ///   A packet usually contains 1024 frames, so 64 packets are about 1.5 seconds at 44.1 kHz.
+    pub fn raw_audio_stream(
+        &self,
+        mix_idx: usize,
+        conversion: Option<ObsAudioConversion>,
+        capacity: usize,
+    ) -> Result<ObsRawAudioStream, ObsError> {
+        if capacity == 0 {
+            return Err(ObsError::InvalidOperation(
+                "Capacity must be at least 1".to_string(),
+            ));
+        }
+
+        if mix_idx >= libobs::MAX_AUDIO_MIXES as usize {
+            return Err(ObsError::InvalidOperation(format!(
+                "Audio mixer {} does not exist, the last mixer is {}",
+                mix_idx,
+                libobs::MAX_AUDIO_MIXES - 1
+            )));
+        }
+
+        let conversion = match conversion {
+            Some(c) => c,
+            None => {
+                let startup_info = self.startup_info().read().map_err(|_| {
+                    ObsError::LockError("Failed to acquire read lock on startup info".to_string())
+                })?;
+                let audio_info = &startup_info.obs_audio_info;
+
+                ObsAudioConversion::new(audio_info.samples_per_sec(), audio_info.speakers())
+            }
+        };
+
+        if conversion.speakers == ObsSpeakerLayout::Unknown {
+            return Err(ObsError::InvalidOperation(
+                "Can not tap audio with an unknown speaker layout".to_string(),
+            ));
+        }
+
+        let (sender, receiver) = mpsc::channel(capacity);
+        let tap_key = TAP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        RAW_AUDIO_TAPS
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on raw audio taps".to_string())
+            })?
+            .insert(
+                tap_key,
+                RawAudioTap {
+                    samples_per_sec: conversion.samples_per_sec as u32,
+                    speakers: conversion.speakers,
+                    sender,
+                },
+            );
+
+        // Created before registering, so the tap is always removed on error
+        let guard = Arc::new(_ObsRawAudioTapGuard {
+            mix_idx: Sendable(mix_idx),
+            tap_key: Sendable(tap_key),
+            runtime: self.runtime.clone(),
+        });
+
+        run_with_obs!(self.runtime, move || unsafe {
+            let convert_info = conversion.as_convert_info();
+            libobs::obs_add_raw_audio_callback(
+                mix_idx,
+                &convert_info,
+                Some(raw_audio_callback),
+                tap_key as *mut c_void,
+            );
+        })?;
+
+        Ok(ObsRawAudioStream {
+            receiver,
+            mix_idx,
+            samples_per_sec: conversion.samples_per_sec as u32,
+            speakers: conversion.speakers,
+            _guard: guard,
+        })
+    }
+}
@@ -0,0 +1,422 @@
+//! Access to the raw frames that libobs renders for the program output.
+//!
+//! A frame tap is registered with `obs_add_raw_video_callback2` and copies
+//! every frame into owned buffers which are sent through a bounded channel.
+//! If the receiver can't keep up, frames are dropped instead of stalling the
+//! video thread of libobs.
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use lazy_static::lazy_static;
+use libobs::{video_data, video_scale_info};
+use tokio::sync::mpsc;
+
+use crate::{
+    context::ObsContext,
+    enums::{ObsColorspace, ObsVideoFormat, ObsVideoRange, OsEnumType},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::Sendable,
+    utils::ObsError,
+};
+
+static TAP_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
+struct RawVideoTap {
+    format: ObsVideoFormat,
+    width: u32,
+    height: u32,
+    sender: mpsc::Sender<ObsRawVideoFrame>,
+}
+
+lazy_static! {
+    /// Active frame taps, keyed by the id that is passed
+    /// as the `param` pointer to libobs.
+    static ref RAW_VIDEO_TAPS: RwLock<HashMap<usize, RawVideoTap>> = RwLock::new(HashMap::new());
+}
+
+/// Returns the height of every plane of a frame with the given format,
+/// matching the layout libobs uses for its video frames.
+pub fn plane_heights(format: ObsVideoFormat, height: u32) -> Vec<u32> {
+    let half_height = height.div_ceil(2);
+    match format {
+        ObsVideoFormat::NONE => vec![],
+        ObsVideoFormat::I420 | ObsVideoFormat::I010 => vec![height, half_height, half_height],
+        ObsVideoFormat::NV12 | ObsVideoFormat::P010 => vec![height, half_height],
+        ObsVideoFormat::I40A => vec![height, half_height, half_height, height],
+        ObsVideoFormat::I444
+        | ObsVideoFormat::I412
+        | ObsVideoFormat::I422
+        | ObsVideoFormat::I210 => vec![height; 3],
+        ObsVideoFormat::I42A | ObsVideoFormat::YUVA | ObsVideoFormat::YA2L => vec![height; 4],
+        ObsVideoFormat::P216 | ObsVideoFormat::P416 => vec![height; 2],
+        ObsVideoFormat::Y800
+        | ObsVideoFormat::YVYU
+        | ObsVideoFormat::YUY2
+        | ObsVideoFormat::UYVY
+        | ObsVideoFormat::RGBA
+        | ObsVideoFormat::BGRA
+        | ObsVideoFormat::BGRX
+        | ObsVideoFormat::BGR3
+        | ObsVideoFormat::AYUV
+        | ObsVideoFormat::R10L
+        | ObsVideoFormat::V210 => vec![height],
+    }
+}
+
+/// The format and size libobs should convert the frames to before they
+/// are handed to a frame tap. Maps to `video_scale_info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObsVideoConversion {
+    pub format: ObsVideoFormat,
+    pub width: u32,
+    pub height: u32,
+    pub range: ObsVideoRange,
+    pub colorspace: ObsColorspace,
+}
+
+impl ObsVideoConversion {
+    /// Converts frames to the given format and size, using the
+    /// default range and colorspace.
+    pub fn new(format: ObsVideoFormat, width: u32, height: u32) -> Self {
+        Self {
+            format,
+            width,
+            height,
+            range: ObsVideoRange::Default,
+            colorspace: ObsColorspace::Default,
+        }
+    }
+
+    pub fn with_range(mut self, range: ObsVideoRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn with_colorspace(mut self, colorspace: ObsColorspace) -> Self {
+        self.colorspace = colorspace;
+        self
+    }
+
+    fn as_scale_info(&self) -> video_scale_info {
+        video_scale_info {
+            format: self.format as OsEnumType,
+            width: self.width,
+            height: self.height,
+            range: self.range as OsEnumType,
+            colorspace: self.colorspace as OsEnumType,
+        }
+    }
+}
+
+/// A single frame of the program output, copied out of libobs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsRawVideoFrame {
+    /// Pixel data of every plane. Each plane contains
+    /// `linesize[i] * plane_height` bytes, so rows may be padded.
+    pub planes: Vec<Vec<u8>>,
+    /// Number of bytes per row of every plane
+    pub linesize: Vec<u32>,
+    /// Timestamp of the frame in nanoseconds
+    pub timestamp: u64,
+    pub format: ObsVideoFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ObsRawVideoFrame {
+    /// Creates a frame from tightly packed RGBA pixels.
+    pub fn rgba(width: u32, height: u32, data: Vec<u8>, timestamp: u64) -> Self {
+        Self::packed(ObsVideoFormat::RGBA, width, height, data, timestamp)
+    }
+
+    /// Creates a frame from tightly packed BGRA pixels.
+    pub fn bgra(width: u32, height: u32, data: Vec<u8>, timestamp: u64) -> Self {
+        Self::packed(ObsVideoFormat::BGRA, width, height, data, timestamp)
+    }
+
+    fn packed(
+        format: ObsVideoFormat,
+        width: u32,
+        height: u32,
+        data: Vec<u8>,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            planes: vec![data],
+            linesize: vec![width * 4],
+            timestamp,
+            format,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a frame from a luma plane and an interleaved chroma plane
+    /// with half the resolution, both without row padding.
+    pub fn nv12(width: u32, height: u32, y: Vec<u8>, uv: Vec<u8>, timestamp: u64) -> Self {
+        Self {
+            planes: vec![y, uv],
+            linesize: vec![width, width.div_ceil(2) * 2],
+            timestamp,
+            format: ObsVideoFormat::NV12,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a frame from a luma plane and two chroma planes
+    /// with half the resolution, all without row padding.
+    pub fn i420(
+        width: u32,
+        height: u32,
+        y: Vec<u8>,
+        u: Vec<u8>,
+        v: Vec<u8>,
+        timestamp: u64,
+    ) -> Self {
+        let chroma_width = width.div_ceil(2);
+        Self {
+            planes: vec![y, u, v],
+            linesize: vec![width, chroma_width, chroma_width],
+            timestamp,
+            format: ObsVideoFormat::I420,
+            width,
+            height,
+        }
+    }
+
+    /// Checks that the frame has every plane of its format and that
+    /// the planes are large enough for the linesizes and height.
+    pub fn validate(&self) -> Result<(), ObsError> {
+        let heights = plane_heights(self.format, self.height);
+        if heights.is_empty() || heights.len() > libobs::MAX_AV_PLANES as usize {
+            return Err(ObsError::InvalidOperation(format!(
+                "Unsupported video format {:?}",
+                self.format
+            )));
+        }
+
+        if self.planes.len() != heights.len() || self.linesize.len() != heights.len() {
+            return Err(ObsError::InvalidOperation(format!(
+                "Video format {:?} requires {} planes, got {}",
+                self.format,
+                heights.len(),
+                self.planes.len()
+            )));
+        }
+
+        for (i, plane_height) in heights.into_iter().enumerate() {
+            let required = self.linesize[i] as usize * plane_height as usize;
+            if self.planes[i].len() < required {
+                return Err(ObsError::InvalidOperation(format!(
+                    "Plane {} must contain at least {} bytes, got {}",
+                    i,
+                    required,
+                    self.planes[i].len()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn raw_video_callback(param: *mut c_void, frame: *mut video_data) {
+    if frame.is_null() {
+        return;
+    }
+
+    let key = param as usize;
+    let taps = match RAW_VIDEO_TAPS.read() {
+        Ok(t) => t,
+        Err(e) => {
+            log::warn!("Failed to acquire read lock for raw video taps: {}", e);
+            return;
+        }
+    };
+
+    let tap = match taps.get(&key) {
+        Some(t) => t,
+        None => return,
+    };
+
+    // Don't copy the frame if it would be dropped anyway
+    if tap.sender.is_closed() || tap.sender.capacity() == 0 {
+        return;
+    }
+
+    let frame = &*frame;
+    let heights = plane_heights(tap.format, tap.height);
+    let mut planes = Vec::with_capacity(heights.len());
+    let mut linesize = Vec::with_capacity(heights.len());
+    for (i, plane_height) in heights.into_iter().enumerate() {
+        let data = frame.data[i];
+        let size = frame.linesize[i] as usize * plane_height as usize;
+        if data.is_null() {
+            planes.push(Vec::new());
+        } else {
+            planes.push(std::slice::from_raw_parts(data, size).to_vec());
+        }
+        linesize.push(frame.linesize[i]);
+    }
+
+    let _ = tap.sender.try_send(ObsRawVideoFrame {
+        planes,
+        linesize,
+        timestamp: frame.timestamp,
+        format: tap.format,
+        width: tap.width,
+        height: tap.height,
+    });
+}
+
+/// Receives the frames of a frame tap. The tap is removed from
+/// libobs as soon as this struct is dropped.
+#[derive(Debug)]
+pub struct ObsRawVideoStream {
+    receiver: mpsc::Receiver<ObsRawVideoFrame>,
+    format: ObsVideoFormat,
+    width: u32,
+    height: u32,
+    _guard: Arc<_ObsRawVideoTapGuard>,
+}
+
+impl ObsRawVideoStream {
+    /// Format of the frames of this stream.
+    pub fn format(&self) -> ObsVideoFormat {
+        self.format
+    }
+
+    /// Width and height of the frames of this stream.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Waits for the next frame.
+    pub async fn recv(&mut self) -> Option<ObsRawVideoFrame> {
+        self.receiver.recv().await
+    }
+
+    /// Blocks the current thread until the next frame is available.
+    ///
+    /// ## Panics
+    /// If called inside of an async runtime, use `recv` there instead.
+    pub fn blocking_recv(&mut self) -> Option<ObsRawVideoFrame> {
+        self.receiver.blocking_recv()
+    }
+
+    /// Returns the next frame if one is already queued.
+    pub fn try_recv(&mut self) -> Option<ObsRawVideoFrame> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+#[derive(Debug)]
+struct _ObsRawVideoTapGuard {
+    tap_key: Sendable<usize>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsRawVideoTapGuard, (tap_key), move || unsafe {
+    libobs::obs_remove_raw_video_callback(Some(raw_video_callback), tap_key as *mut c_void);
+
+    if let Ok(mut taps) = RAW_VIDEO_TAPS.write() {
+        taps.remove(&tap_key);
+    }
+});
+
+impl ObsContext {
+    /// Registers a frame tap on the program output and returns a stream
+    /// of the rendered frames.
+    ///
+    /// * `conversion` - Format and size the frames are converted to. If `None`,
+    ///   the output format and size of the current `ObsVideoInfo` are used.
+    /// * `frame_rate_divisor` - Only every n-th frame is delivered, `1` delivers every frame.
+    /// * `capacity` - How many frames are queued before new frames are dropped.
+    pub fn raw_video_stream(
+        &self,
+        conversion: Option<ObsVideoConversion>,
+        frame_rate_divisor: u32,
+        capacity: usize,
+    ) -> Result<ObsRawVideoStream, ObsError> {
+        if frame_rate_divisor == 0 || capacity == 0 {
+            return Err(ObsError::InvalidOperation(
+                "Frame rate divisor and capacity must be at least 1".to_string(),
+            ));
+        }
+
+        let (format, width, height) = match conversion {
+            Some(c) => (c.format, c.width, c.height),
+            None => {
+                let startup_info = self.startup_info().read().map_err(|_| {
+                    ObsError::LockError("Failed to acquire read lock on startup info".to_string())
+                })?;
+                let ovi = &startup_info.obs_video_info;
+                let format = ovi.get_output_format().ok_or_else(|| {
+                    ObsError::EnumConversionError("Unknown output video format".to_string())
+                })?;
+
+                (format, ovi.get_output_width(), ovi.get_output_height())
+            }
+        };
+
+        if format == ObsVideoFormat::NONE || width == 0 || height == 0 {
+            return Err(ObsError::InvalidOperation(format!(
+                "Can not tap frames with format {:?} and size {}x{}",
+                format, width, height
+            )));
+        }
+
+        let (sender, receiver) = mpsc::channel(capacity);
+        let tap_key = TAP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        RAW_VIDEO_TAPS
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on raw video taps".to_string())
+            })?
+            .insert(
+                tap_key,
+                RawVideoTap {
+                    format,
+                    width,
+                    height,
+                    sender,
+                },
+            );
+
+        // Created before registering, so the tap is always removed on error
+        let guard = Arc::new(_ObsRawVideoTapGuard {
+            tap_key: Sendable(tap_key),
+            runtime: self.runtime.clone(),
+        });
+
+        run_with_obs!(self.runtime, move || unsafe {
+            let scale_info = conversion.map(|c| c.as_scale_info());
+            let scale_ptr = scale_info
+                .as_ref()
+                .map_or(std::ptr::null(), |info| info as *const video_scale_info);
+
+            libobs::obs_add_raw_video_callback2(
+                scale_ptr,
+                frame_rate_divisor,
+                Some(raw_video_callback),
+                tap_key as *mut c_void,
+            );
+        })?;
+
+        Ok(ObsRawVideoStream {
+            receiver,
+            format,
+            width,
+            height,
+            _guard: guard,
+        })
+    }
+}
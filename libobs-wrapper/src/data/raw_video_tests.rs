@@ -0,0 +1,72 @@
+use crate::enums::ObsVideoFormat;
+
+use super::raw_video::{plane_heights, ObsRawVideoFrame};
+
+#[test]
+fn test_plane_heights_planar() {
+    assert_eq!(
+        plane_heights(ObsVideoFormat::I420, 1080),
+        vec![1080, 540, 540]
+    );
+    assert_eq!(plane_heights(ObsVideoFormat::NV12, 1080), vec![1080, 540]);
+    assert_eq!(plane_heights(ObsVideoFormat::I444, 720), vec![720; 3]);
+    assert_eq!(
+        plane_heights(ObsVideoFormat::I40A, 720),
+        vec![720, 360, 360, 720]
+    );
+}
+
+#[test]
+fn test_plane_heights_odd_height() {
+    // Chroma planes are rounded up so the last row is not cut off
+    assert_eq!(
+        plane_heights(ObsVideoFormat::I420, 721),
+        vec![721, 361, 361]
+    );
+    assert_eq!(plane_heights(ObsVideoFormat::P010, 3), vec![3, 2]);
+}
+
+#[test]
+fn test_plane_heights_packed() {
+    for format in [
+        ObsVideoFormat::RGBA,
+        ObsVideoFormat::BGRA,
+        ObsVideoFormat::BGRX,
+        ObsVideoFormat::YUY2,
+        ObsVideoFormat::Y800,
+    ] {
+        assert_eq!(plane_heights(format, 480), vec![480], "{:?}", format);
+    }
+
+    assert!(plane_heights(ObsVideoFormat::NONE, 480).is_empty());
+}
+
+#[test]
+fn test_frame_constructors_are_valid() {
+    let rgba = ObsRawVideoFrame::rgba(4, 2, vec![0; 4 * 4 * 2], 0);
+    assert!(rgba.validate().is_ok());
+
+    let nv12 = ObsRawVideoFrame::nv12(3, 3, vec![0; 3 * 3], vec![0; 4 * 2], 0);
+    assert_eq!(nv12.linesize, vec![3, 4]);
+    assert!(nv12.validate().is_ok());
+
+    let i420 = ObsRawVideoFrame::i420(3, 3, vec![0; 9], vec![0; 4], vec![0; 4], 0);
+    assert_eq!(i420.linesize, vec![3, 2, 2]);
+    assert!(i420.validate().is_ok());
+}
+
+#[test]
+fn test_frame_validation_errors() {
+    // Plane too small for the linesize and height
+    let rgba = ObsRawVideoFrame::rgba(4, 2, vec![0; 4 * 4], 0);
+    assert!(rgba.validate().is_err());
+
+    // Missing chroma plane
+    let mut nv12 = ObsRawVideoFrame::nv12(2, 2, vec![0; 4], vec![0; 2], 0);
+    nv12.planes.pop();
+    assert!(nv12.validate().is_err());
+
+    let mut none = ObsRawVideoFrame::rgba(1, 1, vec![0; 4], 0);
+    none.format = ObsVideoFormat::NONE;
+    assert!(none.validate().is_err());
+}
@@ -0,0 +1,338 @@
+use serde::{
+    de::{self, DeserializeSeed, Deserializer, IntoDeserializer, Visitor},
+    forward_to_deserialize_any,
+};
+
+use crate::utils::ObsError;
+
+use super::{value::ELEMENT_VALUE_KEY, ObsDataValue};
+
+impl de::Error for ObsError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        ObsError::SerializationError(msg.to_string())
+    }
+}
+
+/// Deserializes a value from an `ObsDataValue`, the counterpart of `ObsDataSerializer`.
+#[derive(Debug, Clone)]
+pub struct ObsDataDeserializer {
+    value: ObsDataValue,
+    /// Array elements that are not objects are wrapped in `{ "value": ... }`
+    in_array: bool,
+}
+
+impl ObsDataDeserializer {
+    pub fn new(value: ObsDataValue) -> Self {
+        Self {
+            value,
+            in_array: false,
+        }
+    }
+
+    fn array_element(value: ObsDataValue) -> Self {
+        Self {
+            value,
+            in_array: true,
+        }
+    }
+
+    /// Unwraps array elements if something other than an object is expected.
+    fn into_value(self) -> ObsDataValue {
+        match self.value {
+            ObsDataValue::Object(fields) if self.in_array => fields
+                .into_iter()
+                .find(|(key, _)| key == ELEMENT_VALUE_KEY)
+                .map_or(ObsDataValue::Null, |(_, value)| value),
+            value => value,
+        }
+    }
+
+    fn deserialize_integer<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        match self.into_value() {
+            // Numbers in JSON files can be written as `5.0`, which libobs reads as double
+            ObsDataValue::Double(value) if value.fract() == 0.0 => visitor.visit_i64(value as i64),
+            value => ObsDataDeserializer::new(value).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_value<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        ObsDataDeserializer::new(self.into_value()).deserialize_any(visitor)
+    }
+}
+
+impl<'de> Deserializer<'de> for ObsDataDeserializer {
+    type Error = ObsError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        match self.value {
+            ObsDataValue::Null => visitor.visit_unit(),
+            ObsDataValue::Bool(value) => visitor.visit_bool(value),
+            ObsDataValue::Int(value) => visitor.visit_i64(value),
+            ObsDataValue::Double(value) => visitor.visit_f64(value),
+            ObsDataValue::String(value) => visitor.visit_string(value),
+            ObsDataValue::Array(items) => visitor.visit_seq(SeqDeserializer {
+                items: items.into_iter(),
+            }),
+            ObsDataValue::Object(fields) => visitor.visit_map(MapDeserializer {
+                fields: fields.into_iter(),
+                value: None,
+            }),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_integer(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        match &self.value {
+            ObsDataValue::Null => visitor.visit_none(),
+            // `None` elements of arrays are stored as empty objects
+            ObsDataValue::Object(fields) if self.in_array && fields.is_empty() => {
+                visitor.visit_none()
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        self.deserialize_value(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        let value = match self.value {
+            // Unit variants in arrays are wrapped strings
+            ObsDataValue::Object(mut fields)
+                if self.in_array
+                    && fields.len() == 1
+                    && fields[0].0 == ELEMENT_VALUE_KEY
+                    && matches!(fields[0].1, ObsDataValue::String(_)) =>
+            {
+                fields.remove(0).1
+            }
+            value => value,
+        };
+
+        match value {
+            ObsDataValue::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            ObsDataValue::Object(fields) if fields.len() == 1 => {
+                let (variant, value) = fields.into_iter().next().unwrap();
+                visitor.visit_enum(EnumDeserializer { variant, value })
+            }
+            _ => Err(de::Error::custom(
+                "Expected a string or an object with a single key for an enum",
+            )),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ObsError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        map struct identifier
+    }
+}
+
+struct SeqDeserializer {
+    items: std::vec::IntoIter<ObsDataValue>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqDeserializer {
+    type Error = ObsError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ObsError> {
+        match self.items.next() {
+            Some(item) => seed
+                .deserialize(ObsDataDeserializer::array_element(item))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+struct MapDeserializer {
+    fields: std::vec::IntoIter<(String, ObsDataValue)>,
+    value: Option<ObsDataValue>,
+}
+
+impl<'de> de::MapAccess<'de> for MapDeserializer {
+    type Error = ObsError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ObsError> {
+        match self.fields.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, ObsError> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| <ObsError as de::Error>::custom("next_value called before next_key"))?;
+
+        seed.deserialize(ObsDataDeserializer::new(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+struct EnumDeserializer {
+    variant: String,
+    value: ObsDataValue,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = ObsError;
+    type Variant = ObsDataDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, ObsDataDeserializer), ObsError> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, ObsDataDeserializer::new(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ObsDataDeserializer {
+    type Error = ObsError;
+
+    fn unit_variant(self) -> Result<(), ObsError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, ObsError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, ObsError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ObsError> {
+        self.deserialize_any(visitor)
+    }
+}
@@ -0,0 +1,66 @@
+//! Conversion between `ObsData` and types implementing `Serialize`/`Deserialize`
+//!
+//! Structs and maps are stored as objects, nested structs as child objects
+//! and sequences as `obs_data_array`. Optional fields that are `None` are
+//! left out, so the settings of a source keep their default values.
+//!
+//! ```no_run
+//! use libobs_wrapper::{context::ObsContext, data::serde::{from_obs_data, to_obs_data}, utils::StartupInfo};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct ColorSettings {
+//!     color: u32,
+//!     width: u32,
+//!     height: u32,
+//! }
+//!
+//! let context = ObsContext::new(StartupInfo::default()).unwrap();
+//! let settings = ColorSettings { color: 0xFF0000FF, width: 1920, height: 1080 };
+//!
+//! let data = to_obs_data(&settings, context.runtime().clone()).unwrap();
+//! let settings: ColorSettings = from_obs_data(&data).unwrap();
+//! ```
+mod de;
+mod ser;
+#[cfg(test)]
+mod serde_tests;
+mod value;
+
+pub use de::*;
+pub use ser::*;
+pub use value::ObsDataValue;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{run_with_obs, runtime::ObsRuntime, unsafe_send::Sendable, utils::ObsError};
+
+use super::{ObsData, ObsDataGetters};
+
+/// Converts the value into a new `ObsData`. The value has to serialize to a struct or map.
+pub fn to_obs_data<T: Serialize + ?Sized>(
+    value: &T,
+    runtime: ObsRuntime,
+) -> Result<ObsData, ObsError> {
+    let ObsDataValue::Object(fields) = value.serialize(ObsDataSerializer)? else {
+        return Err(ObsError::SerializationError(
+            "Only structs and maps can be converted to ObsData".to_string(),
+        ));
+    };
+
+    let data = run_with_obs!(runtime, move || unsafe {
+        Sendable(value::write_object(&fields))
+    })?;
+
+    Ok(ObsData::from_raw(data, runtime))
+}
+
+/// Reads the value from `ObsData`, including keys that only have a default value.
+pub fn from_obs_data<T: DeserializeOwned, D: ObsDataGetters>(data: &D) -> Result<T, ObsError> {
+    let data_ptr = data.as_ptr();
+    let value = run_with_obs!(data.runtime(), (data_ptr), move || unsafe {
+        value::read_object(data_ptr)
+    })?;
+
+    T::deserialize(ObsDataDeserializer::new(value))
+}
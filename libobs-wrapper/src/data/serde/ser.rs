@@ -0,0 +1,520 @@
+use serde::{
+    ser::{self, Impossible},
+    Serialize,
+};
+
+use crate::utils::ObsError;
+
+use super::ObsDataValue;
+
+/// Serializes a value into an `ObsDataValue`. Structs and maps become
+/// objects, sequences and tuples become arrays and enums are stored
+/// externally tagged, like `serde_json` does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ObsDataSerializer;
+
+impl ser::Error for ObsError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        ObsError::SerializationError(msg.to_string())
+    }
+}
+
+impl ser::Serializer for ObsDataSerializer {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeTupleVariant;
+    type SerializeMap = SerializeObject;
+    type SerializeStruct = SerializeObject;
+    type SerializeStructVariant = SerializeStructVariant;
+
+    fn serialize_bool(self, v: bool) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Int(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<ObsDataValue, ObsError> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<ObsDataValue, ObsError> {
+        // obs_data only stores signed integers
+        let v = i64::try_from(v)
+            .map_err(|_| ObsError::SerializationError(format!("{} does not fit into an i64", v)))?;
+
+        self.serialize_i64(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<ObsDataValue, ObsError> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Array(
+            v.iter().map(|b| ObsDataValue::Int(*b as i64)).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<ObsDataValue, ObsError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<ObsDataValue, ObsError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<ObsDataValue, ObsError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<ObsDataValue, ObsError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Object(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray, ObsError> {
+        Ok(SerializeArray {
+            items: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, ObsError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, ObsError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeTupleVariant, ObsError> {
+        Ok(SerializeTupleVariant {
+            variant,
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeObject, ObsError> {
+        Ok(SerializeObject {
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject, ObsError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeStructVariant, ObsError> {
+        Ok(SerializeStructVariant {
+            variant,
+            fields: Vec::with_capacity(len),
+        })
+    }
+}
+
+pub struct SerializeArray {
+    items: Vec<ObsDataValue>,
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), ObsError> {
+        self.items.push(value.serialize(ObsDataSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Array(self.items))
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), ObsError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), ObsError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub struct SerializeTupleVariant {
+    variant: &'static str,
+    items: Vec<ObsDataValue>,
+}
+
+impl ser::SerializeTupleVariant for SerializeTupleVariant {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), ObsError> {
+        self.items.push(value.serialize(ObsDataSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Object(vec![(
+            self.variant.to_string(),
+            ObsDataValue::Array(self.items),
+        )]))
+    }
+}
+
+pub struct SerializeObject {
+    fields: Vec<(String, ObsDataValue)>,
+    next_key: Option<String>,
+}
+
+impl SerializeObject {
+    fn push_field(&mut self, key: String, value: ObsDataValue) {
+        // `None` values are left out, they are read back as `None` because the key is missing
+        if value != ObsDataValue::Null {
+            self.fields.push((key, value));
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeObject {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), ObsError> {
+        self.next_key = Some(key.serialize(MapKeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), ObsError> {
+        let key = self.next_key.take().ok_or_else(|| {
+            ObsError::SerializationError("serialize_value called before serialize_key".to_string())
+        })?;
+
+        let value = value.serialize(ObsDataSerializer)?;
+        self.push_field(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Object(self.fields))
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ObsError> {
+        let value = value.serialize(ObsDataSerializer)?;
+        self.push_field(key.to_string(), value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Object(self.fields))
+    }
+}
+
+pub struct SerializeStructVariant {
+    variant: &'static str,
+    fields: Vec<(String, ObsDataValue)>,
+}
+
+impl ser::SerializeStructVariant for SerializeStructVariant {
+    type Ok = ObsDataValue;
+    type Error = ObsError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ObsError> {
+        let value = value.serialize(ObsDataSerializer)?;
+        if value != ObsDataValue::Null {
+            self.fields.push((key.to_string(), value));
+        }
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObsDataValue, ObsError> {
+        Ok(ObsDataValue::Object(vec![(
+            self.variant.to_string(),
+            ObsDataValue::Object(self.fields),
+        )]))
+    }
+}
+
+/// Keys of `obs_data` are always strings, numbers are converted like `serde_json` does.
+struct MapKeySerializer;
+
+fn key_must_be_a_string() -> ObsError {
+    ObsError::SerializationError("Map keys must be strings or integers".to_string())
+}
+
+impl ser::Serializer for MapKeySerializer {
+    type Ok = String;
+    type Error = ObsError;
+
+    type SerializeSeq = Impossible<String, ObsError>;
+    type SerializeTuple = Impossible<String, ObsError>;
+    type SerializeTupleStruct = Impossible<String, ObsError>;
+    type SerializeTupleVariant = Impossible<String, ObsError>;
+    type SerializeMap = Impossible<String, ObsError>;
+    type SerializeStruct = Impossible<String, ObsError>;
+    type SerializeStructVariant = Impossible<String, ObsError>;
+
+    fn serialize_bool(self, _v: bool) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, ObsError> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_none(self) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit(self) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String, ObsError> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<String, ObsError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<String, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, ObsError> {
+        Err(key_must_be_a_string())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, ObsError> {
+        Err(key_must_be_a_string())
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ObsDataDeserializer, ObsDataSerializer, ObsDataValue};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Crop {
+    left: i32,
+    right: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Fill,
+    Scale(f64),
+    Custom { width: u32, height: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    name: String,
+    volume: f64,
+    enabled: bool,
+    crop: Crop,
+    playlist: Vec<String>,
+    crops: Vec<Crop>,
+    modes: Vec<Mode>,
+    mode: Mode,
+    hotkey: Option<String>,
+    labels: BTreeMap<String, u8>,
+}
+
+fn roundtrip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+    let serialized = value.serialize(ObsDataSerializer).unwrap();
+    T::deserialize(ObsDataDeserializer::new(serialized)).unwrap()
+}
+
+fn settings() -> Settings {
+    Settings {
+        name: "capture".to_string(),
+        volume: 0.5,
+        enabled: true,
+        crop: Crop { left: 4, right: 8 },
+        playlist: vec!["a.mp4".to_string(), "b.mp4".to_string()],
+        crops: vec![Crop { left: 1, right: 2 }],
+        modes: vec![Mode::Fill, Mode::Scale(2.0)],
+        mode: Mode::Custom {
+            width: 1920,
+            height: 1080,
+        },
+        hotkey: None,
+        labels: BTreeMap::from([("first".to_string(), 1)]),
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    let settings = settings();
+    assert_eq!(roundtrip(&settings), settings);
+}
+
+#[test]
+fn test_none_is_left_out() {
+    let ObsDataValue::Object(fields) = settings().serialize(ObsDataSerializer).unwrap() else {
+        panic!("Settings should serialize to an object");
+    };
+
+    assert!(fields.iter().all(|(key, _)| key != "hotkey"));
+}
+
+#[test]
+fn test_array_elements_are_objects() {
+    let value = vec!["a".to_string()].serialize(ObsDataSerializer).unwrap();
+    assert_eq!(
+        value,
+        ObsDataValue::Array(vec![ObsDataValue::String("a".to_string())])
+    );
+
+    // Elements as libobs returns them, wrapped in an object like an editable list
+    let stored = ObsDataValue::Array(vec![ObsDataValue::Object(vec![
+        ("value".to_string(), ObsDataValue::String("a".to_string())),
+        ("selected".to_string(), ObsDataValue::Bool(false)),
+    ])]);
+
+    let list = Vec::<String>::deserialize(ObsDataDeserializer::new(stored)).unwrap();
+    assert_eq!(list, ["a"]);
+}
+
+#[test]
+fn test_integral_double_as_integer() {
+    let value = ObsDataValue::Object(vec![
+        ("left".to_string(), ObsDataValue::Double(3.0)),
+        ("right".to_string(), ObsDataValue::Int(5)),
+    ]);
+
+    let crop = Crop::deserialize(ObsDataDeserializer::new(value)).unwrap();
+    assert_eq!(crop, Crop { left: 3, right: 5 });
+}
+
+#[test]
+fn test_u64_out_of_range() {
+    assert!(u64::MAX.serialize(ObsDataSerializer).is_err());
+}
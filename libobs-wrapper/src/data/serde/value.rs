@@ -0,0 +1,154 @@
+use std::ffi::{c_char, CStr};
+
+use libobs::{obs_data_array_t, obs_data_item_t, obs_data_t};
+
+use crate::utils::ObsString;
+
+/// A value stored in `ObsData`, used as the intermediate
+/// representation of the serializer and deserializer.
+///
+/// Arrays in `obs_data` can only contain objects, so elements that are
+/// not objects are stored as `{ "value": ... }`, which is the layout of
+/// editable lists in OBS.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObsDataValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Double(f64),
+    String(String),
+    Object(Vec<(String, ObsDataValue)>),
+    Array(Vec<ObsDataValue>),
+}
+
+/// Key of array elements that are not objects
+pub(super) const ELEMENT_VALUE_KEY: &str = "value";
+
+unsafe fn string_from_ptr(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(ptr).to_string_lossy().to_string()
+}
+
+/// Creates a new `obs_data` with the given fields, the caller owns the returned reference.
+pub(super) unsafe fn write_object(fields: &[(String, ObsDataValue)]) -> *mut obs_data_t {
+    let data = libobs::obs_data_create();
+    for (key, value) in fields {
+        write_field(data, key, value);
+    }
+
+    data
+}
+
+unsafe fn write_field(data: *mut obs_data_t, key: &str, value: &ObsDataValue) {
+    let key = ObsString::new(key);
+    let key_ptr = key.as_ptr().0;
+
+    match value {
+        // There is no null value in obs_data, the key is left out instead
+        ObsDataValue::Null => {}
+        ObsDataValue::Bool(value) => libobs::obs_data_set_bool(data, key_ptr, *value),
+        ObsDataValue::Int(value) => libobs::obs_data_set_int(data, key_ptr, *value),
+        ObsDataValue::Double(value) => libobs::obs_data_set_double(data, key_ptr, *value),
+        ObsDataValue::String(value) => {
+            let value = ObsString::new(value);
+            libobs::obs_data_set_string(data, key_ptr, value.as_ptr().0);
+        }
+        ObsDataValue::Object(fields) => {
+            let obj = write_object(fields);
+            libobs::obs_data_set_obj(data, key_ptr, obj);
+            libobs::obs_data_release(obj);
+        }
+        ObsDataValue::Array(items) => {
+            let array = write_array(items);
+            libobs::obs_data_set_array(data, key_ptr, array);
+            libobs::obs_data_array_release(array);
+        }
+    }
+}
+
+unsafe fn write_array(items: &[ObsDataValue]) -> *mut obs_data_array_t {
+    let array = libobs::obs_data_array_create();
+    for item in items {
+        let obj = match item {
+            ObsDataValue::Object(fields) => write_object(fields),
+            other => {
+                let obj = libobs::obs_data_create();
+                write_field(obj, ELEMENT_VALUE_KEY, other);
+                obj
+            }
+        };
+
+        libobs::obs_data_array_push_back(array, obj);
+        libobs::obs_data_release(obj);
+    }
+
+    array
+}
+
+/// Reads all values of the given `obs_data`. Keys that only have a
+/// default value are included as well.
+pub(super) unsafe fn read_object(data: *mut obs_data_t) -> ObsDataValue {
+    let mut fields = Vec::new();
+
+    let mut item = libobs::obs_data_first(data);
+    while !item.is_null() {
+        let name = string_from_ptr(libobs::obs_data_item_get_name(item));
+        fields.push((name, read_item(item)));
+
+        libobs::obs_data_item_next(&mut item);
+    }
+
+    ObsDataValue::Object(fields)
+}
+
+unsafe fn read_item(item: *mut obs_data_item_t) -> ObsDataValue {
+    match libobs::obs_data_item_gettype(item) {
+        libobs::obs_data_type_OBS_DATA_STRING => {
+            ObsDataValue::String(string_from_ptr(libobs::obs_data_item_get_string(item)))
+        }
+        libobs::obs_data_type_OBS_DATA_NUMBER => {
+            if libobs::obs_data_item_numtype(item)
+                == libobs::obs_data_number_type_OBS_DATA_NUM_DOUBLE
+            {
+                ObsDataValue::Double(libobs::obs_data_item_get_double(item))
+            } else {
+                ObsDataValue::Int(libobs::obs_data_item_get_int(item))
+            }
+        }
+        libobs::obs_data_type_OBS_DATA_BOOLEAN => {
+            ObsDataValue::Bool(libobs::obs_data_item_get_bool(item))
+        }
+        libobs::obs_data_type_OBS_DATA_OBJECT => {
+            let obj = libobs::obs_data_item_get_obj(item);
+            if obj.is_null() {
+                return ObsDataValue::Null;
+            }
+
+            let value = read_object(obj);
+            libobs::obs_data_release(obj);
+            value
+        }
+        libobs::obs_data_type_OBS_DATA_ARRAY => {
+            let array = libobs::obs_data_item_get_array(item);
+            if array.is_null() {
+                return ObsDataValue::Null;
+            }
+
+            let items = (0..libobs::obs_data_array_count(array))
+                .map(|i| {
+                    let obj = libobs::obs_data_array_item(array, i);
+                    let value = read_object(obj);
+                    libobs::obs_data_release(obj);
+                    value
+                })
+                .collect();
+
+            libobs::obs_data_array_release(array);
+            ObsDataValue::Array(items)
+        }
+        _ => ObsDataValue::Null,
+    }
+}
@@ -0,0 +1,131 @@
+//! Render statistics of the OBS context and periodic stats broadcasts.
+//!
+//! Statistics of single outputs are available via [`ObsOutputRef::stats`](crate::data::output::ObsOutputRef::stats).
+use std::{thread, time::Duration};
+
+use tokio::sync::broadcast;
+
+use crate::{context::ObsContext, run_with_obs, runtime::ObsRuntime, utils::ObsError};
+
+/// Rendering and encoding statistics of the whole OBS context,
+/// the same values that are shown in the stats dock of OBS Studio.
+///
+/// All frame counters are totals since the context was started.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ObsRenderStats {
+    /// Frames that could not be rendered in time, because rendering took too long
+    pub lagged_frames: u32,
+    /// Frames rendered in total
+    pub total_frames: u32,
+    /// Frames skipped by the video output, because encoding took too long
+    pub skipped_frames: u32,
+    /// Frames passed to the video output in total
+    pub total_output_frames: u32,
+    /// Average time it takes to render a single frame
+    pub average_frame_time: Duration,
+    /// Time between two frames at the configured fps
+    pub frame_interval: Duration,
+    /// Frames per second that are actually rendered
+    pub active_fps: f64,
+}
+
+impl ObsRenderStats {
+    /// Percentage of frames that missed the render deadline
+    pub fn lagged_frames_percent(&self) -> f64 {
+        percent(self.lagged_frames as f64, self.total_frames as f64)
+    }
+
+    /// Percentage of frames skipped by the video output. A rising value
+    /// means that the encoder is overloaded.
+    pub fn skipped_frames_percent(&self) -> f64 {
+        percent(self.skipped_frames as f64, self.total_output_frames as f64)
+    }
+}
+
+pub(crate) fn percent(part: f64, total: f64) -> f64 {
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    part / total * 100.0
+}
+
+/// Spawns a thread that calls `poll` every `interval` and sends the result
+/// to the returned receiver. The thread stops once all receivers are dropped
+/// or `poll` fails.
+pub(crate) fn spawn_stats_broadcast<T, F>(interval: Duration, mut poll: F) -> broadcast::Receiver<T>
+where
+    T: Clone + Send + 'static,
+    F: FnMut() -> Result<Option<T>, ObsError> + Send + 'static,
+{
+    let (tx, rx) = broadcast::channel(16);
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        if tx.receiver_count() == 0 {
+            break;
+        }
+
+        let stats = match poll() {
+            Ok(Some(stats)) => stats,
+            Ok(None) => break,
+            Err(e) => {
+                log::warn!("Stopping stats broadcast: {:?}", e);
+                break;
+            }
+        };
+
+        if tx.send(stats).is_err() {
+            break;
+        }
+    });
+
+    rx
+}
+
+fn read_render_stats(runtime: &ObsRuntime) -> Result<ObsRenderStats, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        let video = libobs::obs_get_video();
+        let (skipped_frames, total_output_frames) = if video.is_null() {
+            (0, 0)
+        } else {
+            (
+                libobs::video_output_get_skipped_frames(video),
+                libobs::video_output_get_total_frames(video),
+            )
+        };
+
+        ObsRenderStats {
+            lagged_frames: libobs::obs_get_lagged_frames(),
+            total_frames: libobs::obs_get_total_frames(),
+            skipped_frames,
+            total_output_frames,
+            average_frame_time: Duration::from_nanos(libobs::obs_get_average_frame_time_ns()),
+            frame_interval: Duration::from_nanos(libobs::obs_get_frame_interval_ns()),
+            active_fps: libobs::obs_get_active_fps(),
+        }
+    })
+}
+
+impl ObsContext {
+    /// Returns the current render statistics of the context.
+    pub fn render_stats(&self) -> Result<ObsRenderStats, ObsError> {
+        read_render_stats(&self.runtime)
+    }
+
+    /// Sends the render statistics to the returned receiver every `interval`.
+    ///
+    /// The broadcast stops once all receivers are dropped or the context is shut down.
+    pub fn render_stats_broadcast(
+        &self,
+        interval: Duration,
+    ) -> broadcast::Receiver<ObsRenderStats> {
+        let runtime = self.runtime.downgrade();
+        spawn_stats_broadcast(interval, move || {
+            let Some(runtime) = runtime.upgrade() else {
+                return Ok(None);
+            };
+
+            read_render_stats(&runtime).map(Some)
+        })
+    }
+}
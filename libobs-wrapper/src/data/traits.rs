@@ -3,7 +3,9 @@ use std::ffi::CStr;
 use libobs::obs_data;
 
 use crate::{
-    data::ObsData,
+    data::{ObsData, ObsDataArray},
+    enums::{ObsDataNumberType, ObsDataType},
+    macros::enum_from_number,
     run_with_obs,
     runtime::ObsRuntime,
     unsafe_send::Sendable,
@@ -20,6 +22,15 @@ impl ObsDataGetters for ObsData {
     }
 }
 
+/// A key of `ObsData` and the type of its value, as returned by `ObsDataGetters::items`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsDataItem {
+    pub name: String,
+    pub data_type: ObsDataType,
+    /// `ObsDataNumberType::Invalid` if the value is not a number
+    pub number_type: ObsDataNumberType,
+}
+
 pub trait ObsDataGetters {
     fn runtime(&self) -> &ObsRuntime;
     fn as_ptr(&self) -> Sendable<*mut obs_data>;
@@ -116,6 +127,246 @@ pub trait ObsDataGetters {
         Ok(result)
     }
 
+    /// Returns a nested object. The object is shared with this data,
+    /// changes made to it are visible here as well.
+    fn get_obj<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsData>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        let result = run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_obj(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsData::from_raw(result, self.runtime().clone())))
+    }
+
+    /// Returns an array of objects, which is shared with this data like `get_obj`.
+    fn get_array<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsDataArray>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        let result = run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_array(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsDataArray::from_raw(result, self.runtime().clone())))
+    }
+
+    /// Whether a value was set for this key, ignoring its default value.
+    fn has_user_value<T: Into<ObsString> + Sync + Send>(&self, key: T) -> Result<bool, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_user_value(data_ptr, key_ptr)
+        })
+    }
+
+    fn has_default_value<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<bool, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_default_value(data_ptr, key_ptr)
+        })
+    }
+
+    fn get_default_string<T: Into<ObsString> + Send + Sync>(
+        &self,
+        key: T,
+    ) -> Result<Option<String>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        let result = run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            if libobs::obs_data_has_default_value(data_ptr, key_ptr) {
+                Some(Sendable(libobs::obs_data_get_default_string(
+                    data_ptr, key_ptr,
+                )))
+            } else {
+                None
+            }
+        })?;
+
+        let Some(result) = result else {
+            return Ok(None);
+        };
+
+        if result.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let result = unsafe { CStr::from_ptr(result.0) };
+        let result = result
+            .to_str()
+            .map_err(|_| ObsError::StringConversionError)?;
+
+        Ok(Some(result.to_string()))
+    }
+
+    fn get_default_int<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<i64>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_default_value(data_ptr, key_ptr)
+                .then(|| libobs::obs_data_get_default_int(data_ptr, key_ptr))
+        })
+    }
+
+    fn get_default_bool<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<bool>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_default_value(data_ptr, key_ptr)
+                .then(|| libobs::obs_data_get_default_bool(data_ptr, key_ptr))
+        })
+    }
+
+    fn get_default_double<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<f64>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            libobs::obs_data_has_default_value(data_ptr, key_ptr)
+                .then(|| libobs::obs_data_get_default_double(data_ptr, key_ptr))
+        })
+    }
+
+    fn get_default_obj<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsData>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        let result = run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_default_obj(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsData::from_raw(result, self.runtime().clone())))
+    }
+
+    fn get_default_array<T: Into<ObsString> + Sync + Send>(
+        &self,
+        key: T,
+    ) -> Result<Option<ObsDataArray>, ObsError> {
+        let key = key.into();
+
+        let key_ptr = key.as_ptr();
+        let data_ptr = self.as_ptr();
+
+        let result = run_with_obs!(self.runtime(), (data_ptr, key_ptr), move || unsafe {
+            Sendable(libobs::obs_data_get_default_array(data_ptr, key_ptr))
+        })?;
+
+        if result.0.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(ObsDataArray::from_raw(result, self.runtime().clone())))
+    }
+
+    /// Returns all keys with their value types, including keys
+    /// that only have a default value.
+    fn items(&self) -> Result<Vec<ObsDataItem>, ObsError> {
+        let data_ptr = self.as_ptr();
+
+        let items = run_with_obs!(self.runtime(), (data_ptr), move || unsafe {
+            let mut items = Vec::new();
+
+            let mut item = libobs::obs_data_first(data_ptr);
+            while !item.is_null() {
+                let name = CStr::from_ptr(libobs::obs_data_item_get_name(item))
+                    .to_string_lossy()
+                    .to_string();
+
+                items.push((
+                    name,
+                    libobs::obs_data_item_gettype(item),
+                    libobs::obs_data_item_numtype(item),
+                ));
+
+                // Releases the current item and moves to the next one, which is null at the end
+                libobs::obs_data_item_next(&mut item);
+            }
+
+            items
+        })?;
+
+        items
+            .into_iter()
+            .map(|(name, data_type, number_type)| {
+                let data_type = enum_from_number!(ObsDataType, data_type).ok_or_else(|| {
+                    ObsError::EnumConversionError(format!("Unknown data type {}", data_type))
+                })?;
+
+                let number_type =
+                    enum_from_number!(ObsDataNumberType, number_type).ok_or_else(|| {
+                        ObsError::EnumConversionError(format!(
+                            "Unknown number type {}",
+                            number_type
+                        ))
+                    })?;
+
+                Ok(ObsDataItem {
+                    name,
+                    data_type,
+                    number_type,
+                })
+            })
+            .collect()
+    }
+
     fn get_json(&self) -> Result<String, ObsError> {
         let data_ptr = self.as_ptr();
         let ptr = run_with_obs!(self.runtime(), (data_ptr), move || unsafe {
@@ -11,11 +11,19 @@ use crate::{
 use super::_ObsDataDropGuard;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ObsDataChange {
     String(ObsString, ObsString),
     Int(ObsString, i64),
     Bool(ObsString, bool),
     Double(ObsString, f64),
+    /// A nested object with the given values
+    Object(ObsString, Vec<ObsDataChange>),
+    /// An array in the format of editable lists, every item is an object
+    /// with the string as `value`
+    StringArray(ObsString, Vec<ObsString>),
+    /// Removes the user value, so the default value is used again
+    Unset(ObsString),
 }
 
 #[derive(Debug)]
@@ -62,6 +70,87 @@ impl ObsDataUpdater {
         self
     }
 
+    pub fn set_double_ref(&mut self, key: impl Into<ObsString>, value: f64) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Double(key, value));
+    }
+
+    pub fn set_double(mut self, key: impl Into<ObsString>, value: f64) -> Self {
+        self.set_double_ref(key, value);
+        self
+    }
+
+    /// Sets the font in the format used by font properties.
+    pub fn set_font_ref(&mut self, key: impl Into<ObsString>, font: ObsFont) {
+        let key = key.into();
+        self.changes
+            .push(ObsDataChange::Object(key, font.into_changes()));
+    }
+
+    pub fn set_font(mut self, key: impl Into<ObsString>, font: ObsFont) -> Self {
+        self.set_font_ref(key, font);
+        self
+    }
+
+    /// Sets the strings in the format used by editable list properties.
+    pub fn set_string_array_ref<T: Into<ObsString>>(
+        &mut self,
+        key: impl Into<ObsString>,
+        values: impl IntoIterator<Item = T>,
+    ) {
+        let key = key.into();
+        let values = values.into_iter().map(|v| v.into()).collect();
+        self.changes.push(ObsDataChange::StringArray(key, values));
+    }
+
+    pub fn set_string_array<T: Into<ObsString>>(
+        mut self,
+        key: impl Into<ObsString>,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.set_string_array_ref(key, values);
+        self
+    }
+
+    /// Sets the frame rate in the format used by frame rate properties.
+    pub fn set_frame_rate_ref(
+        &mut self,
+        key: impl Into<ObsString>,
+        numerator: u32,
+        denominator: u32,
+    ) {
+        let key = key.into();
+        // Same as the inline `obs_data_set_frames_per_second` of libobs
+        self.changes.push(ObsDataChange::Object(
+            key,
+            vec![
+                ObsDataChange::Int("numerator".into(), numerator as i64),
+                ObsDataChange::Int("denominator".into(), denominator as i64),
+            ],
+        ));
+    }
+
+    pub fn set_frame_rate(
+        mut self,
+        key: impl Into<ObsString>,
+        numerator: u32,
+        denominator: u32,
+    ) -> Self {
+        self.set_frame_rate_ref(key, numerator, denominator);
+        self
+    }
+
+    /// Removes the value that was set for `key`, so its default is used.
+    pub fn unset_user_value_ref(&mut self, key: impl Into<ObsString>) {
+        let key = key.into();
+        self.changes.push(ObsDataChange::Unset(key));
+    }
+
+    pub fn unset_user_value(mut self, key: impl Into<ObsString>) -> Self {
+        self.unset_user_value_ref(key);
+        self
+    }
+
     pub fn update(self) -> Result<(), ObsError> {
         let ObsDataUpdater {
             changes,
@@ -71,22 +160,79 @@ impl ObsDataUpdater {
 
         let obs_data = obs_data.clone();
         run_with_obs!(_drop_guard.runtime, (obs_data), move || unsafe {
-            for change in changes {
-                match change {
-                    ObsDataChange::String(key, value) => {
-                        libobs::obs_data_set_string(obs_data, key.as_ptr().0, value.as_ptr().0)
-                    }
-                    ObsDataChange::Int(key, value) => {
-                        libobs::obs_data_set_int(obs_data, key.as_ptr().0, value)
-                    }
-                    ObsDataChange::Bool(key, value) => {
-                        libobs::obs_data_set_bool(obs_data, key.as_ptr().0, value)
-                    }
-                    ObsDataChange::Double(key, value) => {
-                        libobs::obs_data_set_double(obs_data, key.as_ptr().0, value)
-                    }
-                };
-            }
+            apply_changes(obs_data, changes);
         })
     }
 }
+
+/// Must be called on the obs thread.
+unsafe fn apply_changes(obs_data: *mut obs_data, changes: Vec<ObsDataChange>) {
+    for change in changes {
+        match change {
+            ObsDataChange::String(key, value) => {
+                libobs::obs_data_set_string(obs_data, key.as_ptr().0, value.as_ptr().0)
+            }
+            ObsDataChange::Int(key, value) => {
+                libobs::obs_data_set_int(obs_data, key.as_ptr().0, value)
+            }
+            ObsDataChange::Bool(key, value) => {
+                libobs::obs_data_set_bool(obs_data, key.as_ptr().0, value)
+            }
+            ObsDataChange::Double(key, value) => {
+                libobs::obs_data_set_double(obs_data, key.as_ptr().0, value)
+            }
+            ObsDataChange::Object(key, values) => {
+                let obj = libobs::obs_data_create();
+                apply_changes(obj, values);
+                libobs::obs_data_set_obj(obs_data, key.as_ptr().0, obj);
+                libobs::obs_data_release(obj);
+            }
+            ObsDataChange::StringArray(key, values) => {
+                let array = libobs::obs_data_array_create();
+                let value_key = ObsString::new("value");
+                for value in values {
+                    let item = libobs::obs_data_create();
+                    libobs::obs_data_set_string(item, value_key.as_ptr().0, value.as_ptr().0);
+                    libobs::obs_data_array_push_back(array, item);
+                    libobs::obs_data_release(item);
+                }
+
+                libobs::obs_data_set_array(obs_data, key.as_ptr().0, array);
+                libobs::obs_data_array_release(array);
+            }
+            ObsDataChange::Unset(key) => {
+                libobs::obs_data_unset_user_value(obs_data, key.as_ptr().0)
+            }
+        };
+    }
+}
+
+/// A font as stored by font properties.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ObsFont {
+    pub face: String,
+    pub style: String,
+    pub size: i64,
+    /// Combination of `libobs::OBS_FONT_BOLD`, `OBS_FONT_ITALIC`,
+    /// `OBS_FONT_UNDERLINE` and `OBS_FONT_STRIKEOUT`
+    pub flags: u32,
+}
+
+impl ObsFont {
+    pub fn new(face: impl Into<String>, size: i64) -> Self {
+        Self {
+            face: face.into(),
+            size,
+            ..Default::default()
+        }
+    }
+
+    fn into_changes(self) -> Vec<ObsDataChange> {
+        vec![
+            ObsDataChange::String("face".into(), self.face.into()),
+            ObsDataChange::String("style".into(), self.style.into()),
+            ObsDataChange::Int("size".into(), self.size),
+            ObsDataChange::Int("flags".into(), self.flags as i64),
+        ]
+    }
+}
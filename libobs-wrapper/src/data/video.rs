@@ -9,6 +9,7 @@ use crate::{
     enums::{
         ObsColorspace, ObsGraphicsModule, ObsScaleType, ObsVideoFormat, ObsVideoRange, OsEnumType,
     },
+    macros::enum_from_number,
     unsafe_send::Sendable,
     utils::ObsString,
 };
@@ -133,6 +134,19 @@ impl ObsVideoInfo {
         self.ovi.0.output_height
     }
 
+    /// Returns the output format, or `None` if libobs reported an unknown format.
+    pub fn get_output_format(&self) -> Option<ObsVideoFormat> {
+        enum_from_number!(ObsVideoFormat, self.ovi.0.output_format)
+    }
+
+    pub fn get_colorspace(&self) -> Option<ObsColorspace> {
+        enum_from_number!(ObsColorspace, self.ovi.0.colorspace)
+    }
+
+    pub fn get_range(&self) -> Option<ObsVideoRange> {
+        enum_from_number!(ObsVideoRange, self.ovi.0.range)
+    }
+
     pub fn get_sdr_info(&self) -> &ObsSdrVideoInfo {
         &self.sdr_info
     }
@@ -80,6 +80,15 @@ impl ObsAudioEncoder {
             libobs::obs_encoder_set_audio(encoder_ptr, handler)
         })
     }
+
+    /// Returns the mixer whose audio this encoder encodes.
+    pub fn mixer_index(&self) -> Result<usize, ObsError> {
+        let encoder_ptr = self.encoder.clone();
+
+        run_with_obs!(self.runtime, (encoder_ptr), move || unsafe {
+            libobs::obs_encoder_get_mixer_index(encoder_ptr)
+        })
+    }
 }
 
 impl_obs_drop!(ObsAudioEncoder, (encoder), move || unsafe {
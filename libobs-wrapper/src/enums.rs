@@ -293,3 +293,168 @@ pub mod obs_alignment {
     pub const BOTTOM: u32 = libobs::OBS_ALIGN_BOTTOM;
     pub const CENTER: u32 = libobs::OBS_ALIGN_CENTER;
 }
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Describes which kind of object registered a hotkey.
+pub enum ObsHotkeyRegistererType {
+    Frontend = libobs::obs_hotkey_registerer_type_OBS_HOTKEY_REGISTERER_FRONTEND,
+    Source = libobs::obs_hotkey_registerer_type_OBS_HOTKEY_REGISTERER_SOURCE,
+    Output = libobs::obs_hotkey_registerer_type_OBS_HOTKEY_REGISTERER_OUTPUT,
+    Encoder = libobs::obs_hotkey_registerer_type_OBS_HOTKEY_REGISTERER_ENCODER,
+    Service = libobs::obs_hotkey_registerer_type_OBS_HOTKEY_REGISTERER_SERVICE,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Describes whether the audio of a source is sent to the monitoring device.
+pub enum ObsMonitoringType {
+    /// Audio is only sent to the output
+    None = libobs::obs_monitoring_type_OBS_MONITORING_TYPE_NONE,
+    /// Audio is only sent to the monitoring device and muted in the output
+    MonitorOnly = libobs::obs_monitoring_type_OBS_MONITORING_TYPE_MONITOR_ONLY,
+    /// Audio is sent to both the monitoring device and the output
+    MonitorAndOutput = libobs::obs_monitoring_type_OBS_MONITORING_TYPE_MONITOR_AND_OUTPUT,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The curve that maps the deflection of a fader (0.0 to 1.0) to a volume.
+pub enum ObsFaderType {
+    /// Deflection is mapped to the volume multiplier using `x^3`
+    Cubic = libobs::obs_fader_type_OBS_FADER_CUBIC,
+    /// A fader compliant to IEC 60-268-18 (used by OBS Studio)
+    IEC = libobs::obs_fader_type_OBS_FADER_IEC,
+    /// Logarithmic fader
+    Log = libobs::obs_fader_type_OBS_FADER_LOG,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// How a volume meter measures the peak of the audio signal.
+pub enum ObsPeakMeterType {
+    /// The maximum of all samples
+    SamplePeak = libobs::obs_peak_meter_type_SAMPLE_PEAK_METER,
+    /// The maximum of inter-samples using 4x oversampling, more accurate but slower
+    TruePeak = libobs::obs_peak_meter_type_TRUE_PEAK_METER,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Connection details that can be queried from a streaming service.
+pub enum ObsServiceConnectInfo {
+    ServerUrl = libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_SERVER_URL,
+    /// The stream key, for SRT and RIST this is the stream id
+    StreamKey = libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_STREAM_KEY,
+    Username = libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_USERNAME,
+    Password = libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_PASSWORD,
+    /// Passphrase of an encrypted SRT or RIST stream
+    EncryptPassphrase =
+        libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_ENCRYPT_PASSPHRASE,
+    /// Bearer token used for WHIP authentication
+    BearerToken = libobs::obs_service_connect_info_OBS_SERVICE_CONNECT_INFO_BEARER_TOKEN,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// Moves a scene item relative to the other items of its scene.
+pub enum ObsOrderMovement {
+    /// One layer up, towards the front
+    MoveUp = libobs::obs_order_movement_OBS_ORDER_MOVE_UP,
+    /// One layer down, towards the back
+    MoveDown = libobs::obs_order_movement_OBS_ORDER_MOVE_DOWN,
+    /// In front of all other items
+    MoveTop = libobs::obs_order_movement_OBS_ORDER_MOVE_TOP,
+    /// Behind all other items
+    MoveBottom = libobs::obs_order_movement_OBS_ORDER_MOVE_BOTTOM,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// How a scene item is blended with the items behind it.
+pub enum ObsBlendingType {
+    Normal = libobs::obs_blending_type_OBS_BLEND_NORMAL,
+    Additive = libobs::obs_blending_type_OBS_BLEND_ADDITIVE,
+    Subtract = libobs::obs_blending_type_OBS_BLEND_SUBTRACT,
+    Screen = libobs::obs_blending_type_OBS_BLEND_SCREEN,
+    Multiply = libobs::obs_blending_type_OBS_BLEND_MULTIPLY,
+    Lighten = libobs::obs_blending_type_OBS_BLEND_LIGHTEN,
+    Darken = libobs::obs_blending_type_OBS_BLEND_DARKEN,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The color space in which a scene item is blended.
+pub enum ObsBlendingMethod {
+    /// Blends in linear space if the scene is rendered in linear space
+    Default = libobs::obs_blending_method_OBS_BLEND_METHOD_DEFAULT,
+    /// Always blends in sRGB (gamma) space
+    SrgbOff = libobs::obs_blending_method_OBS_BLEND_METHOD_SRGB_OFF,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// The type of a value stored in `ObsData`.
+pub enum ObsDataType {
+    Null = libobs::obs_data_type_OBS_DATA_NULL,
+    String = libobs::obs_data_type_OBS_DATA_STRING,
+    /// Either an integer or a double, see `ObsDataNumberType`
+    Number = libobs::obs_data_type_OBS_DATA_NUMBER,
+    Boolean = libobs::obs_data_type_OBS_DATA_BOOLEAN,
+    Object = libobs::obs_data_type_OBS_DATA_OBJECT,
+    Array = libobs::obs_data_type_OBS_DATA_ARRAY,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+/// How a number in `ObsData` is stored.
+pub enum ObsDataNumberType {
+    /// The value is not a number
+    Invalid = libobs::obs_data_number_type_OBS_DATA_NUM_INVALID,
+    Int = libobs::obs_data_number_type_OBS_DATA_NUM_INT,
+    Double = libobs::obs_data_number_type_OBS_DATA_NUM_DOUBLE,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive)]
+/// The kind of a registered source type.
+pub enum ObsSourceType {
+    Input = libobs::obs_source_type_OBS_SOURCE_TYPE_INPUT,
+    Filter = libobs::obs_source_type_OBS_SOURCE_TYPE_FILTER,
+    Transition = libobs::obs_source_type_OBS_SOURCE_TYPE_TRANSITION,
+    /// Scenes and groups
+    Scene = libobs::obs_source_type_OBS_SOURCE_TYPE_SCENE,
+}
+
+#[cfg_attr(target_os = "windows", repr(i32))]
+#[cfg_attr(not(target_os = "windows"), repr(u32))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, FromPrimitive, ToPrimitive)]
+/// The icon OBS Studio shows next to sources of a type.
+pub enum ObsIconType {
+    Unknown = libobs::obs_icon_type_OBS_ICON_TYPE_UNKNOWN,
+    Image = libobs::obs_icon_type_OBS_ICON_TYPE_IMAGE,
+    Color = libobs::obs_icon_type_OBS_ICON_TYPE_COLOR,
+    Slideshow = libobs::obs_icon_type_OBS_ICON_TYPE_SLIDESHOW,
+    AudioInput = libobs::obs_icon_type_OBS_ICON_TYPE_AUDIO_INPUT,
+    AudioOutput = libobs::obs_icon_type_OBS_ICON_TYPE_AUDIO_OUTPUT,
+    DesktopCapture = libobs::obs_icon_type_OBS_ICON_TYPE_DESKTOP_CAPTURE,
+    WindowCapture = libobs::obs_icon_type_OBS_ICON_TYPE_WINDOW_CAPTURE,
+    GameCapture = libobs::obs_icon_type_OBS_ICON_TYPE_GAME_CAPTURE,
+    Camera = libobs::obs_icon_type_OBS_ICON_TYPE_CAMERA,
+    Text = libobs::obs_icon_type_OBS_ICON_TYPE_TEXT,
+    Media = libobs::obs_icon_type_OBS_ICON_TYPE_MEDIA,
+    Browser = libobs::obs_icon_type_OBS_ICON_TYPE_BROWSER,
+    Custom = libobs::obs_icon_type_OBS_ICON_TYPE_CUSTOM,
+    ProcessAudioOutput = libobs::obs_icon_type_OBS_ICON_TYPE_PROCESS_AUDIO_OUTPUT,
+}
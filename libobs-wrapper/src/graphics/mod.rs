@@ -1,2 +1,3 @@
+pub mod screenshot;
 mod vec2;
 pub use vec2::Vec2;
@@ -0,0 +1,173 @@
+//! Single-frame captures of sources and the program view.
+//!
+//! The frame is rendered into a `gs_texrender` and read back through a
+//! `gs_stagesurf`, the same way the screenshot feature of OBS Studio works.
+//! Reading back stalls the GPU until the frame is rendered, so this is meant
+//! for thumbnails and previews, not for capturing every frame. Use
+//! [`crate::data::raw_video`] for that instead.
+use std::ptr;
+
+use libobs::{obs_source_t, obs_video_info, vec4};
+
+use crate::{context::ObsContext, run_with_obs, sources::ObsSourceRef, utils::ObsError};
+
+/// A captured frame with 8-bit RGBA pixels, without any padding between rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsScreenshot {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl ObsScreenshot {
+    /// Encodes the screenshot as PNG.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, ObsError> {
+        let mut buf = Vec::new();
+
+        let mut encoder = png::Encoder::new(&mut buf, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let map_err = |e: png::EncodingError| ObsError::IoError(e.to_string());
+        let mut writer = encoder.write_header().map_err(map_err)?;
+        writer.write_image_data(&self.data).map_err(map_err)?;
+        writer.finish().map_err(map_err)?;
+
+        Ok(buf)
+    }
+
+    /// Encodes the screenshot as PNG and writes it to the given path.
+    #[cfg(feature = "png")]
+    pub fn save_png<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ObsError> {
+        std::fs::write(path, self.to_png()?).map_err(|e| ObsError::IoError(e.to_string()))
+    }
+}
+
+/// Renders the source, or the main texture if `source` is null, and copies the pixels.
+///
+/// Has to be called on the OBS thread.
+unsafe fn render_screenshot(
+    source: *mut obs_source_t,
+    width: u32,
+    height: u32,
+) -> Result<ObsScreenshot, ObsError> {
+    if width == 0 || height == 0 {
+        return Err(ObsError::InvalidOperation(
+            "Can not take a screenshot of something without a size".to_string(),
+        ));
+    }
+
+    libobs::obs_enter_graphics();
+
+    let texrender = libobs::gs_texrender_create(
+        libobs::gs_color_format_GS_RGBA,
+        libobs::gs_zstencil_format_GS_ZS_NONE,
+    );
+    let stagesurf = libobs::gs_stagesurface_create(width, height, libobs::gs_color_format_GS_RGBA);
+
+    let result = if texrender.is_null() || stagesurf.is_null() {
+        Err(ObsError::NullPointer)
+    } else if !libobs::gs_texrender_begin(texrender, width, height) {
+        Err(ObsError::RenderFailure(
+            "Could not begin rendering to the texture".to_string(),
+        ))
+    } else {
+        let clear_color: vec4 = std::mem::zeroed();
+        libobs::gs_clear(libobs::GS_CLEAR_COLOR, &clear_color, 0.0, 0);
+        libobs::gs_ortho(0.0, width as f32, 0.0, height as f32, -100.0, 100.0);
+
+        libobs::gs_blend_state_push();
+        libobs::gs_blend_function(
+            libobs::gs_blend_type_GS_BLEND_ONE,
+            libobs::gs_blend_type_GS_BLEND_ZERO,
+        );
+
+        if source.is_null() {
+            libobs::obs_render_main_texture();
+        } else {
+            libobs::obs_source_inc_showing(source);
+            libobs::obs_source_video_render(source);
+            libobs::obs_source_dec_showing(source);
+        }
+
+        libobs::gs_blend_state_pop();
+        libobs::gs_texrender_end(texrender);
+
+        libobs::gs_stage_texture(stagesurf, libobs::gs_texrender_get_texture(texrender));
+        copy_stagesurface(stagesurf, width, height)
+    };
+
+    if !stagesurf.is_null() {
+        libobs::gs_stagesurface_destroy(stagesurf);
+    }
+    if !texrender.is_null() {
+        libobs::gs_texrender_destroy(texrender);
+    }
+
+    libobs::obs_leave_graphics();
+    result
+}
+
+unsafe fn copy_stagesurface(
+    stagesurf: *mut libobs::gs_stagesurf_t,
+    width: u32,
+    height: u32,
+) -> Result<ObsScreenshot, ObsError> {
+    let mut mapped: *mut u8 = ptr::null_mut();
+    let mut linesize = 0u32;
+    if !libobs::gs_stagesurface_map(stagesurf, &mut mapped, &mut linesize) || mapped.is_null() {
+        return Err(ObsError::RenderFailure(
+            "Could not map the staging surface".to_string(),
+        ));
+    }
+
+    // Rows of the mapped surface may be padded
+    let row_size = width as usize * 4;
+    let mut data = Vec::with_capacity(row_size * height as usize);
+    for row in 0..height as usize {
+        let row_ptr = mapped.add(row * linesize as usize);
+        data.extend_from_slice(std::slice::from_raw_parts(row_ptr, row_size));
+    }
+
+    libobs::gs_stagesurface_unmap(stagesurf);
+
+    Ok(ObsScreenshot {
+        width,
+        height,
+        data,
+    })
+}
+
+impl ObsSourceRef {
+    /// Renders the current frame of this source at its native size.
+    ///
+    /// Sources that are not shown anywhere may not have a frame yet,
+    /// which results in a transparent image.
+    pub fn screenshot(&self) -> Result<ObsScreenshot, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let width = libobs::obs_source_get_width(source_ptr);
+            let height = libobs::obs_source_get_height(source_ptr);
+
+            render_screenshot(source_ptr, width, height)
+        })?
+    }
+}
+
+impl ObsContext {
+    /// Renders the program view at the base resolution of the context.
+    pub fn screenshot_program(&self) -> Result<ObsScreenshot, ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut ovi = std::mem::MaybeUninit::<obs_video_info>::uninit();
+            if !libobs::obs_get_video_info(ovi.as_mut_ptr()) {
+                return Err(ObsError::RenderFailure(
+                    "Video is not initialized".to_string(),
+                ));
+            }
+
+            let ovi = ovi.assume_init();
+            render_screenshot(ptr::null_mut(), ovi.base_width, ovi.base_height)
+        })?
+    }
+}
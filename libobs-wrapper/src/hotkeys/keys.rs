@@ -0,0 +1,127 @@
+use std::ffi::CStr;
+
+use libobs::{obs_key_combination_t, obs_key_t};
+
+use crate::utils::ObsString;
+
+/// A single key as understood by libobs (`obs_key_t`).
+///
+/// Keys are identified by their libobs name, for example `OBS_KEY_A`,
+/// `OBS_KEY_F1` or `OBS_KEY_SPACE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObsKey(pub obs_key_t);
+
+impl ObsKey {
+    pub const NONE: ObsKey = ObsKey(libobs::obs_key_OBS_KEY_NONE);
+
+    /// Looks up a key by its libobs name (e.g. `OBS_KEY_A`).
+    /// Returns `ObsKey::NONE` if the name is unknown.
+    pub fn from_name<T: Into<ObsString>>(name: T) -> Self {
+        let name = name.into();
+        let key = unsafe { libobs::obs_key_from_name(name.as_ptr().0) };
+
+        ObsKey(key)
+    }
+
+    /// Returns the libobs name of this key (e.g. `OBS_KEY_A`).
+    pub fn name(&self) -> String {
+        let name = unsafe { libobs::obs_key_to_name(self.0) };
+        if name.is_null() {
+            return String::new();
+        }
+
+        unsafe { CStr::from_ptr(name) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    pub fn is_none(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+// The flag type is signed on windows, so the casts are needed there
+#[allow(clippy::unnecessary_cast)]
+const SHIFT_KEY: u32 = libobs::obs_interaction_flags_INTERACT_SHIFT_KEY as u32;
+#[allow(clippy::unnecessary_cast)]
+const CONTROL_KEY: u32 = libobs::obs_interaction_flags_INTERACT_CONTROL_KEY as u32;
+#[allow(clippy::unnecessary_cast)]
+const ALT_KEY: u32 = libobs::obs_interaction_flags_INTERACT_ALT_KEY as u32;
+#[allow(clippy::unnecessary_cast)]
+const COMMAND_KEY: u32 = libobs::obs_interaction_flags_INTERACT_COMMAND_KEY as u32;
+
+/// Modifier keys that have to be held down for a key combination to trigger.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ObsKeyModifiers {
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+    /// The command key on macOS
+    pub command: bool,
+}
+
+impl ObsKeyModifiers {
+    pub fn from_bits(bits: u32) -> Self {
+        Self {
+            shift: bits & SHIFT_KEY != 0,
+            control: bits & CONTROL_KEY != 0,
+            alt: bits & ALT_KEY != 0,
+            command: bits & COMMAND_KEY != 0,
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        let mut bits = 0;
+        if self.shift {
+            bits |= SHIFT_KEY;
+        }
+        if self.control {
+            bits |= CONTROL_KEY;
+        }
+        if self.alt {
+            bits |= ALT_KEY;
+        }
+        if self.command {
+            bits |= COMMAND_KEY;
+        }
+
+        bits
+    }
+}
+
+/// A key together with the modifiers that must be held down,
+/// mirrors `obs_key_combination_t`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObsKeyCombination {
+    pub modifiers: ObsKeyModifiers,
+    pub key: ObsKey,
+}
+
+impl ObsKeyCombination {
+    pub fn new(key: ObsKey, modifiers: ObsKeyModifiers) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Creates a combination without any modifiers.
+    pub fn key(key: ObsKey) -> Self {
+        Self::new(key, ObsKeyModifiers::default())
+    }
+}
+
+impl From<ObsKeyCombination> for obs_key_combination_t {
+    fn from(value: ObsKeyCombination) -> Self {
+        obs_key_combination_t {
+            modifiers: value.modifiers.bits(),
+            key: value.key.0,
+        }
+    }
+}
+
+impl From<obs_key_combination_t> for ObsKeyCombination {
+    fn from(value: obs_key_combination_t) -> Self {
+        Self {
+            modifiers: ObsKeyModifiers::from_bits(value.modifiers),
+            key: ObsKey(value.key),
+        }
+    }
+}
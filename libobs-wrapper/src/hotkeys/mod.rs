@@ -0,0 +1,414 @@
+//! Hotkey support
+//!
+//! libobs keeps track of every hotkey that was registered by sources, outputs,
+//! encoders, services and the frontend. The [`ObsHotkeyManager`] can be used
+//! to enumerate those hotkeys, register new frontend hotkeys which call a Rust
+//! closure, bind key combinations to them and inject key presses.
+//!
+//! The manager can be obtained by calling `ObsContext::hotkey_manager`.
+mod keys;
+pub use keys::*;
+
+use std::{
+    collections::HashMap,
+    ffi::{c_void, CStr},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use lazy_static::lazy_static;
+use libobs::{obs_hotkey_binding_t, obs_hotkey_t, obs_key_combination_t};
+
+use crate::{
+    data::immutable::ImmutableObsData,
+    enums::ObsHotkeyRegistererType,
+    impl_obs_drop,
+    macros::enum_from_number,
+    run_with_obs,
+    runtime::ObsRuntime,
+    sources::ObsSourceRef,
+    unsafe_send::Sendable,
+    utils::{self, ObsError, ObsString},
+};
+
+pub type ObsHotkeyId = libobs::obs_hotkey_id;
+
+/// Id libobs returns if a hotkey could not be registered (`OBS_INVALID_HOTKEY_ID`)
+pub const INVALID_HOTKEY_ID: ObsHotkeyId = ObsHotkeyId::MAX;
+
+type HotkeyCallback = Arc<dyn Fn(bool) + Send + Sync>;
+
+static CALLBACK_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
+lazy_static! {
+    /// Closures of the registered frontend hotkeys, keyed by the id that is
+    /// passed as the `data` pointer to libobs.
+    static ref HOTKEY_CALLBACKS: RwLock<HashMap<usize, HotkeyCallback>> =
+        RwLock::new(HashMap::new());
+}
+
+unsafe extern "C" fn hotkey_callback(
+    data: *mut c_void,
+    _id: ObsHotkeyId,
+    _hotkey: *mut obs_hotkey_t,
+    pressed: bool,
+) {
+    let key = data as usize;
+    let callback = HOTKEY_CALLBACKS
+        .read()
+        .ok()
+        .and_then(|callbacks| callbacks.get(&key).cloned());
+
+    if let Some(callback) = callback {
+        // Unwinding into libobs would abort the process
+        utils::catch_panic(format_args!("Hotkey callback {}", key), || {
+            callback(pressed)
+        });
+    }
+}
+
+unsafe extern "C" fn enum_hotkeys_callback(
+    data: *mut c_void,
+    id: ObsHotkeyId,
+    key: *mut obs_hotkey_t,
+) -> bool {
+    let hotkeys = &mut *(data as *mut Vec<ObsHotkeyInfo>);
+
+    let registerer_type = libobs::obs_hotkey_get_registerer_type(key);
+    let registerer_type = match enum_from_number!(ObsHotkeyRegistererType, registerer_type) {
+        Some(x) => x,
+        None => {
+            log::warn!("Unknown hotkey registerer type {}", registerer_type);
+            return true;
+        }
+    };
+
+    hotkeys.push(ObsHotkeyInfo {
+        id,
+        name: ptr_to_string(libobs::obs_hotkey_get_name(key)),
+        description: ptr_to_string(libobs::obs_hotkey_get_description(key)),
+        registerer_type,
+        pair_partner_id: libobs::obs_hotkey_get_pair_partner_id(key),
+    });
+
+    true
+}
+
+unsafe extern "C" fn enum_bindings_callback(
+    data: *mut c_void,
+    _idx: usize,
+    binding: *mut obs_hotkey_binding_t,
+) -> bool {
+    let bindings = &mut *(data as *mut Vec<ObsHotkeyBinding>);
+
+    bindings.push(ObsHotkeyBinding {
+        hotkey_id: libobs::obs_hotkey_binding_get_hotkey_id(binding),
+        combination: libobs::obs_hotkey_binding_get_key_combination(binding).into(),
+    });
+
+    true
+}
+
+unsafe fn ptr_to_string(ptr: *const std::os::raw::c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+/// Information about a hotkey that is registered in libobs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObsHotkeyInfo {
+    pub id: ObsHotkeyId,
+    pub name: String,
+    pub description: String,
+    pub registerer_type: ObsHotkeyRegistererType,
+    /// Id of the other hotkey if this hotkey is part of a pair
+    /// (e.g. "Mute" / "Unmute"). `INVALID_HOTKEY_ID` if there is no partner.
+    pub pair_partner_id: ObsHotkeyId,
+}
+
+/// A key combination that is bound to a hotkey.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObsHotkeyBinding {
+    pub hotkey_id: ObsHotkeyId,
+    pub combination: ObsKeyCombination,
+}
+
+/// A frontend hotkey that was registered using
+/// [`ObsHotkeyManager::register_frontend`]. The hotkey is unregistered
+/// when every clone of this struct (including the one stored in the manager)
+/// has been dropped.
+#[derive(Debug, Clone)]
+pub struct ObsHotkeyRef {
+    id: ObsHotkeyId,
+    name: ObsString,
+    description: ObsString,
+    _guard: Arc<_ObsHotkeyGuard>,
+}
+
+impl ObsHotkeyRef {
+    pub fn id(&self) -> ObsHotkeyId {
+        self.id
+    }
+
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.to_string()
+    }
+}
+
+#[derive(Debug)]
+struct _ObsHotkeyGuard {
+    hotkey_id: Sendable<ObsHotkeyId>,
+    callback_key: Sendable<usize>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsHotkeyGuard, (hotkey_id, callback_key), move || unsafe {
+    libobs::obs_hotkey_unregister(hotkey_id);
+    if let Ok(mut callbacks) = HOTKEY_CALLBACKS.write() {
+        callbacks.remove(&callback_key);
+    }
+});
+
+/// Manages the hotkeys of the OBS context. Get it by calling
+/// `ObsContext::hotkey_manager`.
+#[derive(Debug, Clone)]
+pub struct ObsHotkeyManager {
+    /// Frontend hotkeys registered through this manager, kept alive
+    /// until they are unregistered or the context is dropped.
+    frontend_hotkeys: Arc<RwLock<HashMap<ObsHotkeyId, ObsHotkeyRef>>>,
+    runtime: ObsRuntime,
+}
+
+impl ObsHotkeyManager {
+    pub(crate) fn new(runtime: ObsRuntime) -> Self {
+        Self {
+            frontend_hotkeys: Default::default(),
+            runtime,
+        }
+    }
+
+    /// Returns every hotkey that is currently registered in libobs.
+    pub fn hotkeys(&self) -> Result<Vec<ObsHotkeyInfo>, ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut hotkeys = Vec::<ObsHotkeyInfo>::new();
+            libobs::obs_enum_hotkeys(
+                Some(enum_hotkeys_callback),
+                &mut hotkeys as *mut _ as *mut c_void,
+            );
+
+            hotkeys
+        })
+    }
+
+    /// Looks up a registered hotkey by its name.
+    pub fn get_hotkey_by_name(&self, name: &str) -> Result<Option<ObsHotkeyInfo>, ObsError> {
+        Ok(self.hotkeys()?.into_iter().find(|h| h.name == name))
+    }
+
+    /// Returns every key combination that is bound to a hotkey.
+    pub fn bindings(&self) -> Result<Vec<ObsHotkeyBinding>, ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut bindings = Vec::<ObsHotkeyBinding>::new();
+            libobs::obs_enum_hotkey_bindings(
+                Some(enum_bindings_callback),
+                &mut bindings as *mut _ as *mut c_void,
+            );
+
+            bindings
+        })
+    }
+
+    /// Registers a new frontend hotkey. The given callback is called with
+    /// `true` when the hotkey is pressed and with `false` when it is released.
+    ///
+    /// Note that the callback is executed on the hotkey thread of libobs, so
+    /// it should not block.
+    pub fn register_frontend<
+        T: Into<ObsString> + Send + Sync,
+        K: Into<ObsString> + Send + Sync,
+        F: Fn(bool) + Send + Sync + 'static,
+    >(
+        &self,
+        name: T,
+        description: K,
+        callback: F,
+    ) -> Result<ObsHotkeyRef, ObsError> {
+        let name = name.into();
+        let description = description.into();
+
+        let callback_key = CALLBACK_COUNTER.fetch_add(1, Ordering::Relaxed);
+        HOTKEY_CALLBACKS
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on hotkey callbacks".to_string())
+            })?
+            .insert(callback_key, Arc::new(callback));
+
+        let name_ptr = name.as_ptr();
+        let description_ptr = description.as_ptr();
+        let hotkey_id = run_with_obs!(self.runtime, (name_ptr, description_ptr), move || unsafe {
+            libobs::obs_hotkey_register_frontend(
+                name_ptr,
+                description_ptr,
+                Some(hotkey_callback),
+                callback_key as *mut c_void,
+            )
+        });
+
+        let hotkey_id = match hotkey_id {
+            Ok(id) if id != INVALID_HOTKEY_ID => id,
+            res => {
+                if let Ok(mut callbacks) = HOTKEY_CALLBACKS.write() {
+                    callbacks.remove(&callback_key);
+                }
+
+                return Err(res
+                    .err()
+                    .unwrap_or_else(|| ObsError::HotkeyRegistrationFailure(name.to_string())));
+            }
+        };
+
+        let hotkey = ObsHotkeyRef {
+            id: hotkey_id,
+            name,
+            description,
+            _guard: Arc::new(_ObsHotkeyGuard {
+                hotkey_id: Sendable(hotkey_id),
+                callback_key: Sendable(callback_key),
+                runtime: self.runtime.clone(),
+            }),
+        };
+
+        self.frontend_hotkeys
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on frontend hotkeys".to_string())
+            })?
+            .insert(hotkey_id, hotkey.clone());
+
+        Ok(hotkey)
+    }
+
+    /// Removes the frontend hotkey with the given id from this manager.
+    /// The hotkey is unregistered from libobs once every `ObsHotkeyRef`
+    /// pointing to it has been dropped.
+    pub fn unregister_frontend(&self, id: ObsHotkeyId) -> Result<(), ObsError> {
+        self.frontend_hotkeys
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on frontend hotkeys".to_string())
+            })?
+            .remove(&id);
+
+        Ok(())
+    }
+
+    /// Returns the frontend hotkeys that were registered using this manager.
+    pub fn frontend_hotkeys(&self) -> Result<Vec<ObsHotkeyRef>, ObsError> {
+        let hotkeys = self
+            .frontend_hotkeys
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on frontend hotkeys".to_string())
+            })?
+            .values()
+            .cloned()
+            .collect();
+
+        Ok(hotkeys)
+    }
+
+    /// Replaces all key combinations bound to the given hotkey.
+    /// Passing an empty slice removes every binding of the hotkey.
+    pub fn set_bindings(
+        &self,
+        id: ObsHotkeyId,
+        combinations: &[ObsKeyCombination],
+    ) -> Result<(), ObsError> {
+        let combinations = combinations.to_vec();
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut combinations = combinations
+                .into_iter()
+                .map(obs_key_combination_t::from)
+                .collect::<Vec<_>>();
+
+            libobs::obs_hotkey_load_bindings(id, combinations.as_mut_ptr(), combinations.len());
+        })
+    }
+
+    /// Returns all key combinations that are bound to the given hotkey.
+    pub fn get_bindings(&self, id: ObsHotkeyId) -> Result<Vec<ObsKeyCombination>, ObsError> {
+        let bindings = self
+            .bindings()?
+            .into_iter()
+            .filter(|b| b.hotkey_id == id)
+            .map(|b| b.combination)
+            .collect();
+
+        Ok(bindings)
+    }
+
+    /// Simulates a key press or release of the given combination.
+    /// Every hotkey bound to the combination is triggered.
+    pub fn inject_event(
+        &self,
+        combination: ObsKeyCombination,
+        pressed: bool,
+    ) -> Result<(), ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            libobs::obs_hotkey_inject_event(combination.into(), pressed);
+        })
+    }
+
+    /// Injects a press followed by a release of the given combination.
+    pub fn inject_press(&self, combination: ObsKeyCombination) -> Result<(), ObsError> {
+        self.inject_event(combination, true)?;
+        self.inject_event(combination, false)
+    }
+
+    /// Sets whether hotkeys should be triggered while the application is not focused.
+    pub fn enable_background_press(&self, enable: bool) -> Result<(), ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            libobs::obs_hotkey_enable_background_press(enable);
+        })
+    }
+
+    /// Saves the key bindings of every hotkey registered by the given source.
+    /// The returned data can be passed to `load_source_hotkeys` or used as
+    /// `hotkey_data` when creating the source again.
+    pub fn save_source_hotkeys(&self, source: &ObsSourceRef) -> Result<ImmutableObsData, ObsError> {
+        let source_ptr = source.source.clone();
+        let data = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            Sendable(libobs::obs_hotkeys_save_source(source_ptr))
+        })?;
+
+        if data.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        Ok(ImmutableObsData::from_raw(data, self.runtime.clone()))
+    }
+
+    /// Loads key bindings previously saved with `save_source_hotkeys`
+    /// into the hotkeys of the given source.
+    pub fn load_source_hotkeys(
+        &self,
+        source: &ObsSourceRef,
+        data: &ImmutableObsData,
+    ) -> Result<(), ObsError> {
+        let source_ptr = source.source.clone();
+        let data_ptr = data.as_ptr();
+        run_with_obs!(self.runtime, (source_ptr, data_ptr), move || unsafe {
+            libobs::obs_hotkeys_load_source(source_ptr, data_ptr);
+        })
+    }
+}
@@ -1,16 +1,21 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
+pub mod canvas;
 pub mod context;
 pub mod crash_handler;
 pub mod data;
 pub mod display;
 pub mod encoders;
 pub mod enums;
+pub mod hotkeys;
 pub mod logger;
+pub mod registry;
 pub mod runtime;
 pub mod scenes;
+pub mod services;
 pub mod signals;
 pub mod sources;
+pub mod transitions;
 pub mod unsafe_send;
 pub mod utils;
 
@@ -50,6 +50,32 @@ macro_rules! run_with_obs {
     };
 }
 
+/// Same as `run_with_obs!`, but awaits the result instead of blocking.
+/// Can only be used in async functions.
+#[cfg(feature = "async")]
+#[macro_export]
+macro_rules! run_with_obs_async {
+    ($runtime:expr, $operation:expr) => {
+        $crate::run_with_obs_async!($runtime, (), $operation)
+    };
+    ($runtime:expr, ($($var:ident),* $(,)*), $operation:expr) => {
+        {
+            $(let $var = $var.clone();)*
+            $runtime
+                .run_with_obs_async(move || {
+                    $(let $var = $var;)*
+                    let e = {
+                        $(let $var = $var.0;)*
+                        $operation
+                    };
+                    e()
+                })
+                .await
+                .map_err(|e| $crate::utils::ObsError::InvocationError(e.to_string()))
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_obs_drop {
     ($struct_name: ident, $operation:expr) => {
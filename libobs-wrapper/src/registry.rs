@@ -0,0 +1,305 @@
+//! Discovery of the object types registered by the loaded plugins
+//!
+//! Which sources, filters, transitions, outputs and services are available
+//! depends on the plugins that could be loaded on the current machine. The
+//! functions in this module list them together with their display names and
+//! capabilities, so settings can be offered accordingly. The properties of a
+//! type can be queried without creating an object of it.
+use std::{collections::HashMap, ffi::CStr, os::raw::c_char};
+
+use crate::{
+    context::ObsContext,
+    data::output::ObsOutputRef,
+    data::properties::{ObsProperty, ObsPropertyObject},
+    enums::{ObsIconType, ObsSourceType},
+    macros::enum_from_number,
+    run_with_obs,
+    runtime::ObsRuntime,
+    services::ObsServiceRef,
+    sources::ObsSourceRef,
+    utils::{ObsError, ObsString},
+};
+
+/// Capabilities of a source type, see `OBS_SOURCE_*` in libobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ObsSourceOutputFlags(pub u32);
+
+impl ObsSourceOutputFlags {
+    /// The source renders video
+    pub fn has_video(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_VIDEO != 0
+    }
+
+    /// The source outputs audio
+    pub fn has_audio(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_AUDIO != 0
+    }
+
+    /// Video or audio is pushed asynchronously instead of being rendered,
+    /// e.g. by media or camera sources
+    pub fn is_async(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_ASYNC != 0
+    }
+
+    /// The source draws itself without the default effect
+    pub fn is_custom_draw(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_CUSTOM_DRAW != 0
+    }
+
+    /// The source accepts mouse and keyboard input
+    pub fn is_interactive(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_INTERACTION != 0
+    }
+
+    /// The source is composed of other sources, e.g. scenes
+    pub fn is_composite(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_COMPOSITE != 0
+    }
+
+    /// The source type is deprecated and should not be offered for new sources
+    pub fn is_deprecated(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_DEPRECATED != 0
+    }
+
+    /// The source supports playback controls like play, pause and seek
+    pub fn is_controllable_media(&self) -> bool {
+        self.0 & libobs::OBS_SOURCE_CONTROLLABLE_MEDIA != 0
+    }
+}
+
+/// Capabilities of an output type, see `OBS_OUTPUT_*` in libobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ObsOutputFlags(pub u32);
+
+impl ObsOutputFlags {
+    pub fn has_video(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_VIDEO != 0
+    }
+
+    pub fn has_audio(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_AUDIO != 0
+    }
+
+    /// The output takes encoded data instead of raw frames
+    pub fn is_encoded(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_ENCODED != 0
+    }
+
+    /// The output requires a service, i.e. it is used for streaming
+    pub fn uses_service(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_SERVICE != 0
+    }
+
+    /// The output can record more than one audio track
+    pub fn is_multi_track_audio(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_MULTI_TRACK_AUDIO != 0
+    }
+
+    /// The output can record more than one video track
+    pub fn is_multi_track_video(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_MULTI_TRACK_VIDEO != 0
+    }
+
+    pub fn can_pause(&self) -> bool {
+        self.0 & libobs::OBS_OUTPUT_CAN_PAUSE != 0
+    }
+}
+
+/// A registered source type, which includes filters, transitions and scenes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsSourceTypeInfo {
+    pub id: String,
+    /// Translated name, as shown in OBS Studio
+    pub display_name: String,
+    pub source_type: ObsSourceType,
+    pub output_flags: ObsSourceOutputFlags,
+    pub icon_type: ObsIconType,
+}
+
+impl ObsSourceTypeInfo {
+    /// Returns the default properties of this source type.
+    pub fn get_properties(
+        &self,
+        runtime: &ObsRuntime,
+    ) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        ObsSourceRef::get_properties_by_id(self.id.as_str(), runtime)
+    }
+}
+
+/// A registered output type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsOutputTypeInfo {
+    pub id: String,
+    pub display_name: String,
+    pub flags: ObsOutputFlags,
+}
+
+impl ObsOutputTypeInfo {
+    /// Returns the default properties of this output type.
+    pub fn get_properties(
+        &self,
+        runtime: &ObsRuntime,
+    ) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        ObsOutputRef::get_properties_by_id(self.id.as_str(), runtime)
+    }
+}
+
+/// A registered streaming service type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObsServiceTypeInfo {
+    pub id: String,
+    pub display_name: String,
+}
+
+impl ObsServiceTypeInfo {
+    /// Returns the default properties of this service type.
+    pub fn get_properties(
+        &self,
+        runtime: &ObsRuntime,
+    ) -> Result<HashMap<String, ObsProperty>, ObsError> {
+        ObsServiceRef::get_properties_by_id(self.id.as_str(), runtime)
+    }
+}
+
+type EnumTypesFn = unsafe extern "C" fn(usize, *mut *const c_char) -> bool;
+
+/// Collects all ids returned by one of the `obs_enum_*_types` functions.
+/// Must be called on the obs thread.
+unsafe fn enum_type_ids(enum_fn: EnumTypesFn) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut idx = 0;
+    let mut ptr: *const c_char = std::ptr::null();
+    while enum_fn(idx, &mut ptr) {
+        idx += 1;
+        if ptr.is_null() {
+            continue;
+        }
+
+        ids.push(CStr::from_ptr(ptr).to_string_lossy().to_string());
+    }
+
+    ids
+}
+
+unsafe fn display_name(name: *const c_char, id: &str) -> String {
+    if name.is_null() {
+        return id.to_string();
+    }
+
+    CStr::from_ptr(name).to_string_lossy().to_string()
+}
+
+unsafe fn source_type_info(id: String, source_type: ObsSourceType) -> ObsSourceTypeInfo {
+    let id_str = ObsString::new(id.as_str());
+    let id_ptr = id_str.as_ptr().0;
+
+    let icon_type = libobs::obs_source_get_icon_type(id_ptr);
+    ObsSourceTypeInfo {
+        display_name: display_name(libobs::obs_source_get_display_name(id_ptr), &id),
+        source_type,
+        output_flags: ObsSourceOutputFlags(libobs::obs_get_source_output_flags(id_ptr)),
+        icon_type: enum_from_number!(ObsIconType, icon_type).unwrap_or(ObsIconType::Unknown),
+        id,
+    }
+}
+
+fn source_types(
+    runtime: &ObsRuntime,
+    kind: Option<ObsSourceType>,
+) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+    run_with_obs!(runtime, move || unsafe {
+        let inputs = enum_type_ids(libobs::obs_enum_input_types);
+        let filters = enum_type_ids(libobs::obs_enum_filter_types);
+        let transitions = enum_type_ids(libobs::obs_enum_transition_types);
+
+        // Scenes and groups are only listed by the function enumerating all source types
+        let mut types = enum_type_ids(libobs::obs_enum_source_types)
+            .into_iter()
+            .map(|id| {
+                let source_type = if inputs.contains(&id) {
+                    ObsSourceType::Input
+                } else if filters.contains(&id) {
+                    ObsSourceType::Filter
+                } else if transitions.contains(&id) {
+                    ObsSourceType::Transition
+                } else {
+                    ObsSourceType::Scene
+                };
+
+                source_type_info(id, source_type)
+            })
+            .filter(|info| kind.is_none_or(|kind| info.source_type == kind))
+            .filter(|info| info.output_flags.0 & libobs::OBS_SOURCE_CAP_DISABLED == 0)
+            .collect::<Vec<_>>();
+
+        types.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+        types
+    })
+}
+
+impl ObsContext {
+    /// Returns all source types that can be created, including filters,
+    /// transitions and scenes. Disabled types are left out.
+    pub fn available_source_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        source_types(&self.runtime, None)
+    }
+
+    /// Returns the source types that can be added to scenes.
+    pub fn available_input_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        source_types(&self.runtime, Some(ObsSourceType::Input))
+    }
+
+    pub fn available_filter_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        source_types(&self.runtime, Some(ObsSourceType::Filter))
+    }
+
+    pub fn available_transition_types(&self) -> Result<Vec<ObsSourceTypeInfo>, ObsError> {
+        source_types(&self.runtime, Some(ObsSourceType::Transition))
+    }
+
+    pub fn available_output_types(&self) -> Result<Vec<ObsOutputTypeInfo>, ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut types = enum_type_ids(libobs::obs_enum_output_types)
+                .into_iter()
+                .map(|id| {
+                    let id_str = ObsString::new(id.as_str());
+                    let id_ptr = id_str.as_ptr().0;
+
+                    ObsOutputTypeInfo {
+                        display_name: display_name(
+                            libobs::obs_output_get_display_name(id_ptr),
+                            &id,
+                        ),
+                        flags: ObsOutputFlags(libobs::obs_get_output_flags(id_ptr)),
+                        id,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            types.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+            types
+        })
+    }
+
+    pub fn available_service_types(&self) -> Result<Vec<ObsServiceTypeInfo>, ObsError> {
+        run_with_obs!(self.runtime, move || unsafe {
+            let mut types = enum_type_ids(libobs::obs_enum_service_types)
+                .into_iter()
+                .map(|id| {
+                    let id_str = ObsString::new(id.as_str());
+
+                    ObsServiceTypeInfo {
+                        display_name: display_name(
+                            libobs::obs_service_get_display_name(id_str.as_ptr().0),
+                            &id,
+                        ),
+                        id,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            types.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+            types
+        })
+    }
+}
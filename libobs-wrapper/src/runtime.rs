@@ -14,6 +14,8 @@
 //!
 //! The runtime locking APIs:
 //! - By default all operations are synchronous
+//! - With the `async` feature, `run_with_obs_async` and the `*_async` methods of
+//!   contexts, outputs and scenes return futures instead of blocking the caller
 //!
 //! # Example
 //!
@@ -35,7 +37,7 @@
 //! ```
 
 use std::ffi::CStr;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use std::{ptr, thread};
 
 use crate::context::ObsContext;
@@ -97,6 +99,39 @@ pub struct ObsRuntime {
     _platform_specific: Option<Arc<PlatformSpecificGuard>>,
 }
 
+/// Reference to the runtime that does not keep OBS running, used by
+/// background threads that should stop once OBS is shut down.
+#[derive(Debug, Clone)]
+pub(crate) struct WeakObsRuntime {
+    #[cfg(feature = "enable_runtime")]
+    command_sender: Arc<Sender<ObsCommand>>,
+    #[cfg(feature = "enable_runtime")]
+    queued_commands: Arc<AtomicUsize>,
+    _guard: Weak<_ObsRuntimeGuard>,
+
+    #[cfg(not(feature = "enable_runtime"))]
+    _platform_specific: Option<Weak<PlatformSpecificGuard>>,
+}
+
+impl WeakObsRuntime {
+    /// Returns the runtime if OBS is still running.
+    pub(crate) fn upgrade(&self) -> Option<ObsRuntime> {
+        Some(ObsRuntime {
+            #[cfg(feature = "enable_runtime")]
+            command_sender: self.command_sender.clone(),
+            #[cfg(feature = "enable_runtime")]
+            queued_commands: self.queued_commands.clone(),
+            _guard: self._guard.upgrade()?,
+
+            #[cfg(not(feature = "enable_runtime"))]
+            _platform_specific: match &self._platform_specific {
+                Some(guard) => Some(guard.upgrade()?),
+                None => None,
+            },
+        })
+    }
+}
+
 impl ObsRuntime {
     /// Initializes the OBS runtime.
     ///
@@ -149,6 +184,19 @@ impl ObsRuntime {
             .map_err(|e| ObsError::Unexpected(format!("Failed to initialize OBS runtime: {:?}", e)))
     }
 
+    pub(crate) fn downgrade(&self) -> WeakObsRuntime {
+        WeakObsRuntime {
+            #[cfg(feature = "enable_runtime")]
+            command_sender: self.command_sender.clone(),
+            #[cfg(feature = "enable_runtime")]
+            queued_commands: self.queued_commands.clone(),
+            _guard: Arc::downgrade(&self._guard),
+
+            #[cfg(not(feature = "enable_runtime"))]
+            _platform_specific: self._platform_specific.as_ref().map(Arc::downgrade),
+        }
+    }
+
     /// Internal initialization method
     ///
     /// Creates the OBS thread and performs core initialization.
@@ -302,39 +350,56 @@ impl ObsRuntime {
     {
         #[cfg(feature = "enable_runtime")]
         {
-            let (tx, rx) = oneshot::channel();
-
-            // Create a wrapper closure that boxes the result as Any
-            let wrapper = move || -> Box<dyn std::any::Any + Send> {
-                let result = operation();
-                Box::new(result)
-            };
-
-            let val = self.queued_commands.fetch_add(1, Ordering::SeqCst);
-            if val > 50 {
-                log::warn!("More than 50 queued commands. Try to batch them together.");
-            }
-
-            self.command_sender
-                .send(ObsCommand::Execute(Box::new(wrapper), tx))
-                .map_err(|_| {
-                    ObsError::RuntimeChannelError(
-                        "Failed to send command to OBS thread".to_string(),
-                    )
-                })?;
-
+            let rx = self.send_command(operation)?;
             let result = rx.recv().map_err(|_| {
                 ObsError::RuntimeChannelError("OBS thread dropped the response channel".to_string())
             })?;
 
-            // Downcast the Any type back to T
-            let res = result.downcast::<T>().map(|boxed| *boxed).map_err(|_| {
-                ObsError::RuntimeChannelError(
-                    "Failed to downcast result to the expected type".to_string(),
-                )
+            Self::downcast_result(result)
+        }
+
+        #[cfg(not(feature = "enable_runtime"))]
+        {
+            let result = operation();
+            Ok(result)
+        }
+    }
+
+    /// Executes an operation on the OBS thread and returns a future that
+    /// resolves to its result.
+    ///
+    /// Unlike `run_with_obs_result`, waiting for the OBS thread does not
+    /// block the calling thread, so this can be used from async runtimes
+    /// like tokio without `spawn_blocking`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use libobs_wrapper::runtime::ObsRuntime;
+    ///
+    /// async fn example(runtime: &ObsRuntime) {
+    ///     let version = runtime.run_with_obs_async(|| {
+    ///         // This code runs on the OBS thread
+    ///         unsafe { libobs::obs_get_version() }
+    ///     }).await.unwrap();
+    ///
+    ///     println!("OBS Version: {}", version);
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn run_with_obs_async<F, T>(&self, operation: F) -> Result<T, ObsError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        #[cfg(feature = "enable_runtime")]
+        {
+            let rx = self.send_command(operation)?;
+            let result = rx.await.map_err(|_| {
+                ObsError::RuntimeChannelError("OBS thread dropped the response channel".to_string())
             })?;
 
-            Ok(res)
+            Self::downcast_result(result)
         }
 
         #[cfg(not(feature = "enable_runtime"))]
@@ -344,6 +409,48 @@ impl ObsRuntime {
         }
     }
 
+    /// Queues the operation on the OBS thread, the result is sent to the returned receiver.
+    #[cfg(feature = "enable_runtime")]
+    fn send_command<F, T>(
+        &self,
+        operation: F,
+    ) -> Result<oneshot::Receiver<Box<dyn std::any::Any + Send>>, ObsError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        // Create a wrapper closure that boxes the result as Any
+        let wrapper = move || -> Box<dyn std::any::Any + Send> {
+            let result = operation();
+            Box::new(result)
+        };
+
+        let val = self.queued_commands.fetch_add(1, Ordering::SeqCst);
+        if val > 50 {
+            log::warn!("More than 50 queued commands. Try to batch them together.");
+        }
+
+        self.command_sender
+            .send(ObsCommand::Execute(Box::new(wrapper), tx))
+            .map_err(|_| {
+                ObsError::RuntimeChannelError("Failed to send command to OBS thread".to_string())
+            })?;
+
+        Ok(rx)
+    }
+
+    #[cfg(feature = "enable_runtime")]
+    fn downcast_result<T: 'static>(result: Box<dyn std::any::Any + Send>) -> Result<T, ObsError> {
+        // Downcast the Any type back to T
+        result.downcast::<T>().map(|boxed| *boxed).map_err(|_| {
+            ObsError::RuntimeChannelError(
+                "Failed to downcast result to the expected type".to_string(),
+            )
+        })
+    }
+
     /// Initializes the libobs context and prepares it for recording.
     ///
     /// This method handles core OBS initialization including:
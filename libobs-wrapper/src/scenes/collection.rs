@@ -0,0 +1,633 @@
+//! Scene collections in the JSON format of OBS Studio
+//!
+//! `ObsContext::save_collection` writes all scenes, sources (including their
+//! filters and the transforms of scene items) and transitions the same way
+//! OBS Studio saves a scene collection, so the file can be opened by OBS
+//! Studio and collections created there can be loaded with
+//! `ObsContext::load_collection`.
+use std::{
+    collections::HashMap,
+    ffi::{c_void, CStr},
+    path::Path,
+    time::Duration,
+};
+
+use getters0::Getters;
+use libobs::{obs_data_array_t, obs_data_t, obs_scene_item, obs_scene_t, obs_source_t};
+
+use crate::{
+    context::ObsContext,
+    data::{immutable::ImmutableObsData, ObsData},
+    run_with_obs,
+    sources::{ObsFilterRef, ObsSourceRef},
+    transitions::{duration_to_ms, ObsTransitionRef, DEFAULT_TRANSITION_DURATION},
+    unsafe_send::{Sendable, SendableComp},
+    utils::{ObsError, ObsString},
+};
+
+use super::ObsSceneRef;
+
+/// Everything that was created when loading a scene collection.
+#[derive(Debug, Clone, Getters)]
+#[skip_new]
+pub struct ObsSceneCollection {
+    name: String,
+    /// Scenes in the order they are listed in OBS Studio
+    scenes: Vec<ObsSceneRef>,
+    /// All sources that are not scenes, including groups
+    sources: Vec<ObsSourceRef>,
+    transitions: Vec<ObsTransitionRef>,
+    /// The scene that was shown in the program view
+    current_scene: Option<ObsSceneRef>,
+    current_transition: Option<ObsTransitionRef>,
+}
+
+/// A source loaded by libobs, all pointers hold one reference.
+struct LoadedSource {
+    source: Sendable<*mut obs_source_t>,
+    /// Set if the source is a scene, holds an additional reference to the scene source
+    scene: Option<Sendable<*mut obs_scene_t>>,
+    /// Scene items and their sources, these don't hold a reference
+    items: Vec<(Sendable<*mut obs_scene_item>, Sendable<*mut obs_source_t>)>,
+    filters: Vec<Sendable<*mut obs_source_t>>,
+}
+
+/// A `LoadedSource` after its references were handed to the wrappers
+struct WrappedSource {
+    source: Result<ObsSourceRef, ObsError>,
+    scene: Option<Result<ObsSceneRef, ObsError>>,
+    items: Vec<(Sendable<*mut obs_scene_item>, Sendable<*mut obs_source_t>)>,
+    filters: Vec<Result<ObsFilterRef, ObsError>>,
+}
+
+struct LoadedCollection {
+    name: String,
+    sources: Vec<LoadedSource>,
+    transitions: Vec<LoadedSource>,
+    scene_order: Vec<String>,
+    current_scene: Option<String>,
+    current_transition: Option<String>,
+    transition_duration: Option<i64>,
+}
+
+unsafe fn data_get_string(data: *mut obs_data_t, key: &str) -> Option<String> {
+    let key = ObsString::new(key);
+    if !libobs::obs_data_has_user_value(data, key.as_ptr().0) {
+        return None;
+    }
+
+    let value = libobs::obs_data_get_string(data, key.as_ptr().0);
+    if value.is_null() {
+        return None;
+    }
+
+    Some(CStr::from_ptr(value).to_string_lossy().to_string())
+}
+
+unsafe fn data_set_string(data: *mut obs_data_t, key: &str, value: &str) {
+    let key = ObsString::new(key);
+    let value = ObsString::new(value);
+    libobs::obs_data_set_string(data, key.as_ptr().0, value.as_ptr().0);
+}
+
+/// Sets the array and releases the reference of the caller.
+unsafe fn data_set_array(data: *mut obs_data_t, key: &str, array: *mut obs_data_array_t) {
+    let key = ObsString::new(key);
+    libobs::obs_data_set_array(data, key.as_ptr().0, array);
+    libobs::obs_data_array_release(array);
+}
+
+unsafe fn data_get_array(data: *mut obs_data_t, key: &str) -> *mut obs_data_array_t {
+    let key = ObsString::new(key);
+    libobs::obs_data_get_array(data, key.as_ptr().0)
+}
+
+unsafe extern "C" fn save_source_filter(_data: *mut c_void, source: *mut obs_source_t) -> bool {
+    !libobs::obs_source_is_group(source)
+        && libobs::obs_source_get_type(source) != libobs::obs_source_type_OBS_SOURCE_TYPE_TRANSITION
+}
+
+unsafe extern "C" fn save_group_filter(_data: *mut c_void, source: *mut obs_source_t) -> bool {
+    libobs::obs_source_is_group(source)
+}
+
+unsafe extern "C" fn load_source_callback(param: *mut c_void, source: *mut obs_source_t) {
+    let sources = &mut *(param as *mut Vec<Sendable<*mut obs_source_t>>);
+    sources.push(Sendable(libobs::obs_source_get_ref(source)));
+}
+
+unsafe extern "C" fn enum_filters_callback(
+    _parent: *mut obs_source_t,
+    child: *mut obs_source_t,
+    param: *mut c_void,
+) {
+    let filters = &mut *(param as *mut Vec<Sendable<*mut obs_source_t>>);
+    filters.push(Sendable(libobs::obs_source_get_ref(child)));
+}
+
+unsafe extern "C" fn enum_items_callback(
+    _scene: *mut obs_scene_t,
+    item: *mut obs_scene_item,
+    param: *mut c_void,
+) -> bool {
+    let items =
+        &mut *(param as *mut Vec<(Sendable<*mut obs_scene_item>, Sendable<*mut obs_source_t>)>);
+    items.push((
+        Sendable(item),
+        Sendable(libobs::obs_sceneitem_get_source(item)),
+    ));
+    true
+}
+
+/// Names in the collection that are already used by a source or transition of this context.
+unsafe fn conflicting_names(data: *mut obs_data_t, transition_names: &[String]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for key in ["groups", "sources", "transitions"] {
+        let array = data_get_array(data, key);
+        if array.is_null() {
+            continue;
+        }
+
+        for i in 0..libobs::obs_data_array_count(array) {
+            let item = libobs::obs_data_array_item(array, i);
+            if let Some(name) = data_get_string(item, "name") {
+                let name_str = ObsString::new(name.as_str());
+                let existing = libobs::obs_get_source_by_name(name_str.as_ptr().0);
+                if !existing.is_null() {
+                    libobs::obs_source_release(existing);
+                    conflicts.push(name);
+                } else if transition_names.contains(&name) {
+                    conflicts.push(name);
+                }
+            }
+            libobs::obs_data_release(item);
+        }
+
+        libobs::obs_data_array_release(array);
+    }
+
+    conflicts
+}
+
+/// Collects the scene items and filters of a source that was just loaded.
+unsafe fn inspect_loaded_source(source: Sendable<*mut obs_source_t>) -> LoadedSource {
+    let mut filters = Vec::new();
+    libobs::obs_source_enum_filters(
+        source.0,
+        Some(enum_filters_callback),
+        &mut filters as *mut _ as *mut c_void,
+    );
+
+    let scene = libobs::obs_scene_from_source(source.0);
+    let mut items = Vec::new();
+    if !scene.is_null() {
+        libobs::obs_scene_enum_items(
+            scene,
+            Some(enum_items_callback),
+            &mut items as *mut _ as *mut c_void,
+        );
+
+        libobs::obs_source_get_ref(source.0);
+    }
+
+    LoadedSource {
+        source,
+        scene: (!scene.is_null()).then_some(Sendable(scene)),
+        items,
+        filters,
+    }
+}
+
+impl ObsContext {
+    /// Saves all scenes, sources and transitions as a scene collection in
+    /// the JSON format of OBS Studio.
+    pub fn save_collection_json(&self, name: &str) -> Result<String, ObsError> {
+        let data = self.collection_data(name)?;
+
+        let data_ptr = data.as_ptr();
+        run_with_obs!(self.runtime, (data_ptr), move || unsafe {
+            CStr::from_ptr(libobs::obs_data_get_json_pretty(data_ptr))
+                .to_string_lossy()
+                .to_string()
+        })
+    }
+
+    /// Saves the scene collection to the given file, which can be imported in
+    /// OBS Studio or copied to its `basic/scenes` directory.
+    pub fn save_collection<P: AsRef<Path>>(&self, name: &str, path: P) -> Result<(), ObsError> {
+        let path = path.as_ref();
+        let data = self.collection_data(name)?;
+
+        let data_ptr = data.as_ptr();
+        let path_str = ObsString::new(path.to_string_lossy());
+        let saved = run_with_obs!(self.runtime, (data_ptr), move || unsafe {
+            let temp_ext = ObsString::new("tmp");
+            let backup_ext = ObsString::new("bak");
+            libobs::obs_data_save_json_safe(
+                data_ptr,
+                path_str.as_ptr().0,
+                temp_ext.as_ptr().0,
+                backup_ext.as_ptr().0,
+            )
+        })?;
+
+        if !saved {
+            return Err(ObsError::IoError(format!(
+                "Failed to save scene collection to {}",
+                path.display()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Loads a scene collection that was saved by OBS Studio or `save_collection_json`.
+    ///
+    /// Loaded scenes, transitions and filters are added to this context and
+    /// the current scene and transition of the collection are set to output
+    /// channel 0. Sources that are in no scene are only kept by the returned
+    /// collection and are destroyed once it is dropped.
+    /// Nothing is loaded if a source or transition of the collection has the
+    /// same name as one that already exists in this context.
+    pub fn load_collection_json(&mut self, json: &str) -> Result<ObsSceneCollection, ObsError> {
+        let data = ObsData::from_json(json, self.runtime.clone())?;
+        self.load_collection_data(data.as_ptr())
+    }
+
+    /// Loads a scene collection file, see `load_collection_json`. If the file
+    /// is corrupted, the backup that is created when saving is used instead.
+    pub fn load_collection<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<ObsSceneCollection, ObsError> {
+        let path = path.as_ref();
+
+        let path_str = ObsString::new(path.to_string_lossy());
+        let data_ptr = run_with_obs!(self.runtime, move || unsafe {
+            let backup_ext = ObsString::new("bak");
+            Sendable(libobs::obs_data_create_from_json_file_safe(
+                path_str.as_ptr().0,
+                backup_ext.as_ptr().0,
+            ))
+        })?;
+
+        if data_ptr.0.is_null() {
+            return Err(ObsError::IoError(format!(
+                "Failed to read scene collection from {}",
+                path.display()
+            )));
+        }
+
+        let data = ImmutableObsData::from_raw(data_ptr, self.runtime.clone());
+        self.load_collection_data(data.as_ptr())
+    }
+
+    fn collection_data(&self, name: &str) -> Result<ImmutableObsData, ObsError> {
+        let scene_names = self
+            .scenes
+            .read()
+            .map_err(|_| ObsError::LockError("Failed to acquire read lock on scenes".to_string()))?
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect::<Vec<_>>();
+
+        let transitions = self
+            .transitions
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on transitions".to_string())
+            })?
+            .iter()
+            .map(|t| Sendable(t.as_ptr()))
+            .collect::<Vec<_>>();
+
+        let output_transition = self.output_transition()?;
+        let transition_duration = output_transition
+            .as_ref()
+            .map_or(DEFAULT_TRANSITION_DURATION, |t| t.duration());
+        let current_transition = output_transition.as_ref().map(|t| t.name());
+        let transition_ptr = output_transition.map(|t| Sendable(t.as_ptr()));
+
+        let name = name.to_string();
+        let data = run_with_obs!(self.runtime, move || unsafe {
+            let data = libobs::obs_data_create();
+            data_set_string(data, "name", &name);
+
+            let sources =
+                libobs::obs_save_sources_filtered(Some(save_source_filter), std::ptr::null_mut());
+            data_set_array(data, "sources", sources);
+
+            let groups =
+                libobs::obs_save_sources_filtered(Some(save_group_filter), std::ptr::null_mut());
+            data_set_array(data, "groups", groups);
+
+            let scene_order = libobs::obs_data_array_create();
+            for scene_name in &scene_names {
+                let item = libobs::obs_data_create();
+                data_set_string(item, "name", scene_name);
+                libobs::obs_data_array_push_back(scene_order, item);
+                libobs::obs_data_release(item);
+            }
+            data_set_array(data, "scene_order", scene_order);
+
+            let transition_array = libobs::obs_data_array_create();
+            for transition in &transitions {
+                let item = libobs::obs_save_source(transition.0);
+                libobs::obs_data_array_push_back(transition_array, item);
+                libobs::obs_data_release(item);
+            }
+            data_set_array(data, "transitions", transition_array);
+
+            // With an output transition, channel 0 holds the transition instead of the scene
+            let program = match &transition_ptr {
+                Some(t) => libobs::obs_transition_get_active_source(t.0),
+                None => libobs::obs_get_output_source(0),
+            };
+
+            if !program.is_null() {
+                if libobs::obs_source_is_scene(program) {
+                    let program_name = CStr::from_ptr(libobs::obs_source_get_name(program))
+                        .to_string_lossy()
+                        .to_string();
+
+                    data_set_string(data, "current_scene", &program_name);
+                    data_set_string(data, "current_program_scene", &program_name);
+                }
+
+                libobs::obs_source_release(program);
+            }
+
+            if let Some(current_transition) = &current_transition {
+                data_set_string(data, "current_transition", current_transition);
+            }
+
+            let key = ObsString::new("transition_duration");
+            libobs::obs_data_set_int(
+                data,
+                key.as_ptr().0,
+                duration_to_ms(transition_duration) as i64,
+            );
+
+            Sendable(data)
+        })?;
+
+        Ok(ImmutableObsData::from_raw(data, self.runtime.clone()))
+    }
+
+    fn load_collection_data(
+        &mut self,
+        data: Sendable<*mut obs_data_t>,
+    ) -> Result<ObsSceneCollection, ObsError> {
+        // Loaded transitions are private, so libobs can't find them by name
+        let transition_names = self
+            .transitions
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on transitions".to_string())
+            })?
+            .iter()
+            .map(|t| t.name())
+            .collect::<Vec<_>>();
+
+        let loaded = run_with_obs!(self.runtime, (data), move || unsafe {
+            let conflicts = conflicting_names(data, &transition_names);
+            if !conflicts.is_empty() {
+                return Err(conflicts);
+            }
+
+            let mut sources = Vec::new();
+
+            // Same order as OBS Studio, groups have to exist before the scenes that contain them
+            for key in ["groups", "sources"] {
+                let array = data_get_array(data, key);
+                if array.is_null() {
+                    continue;
+                }
+
+                libobs::obs_load_sources(
+                    array,
+                    Some(load_source_callback),
+                    &mut sources as *mut _ as *mut c_void,
+                );
+                libobs::obs_data_array_release(array);
+            }
+
+            let mut transitions = Vec::new();
+            let transition_array = data_get_array(data, "transitions");
+            if !transition_array.is_null() {
+                for i in 0..libobs::obs_data_array_count(transition_array) {
+                    let item = libobs::obs_data_array_item(transition_array, i);
+                    let transition = libobs::obs_load_private_source(item);
+                    libobs::obs_data_release(item);
+
+                    if !transition.is_null() {
+                        libobs::obs_source_load2(transition);
+                        transitions.push(Sendable(transition));
+                    }
+                }
+
+                libobs::obs_data_array_release(transition_array);
+            }
+
+            let mut scene_order = Vec::new();
+            let order_array = data_get_array(data, "scene_order");
+            if !order_array.is_null() {
+                for i in 0..libobs::obs_data_array_count(order_array) {
+                    let item = libobs::obs_data_array_item(order_array, i);
+                    if let Some(name) = data_get_string(item, "name") {
+                        scene_order.push(name);
+                    }
+                    libobs::obs_data_release(item);
+                }
+
+                libobs::obs_data_array_release(order_array);
+            }
+
+            let transition_duration_key = ObsString::new("transition_duration");
+            Ok(LoadedCollection {
+                name: data_get_string(data, "name").unwrap_or_default(),
+                sources: sources
+                    .into_iter()
+                    .map(|s| inspect_loaded_source(s))
+                    .collect(),
+                transitions: transitions
+                    .into_iter()
+                    .map(|s| inspect_loaded_source(s))
+                    .collect(),
+                scene_order,
+                current_scene: data_get_string(data, "current_program_scene")
+                    .or_else(|| data_get_string(data, "current_scene")),
+                current_transition: data_get_string(data, "current_transition"),
+                transition_duration: libobs::obs_data_has_user_value(
+                    data,
+                    transition_duration_key.as_ptr().0,
+                )
+                .then(|| libobs::obs_data_get_int(data, transition_duration_key.as_ptr().0)),
+            })
+        })?
+        .map_err(|names| {
+            ObsError::InvalidOperation(format!(
+                "Scene collection contains names that already exist: {}",
+                names.join(", ")
+            ))
+        })?;
+
+        self.wrap_loaded_collection(loaded)
+    }
+
+    /// Creates the wrappers for everything libobs loaded and stores them in this context.
+    fn wrap_loaded_collection(
+        &mut self,
+        loaded: LoadedCollection,
+    ) -> Result<ObsSceneCollection, ObsError> {
+        // Wrap every pointer before anything can fail, so all references are released on errors
+        let loaded_sources = loaded
+            .sources
+            .into_iter()
+            .map(|loaded_source| self.wrap_loaded_source(loaded_source))
+            .collect::<Vec<_>>();
+        let loaded_transitions = loaded
+            .transitions
+            .into_iter()
+            .map(|loaded_transition| self.wrap_loaded_source(loaded_transition))
+            .collect::<Vec<_>>();
+
+        let mut wrapped_sources = HashMap::new();
+        let mut scenes = Vec::new();
+        let mut sources = Vec::new();
+        let mut filters = Vec::new();
+        let mut scene_items = Vec::new();
+
+        for wrapped in loaded_sources {
+            let source = wrapped.source?;
+            wrapped_sources.insert(SendableComp(source.as_ptr()), source.clone());
+
+            for filter in wrapped.filters {
+                filters.push(filter?);
+            }
+
+            match wrapped.scene {
+                Some(scene) => {
+                    let scene = scene?;
+                    scene_items.push((scene.clone(), wrapped.items));
+                    scenes.push(scene);
+                }
+                None => sources.push(source),
+            }
+        }
+
+        // Every source of a scene item is a public source, so it was loaded as well
+        for (scene, items) in scene_items {
+            for (item, item_source) in items {
+                let Some(source) = wrapped_sources.get(&SendableComp(item_source.0)) else {
+                    log::warn!(
+                        "Source of a scene item in {} was not loaded, skipping it",
+                        scene.name()
+                    );
+                    continue;
+                };
+
+                source
+                    .scene_items
+                    .write()
+                    .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+                    .insert(SendableComp(scene.as_ptr().0), item);
+
+                scene
+                    .sources
+                    .write()
+                    .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+                    .insert(source.clone());
+            }
+        }
+
+        scenes.sort_by_key(|s| {
+            let name = s.name().to_string();
+            loaded
+                .scene_order
+                .iter()
+                .position(|n| *n == name)
+                .unwrap_or(usize::MAX)
+        });
+
+        let duration = loaded
+            .transition_duration
+            .map_or(DEFAULT_TRANSITION_DURATION, |ms| {
+                Duration::from_millis(ms.max(0) as u64)
+            });
+
+        let mut transitions = Vec::new();
+        for wrapped in loaded_transitions {
+            let source = wrapped.source?;
+
+            for filter in wrapped.filters {
+                filters.push(filter?);
+            }
+
+            transitions.push(ObsTransitionRef::from_source(source, duration));
+        }
+
+        self.scenes
+            .write()
+            .map_err(|_| ObsError::LockError("Failed to acquire write lock on scenes".to_string()))?
+            .extend(scenes.iter().cloned());
+
+        self.transitions
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on transitions".to_string())
+            })?
+            .extend(transitions.iter().cloned());
+
+        self.filters
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on filters".to_string())
+            })?
+            .extend(filters);
+
+        let current_scene = loaded
+            .current_scene
+            .and_then(|name| scenes.iter().find(|s| s.name().to_string() == name))
+            .cloned();
+
+        let current_transition = loaded
+            .current_transition
+            .and_then(|name| transitions.iter().find(|t| t.name() == name))
+            .cloned();
+
+        if let Some(scene) = &current_scene {
+            scene.set_to_channel(0)?;
+        }
+
+        if let Some(transition) = &current_transition {
+            self.set_output_transition(transition)?;
+        }
+
+        Ok(ObsSceneCollection {
+            name: loaded.name,
+            scenes,
+            sources,
+            transitions,
+            current_scene,
+            current_transition,
+        })
+    }
+
+    /// Takes ownership of every reference of the loaded source. Pointers that
+    /// fail to be wrapped are released, so nothing leaks if one of them fails.
+    fn wrap_loaded_source(&self, loaded: LoadedSource) -> WrappedSource {
+        WrappedSource {
+            source: ObsSourceRef::from_raw(loaded.source, self.runtime.clone()),
+            scene: loaded.scene.map(|scene| {
+                ObsSceneRef::from_raw(scene, self.active_scenes.clone(), self.runtime.clone())
+            }),
+            items: loaded.items,
+            filters: loaded
+                .filters
+                .into_iter()
+                .map(|filter| ObsFilterRef::from_raw(filter, self.runtime.clone()))
+                .collect(),
+        }
+    }
+}
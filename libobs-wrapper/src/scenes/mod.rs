@@ -1,7 +1,12 @@
+mod collection;
+mod scene_item;
 mod transform_info;
+pub use collection::*;
+pub use scene_item::*;
 pub use transform_info::*;
 
 use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::sync::{Arc, RwLock};
@@ -9,8 +14,11 @@ use std::sync::{Arc, RwLock};
 use getters0::Getters;
 use libobs::{obs_scene_item, obs_scene_t, obs_source_t, obs_transform_info, obs_video_info};
 
+use crate::canvas::ObsCanvasWeakRef;
 use crate::enums::ObsBoundsType;
 use crate::macros::impl_eq_of_ptr;
+#[cfg(feature = "async")]
+use crate::run_with_obs_async;
 use crate::unsafe_send::SendableComp;
 use crate::{
     graphics::Vec2,
@@ -46,6 +54,11 @@ pub struct ObsSceneRef {
     /// Maps the currently current active scenes by their channel (this is a shared reference between all scenes)
     pub(crate) active_scenes: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
 
+    #[skip_getter]
+    /// The canvas this scene was created on, `None` for the main canvas.
+    /// Channels of other canvases are tracked by the canvas instead of `active_scenes`.
+    canvas: Option<ObsCanvasWeakRef>,
+
     #[skip_getter]
     _guard: Arc<_SceneDropGuard>,
 
@@ -68,17 +81,135 @@ impl ObsSceneRef {
             Sendable(libobs::obs_scene_create(name_ptr))
         })?;
 
-        let signals = Arc::new(ObsSceneSignals::new(&scene, runtime.clone())?);
-        Ok(Self {
+        if scene.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let signals = ObsSceneSignals::new(&scene, runtime.clone())?;
+        Ok(Self::from_created(
+            name,
+            scene,
+            active_scenes,
+            None,
+            signals,
+            runtime,
+        ))
+    }
+
+    /// Async version of `new`, creating the scene is awaited instead of blocking.
+    #[cfg(feature = "async")]
+    pub(crate) async fn new_async(
+        name: ObsString,
+        active_scenes: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        let name_ptr = name.as_ptr();
+        let scene = run_with_obs_async!(runtime, (name_ptr), move || unsafe {
+            Sendable(libobs::obs_scene_create(name_ptr))
+        })?;
+
+        if scene.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let signals = ObsSceneSignals::new_async(&scene, runtime.clone()).await?;
+        Ok(Self::from_created(
+            name,
+            scene,
+            active_scenes,
+            None,
+            signals,
+            runtime,
+        ))
+    }
+
+    /// Creates a scene that belongs to the given canvas instead of the main one.
+    pub(crate) fn new_in_canvas(
+        name: ObsString,
+        canvas: ObsCanvasWeakRef,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        let name_ptr = name.as_ptr();
+        let canvas_ptr = canvas.as_ptr();
+        let scene = run_with_obs!(runtime, (canvas_ptr, name_ptr), move || unsafe {
+            Sendable(libobs::obs_canvas_scene_create(canvas_ptr, name_ptr))
+        })?;
+
+        if scene.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let signals = ObsSceneSignals::new(&scene, runtime.clone())?;
+        Ok(Self::from_created(
+            name,
+            scene,
+            Default::default(),
+            Some(canvas),
+            signals,
+            runtime,
+        ))
+    }
+
+    fn from_created(
+        name: ObsString,
+        scene: Sendable<*mut obs_scene_t>,
+        active_scenes: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
+        canvas: Option<ObsCanvasWeakRef>,
+        signals: ObsSceneSignals,
+        runtime: ObsRuntime,
+    ) -> Self {
+        Self {
             name,
             scene: Arc::new(scene.clone()),
             sources: Arc::new(RwLock::new(HashSet::new())),
             active_scenes,
+            canvas,
             _guard: Arc::new(_SceneDropGuard {
                 scene,
                 runtime: runtime.clone(),
             }),
             runtime,
+            signals: Arc::new(signals),
+        }
+    }
+
+    /// Wraps a scene that was created by libobs itself, e.g. when loading a
+    /// scene collection. Takes ownership of one reference of the scene.
+    pub(crate) fn from_raw(
+        scene: Sendable<*mut obs_scene_t>,
+        active_scenes: Arc<RwLock<HashMap<u32, ObsSceneRef>>>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        if scene.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let scene_ptr = scene.clone();
+        let name = run_with_obs!(runtime, (scene_ptr), move || unsafe {
+            let scene_source = libobs::obs_scene_get_source(scene_ptr);
+
+            // The drop guard releases the scene and its source separately
+            libobs::obs_source_get_ref(scene_source);
+            CStr::from_ptr(libobs::obs_source_get_name(scene_source))
+                .to_string_lossy()
+                .to_string()
+        })?;
+
+        // Created before the signals, so both references are released if connecting them fails
+        let guard = Arc::new(_SceneDropGuard {
+            scene: scene.clone(),
+            runtime: runtime.clone(),
+        });
+
+        let signals = Arc::new(ObsSceneSignals::new(&scene, runtime.clone())?);
+        Ok(Self {
+            name: name.into(),
+            scene: Arc::new(scene),
+            sources: Arc::new(RwLock::new(HashSet::new())),
+            active_scenes,
+            canvas: None,
+            _guard: guard,
+            runtime,
             signals,
         })
     }
@@ -92,7 +223,13 @@ impl ObsSceneRef {
     /// There are 64
     /// channels that you can assign scenes to, which will draw on top of each
     /// other in ascending index order.
+    ///
+    /// Scenes of a canvas are assigned to the channels of their canvas, see `ObsCanvasRef::set_channel`.
     pub fn set_to_channel(&self, channel: u32) -> Result<(), ObsError> {
+        if let Some(canvas) = &self.canvas {
+            return canvas.set_channel(&self.runtime, channel, Some(self));
+        }
+
         if channel >= libobs::MAX_CHANNELS {
             return Err(ObsError::InvalidOperation(format!(
                 "Channel {} is out of bounds (max {})",
@@ -116,6 +253,10 @@ impl ObsSceneRef {
 
     /// Removes a scene from a given output channel, for more info about channels see `set_to_channel`.
     pub fn remove_from_channel(&self, channel: u32) -> Result<(), ObsError> {
+        if let Some(canvas) = &self.canvas {
+            return canvas.set_channel(&self.runtime, channel, None);
+        }
+
         if channel >= libobs::MAX_CHANNELS {
             return Err(ObsError::InvalidOperation(format!(
                 "Channel {} is out of bounds (max {})",
@@ -144,10 +285,11 @@ impl ObsSceneRef {
         })
     }
 
-    /// Adds and creates the specified source to this scene. Returns a reference to the created source. The source is also stored internally in this scene.
+    /// Adds and creates the specified source to this scene. Returns the scene item of the created source,
+    /// the source itself is available using `ObsSceneItemRef::source` and is also stored internally in this scene.
     ///
     /// If you need to remove the source later, use `remove_source`.
-    pub fn add_source(&mut self, info: SourceInfo) -> Result<ObsSourceRef, ObsError> {
+    pub fn add_source(&mut self, info: SourceInfo) -> Result<ObsSceneItemRef, ObsError> {
         let source = ObsSourceRef::new(
             info.id,
             info.name,
@@ -163,22 +305,107 @@ impl ObsSceneRef {
             Sendable(libobs::obs_scene_add(scene_ptr, source_ptr))
         })?;
 
+        self.register_scene_item(ptr, source)
+    }
+
+    /// Async version of `add_source`, the source is created without blocking.
+    #[cfg(feature = "async")]
+    pub async fn add_source_async(
+        &mut self,
+        info: SourceInfo,
+    ) -> Result<ObsSceneItemRef, ObsError> {
+        let source = ObsSourceRef::new_async(
+            info.id,
+            info.name,
+            info.settings,
+            info.hotkey_data,
+            self.runtime.clone(),
+        )
+        .await?;
+
+        let scene_ptr = self.scene.clone();
+        let source_ptr = source.source.clone();
+
+        // The reference of the returned scene item is taken right away, so adding
+        // the source takes a single round trip to the OBS thread
+        let ptr = run_with_obs_async!(self.runtime, (scene_ptr, source_ptr), move || unsafe {
+            let scene_item = libobs::obs_scene_add(scene_ptr, source_ptr);
+            if !scene_item.is_null() {
+                libobs::obs_sceneitem_addref(scene_item);
+            }
+
+            Sendable(scene_item)
+        })?;
+
         if ptr.0.is_null() {
             return Err(ObsError::NullPointer);
         }
 
-        //TODO We should clear one reference because with this obs doesn't clean up properly
+        let item =
+            ObsSceneItemRef::from_ref(ptr.clone(), source, self.clone(), self.runtime.clone());
+        self.track_scene_item(ptr, item.source())?;
+
+        Ok(item)
+    }
+
+    /// Adds a source that already exists to this scene, e.g. to show the
+    /// same source in the main video and in a canvas.
+    pub fn add_existing_source(
+        &mut self,
+        source: &ObsSourceRef,
+    ) -> Result<ObsSceneItemRef, ObsError> {
+        let scene_ptr = self.scene.clone();
+        let source_ptr = source.source.clone();
+
+        let ptr = run_with_obs!(self.runtime, (scene_ptr, source_ptr), move || unsafe {
+            Sendable(libobs::obs_scene_add(scene_ptr, source_ptr))
+        })?;
+
+        self.register_scene_item(ptr, source.clone())
+    }
+
+    fn register_scene_item(
+        &mut self,
+        ptr: Sendable<*mut obs_scene_item>,
+        source: ObsSourceRef,
+    ) -> Result<ObsSceneItemRef, ObsError> {
+        if ptr.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        self.track_scene_item(ptr.clone(), &source)?;
+        ObsSceneItemRef::new(ptr, source, self.clone(), self.runtime.clone())
+    }
+
+    fn track_scene_item(
+        &mut self,
+        ptr: Sendable<*mut obs_scene_item>,
+        source: &ObsSourceRef,
+    ) -> Result<(), ObsError> {
+        // The reference returned by `obs_scene_add` is owned by the scene
         source
             .scene_items
             .write()
             .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
-            .insert(SendableComp(self.scene.0), ptr.clone());
+            .insert(SendableComp(self.scene.0), ptr);
 
         self.sources
             .write()
             .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
             .insert(source.clone());
-        Ok(source)
+
+        Ok(())
+    }
+
+    /// Gets the scene item of the given source in this scene.
+    pub fn get_scene_item(&self, source: &ObsSourceRef) -> Result<ObsSceneItemRef, ObsError> {
+        let scene_item_ptr = self.get_scene_item_ptr(source)?;
+        ObsSceneItemRef::new(
+            scene_item_ptr,
+            source.clone(),
+            self.clone(),
+            self.runtime.clone(),
+        )
     }
 
     /// Gets a source by name from this scene. Returns None if no source with the given name exists in this scene.
@@ -209,10 +436,9 @@ impl ObsSceneRef {
                 .clone();
 
             run_with_obs!(self.runtime, (scene_item_ptr), move || unsafe {
-                // Remove the scene item
+                // Removing the item also releases the reference that is held by the scene,
+                // an `ObsSceneItemRef` keeps the item alive with its own reference
                 libobs::obs_sceneitem_remove(scene_item_ptr);
-                // Release the scene item reference
-                libobs::obs_sceneitem_release(scene_item_ptr);
             })?;
         }
 
@@ -232,17 +458,13 @@ impl ObsSceneRef {
     }
 
     /// Adds a filter to the given source in this scene.
+    /// Same as `ObsSourceRef::add_filter`, the filter applies wherever the source is used.
     pub fn add_scene_filter(
         &self,
         source: &ObsSourceRef,
         filter_ref: &ObsFilterRef,
     ) -> Result<(), ObsError> {
-        let source_ptr = source.source.clone();
-        let filter_ptr = filter_ref.source.clone();
-        run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
-            libobs::obs_source_filter_add(source_ptr, filter_ptr);
-        })?;
-        Ok(())
+        source.add_filter(filter_ref)
     }
 
     /// Removes a filter from the this scene (internally removes the filter to the scene's source).
@@ -251,12 +473,7 @@ impl ObsSceneRef {
         source: &ObsSourceRef,
         filter_ref: &ObsFilterRef,
     ) -> Result<(), ObsError> {
-        let source_ptr = source.source.clone();
-        let filter_ptr = filter_ref.source.clone();
-        run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
-            libobs::obs_source_filter_remove(source_ptr, filter_ptr);
-        })?;
-        Ok(())
+        source.remove_filter(filter_ref)
     }
 
     /// Gets the underlying scene item pointer for the given source in this scene.
@@ -0,0 +1,292 @@
+use std::{hash::Hash, ptr, sync::Arc, time::Duration};
+
+use libobs::{obs_scene_item, obs_sceneitem_crop};
+
+use crate::{
+    enums::{ObsBlendingMethod, ObsBlendingType, ObsOrderMovement, ObsScaleType, OsEnumType},
+    impl_obs_drop,
+    macros::{enum_from_number, impl_eq_of_ptr},
+    run_with_obs,
+    runtime::ObsRuntime,
+    sources::ObsSourceRef,
+    transitions::{duration_to_ms, ObsTransitionRef},
+    unsafe_send::Sendable,
+    utils::ObsError,
+};
+
+use super::ObsSceneRef;
+
+#[derive(Debug)]
+struct _ObsSceneItemDropGuard {
+    scene_item: Sendable<*mut obs_scene_item>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsSceneItemDropGuard, (scene_item), move || unsafe {
+    libobs::obs_sceneitem_release(scene_item);
+});
+
+/// Number of pixels that are cut off at each edge of a scene item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ObsSceneItemCrop {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl From<obs_sceneitem_crop> for ObsSceneItemCrop {
+    fn from(crop: obs_sceneitem_crop) -> Self {
+        Self {
+            left: crop.left,
+            top: crop.top,
+            right: crop.right,
+            bottom: crop.bottom,
+        }
+    }
+}
+
+impl From<ObsSceneItemCrop> for obs_sceneitem_crop {
+    fn from(crop: ObsSceneItemCrop) -> Self {
+        Self {
+            left: crop.left,
+            top: crop.top,
+            right: crop.right,
+            bottom: crop.bottom,
+        }
+    }
+}
+
+/// A source placed in a scene. Holds its own reference to the scene item,
+/// so it stays valid even after it was removed from the scene (changes to
+/// a removed item have no effect though).
+#[derive(Debug, Clone)]
+pub struct ObsSceneItemRef {
+    /// Released before the scene, which is kept alive by `scene`
+    _guard: Arc<_ObsSceneItemDropGuard>,
+    pub(crate) scene_item: Sendable<*mut obs_scene_item>,
+    source: ObsSourceRef,
+    scene: ObsSceneRef,
+    runtime: ObsRuntime,
+}
+
+impl_eq_of_ptr!(ObsSceneItemRef, scene_item);
+
+impl ObsSceneItemRef {
+    pub(crate) fn new(
+        scene_item: Sendable<*mut obs_scene_item>,
+        source: ObsSourceRef,
+        scene: ObsSceneRef,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        if scene_item.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let item_ptr = scene_item.clone();
+        run_with_obs!(runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_addref(item_ptr);
+        })?;
+
+        Ok(Self::from_ref(scene_item, source, scene, runtime))
+    }
+
+    /// Wraps a scene item whose reference was already taken, so the drop guard releases it.
+    pub(crate) fn from_ref(
+        scene_item: Sendable<*mut obs_scene_item>,
+        source: ObsSourceRef,
+        scene: ObsSceneRef,
+        runtime: ObsRuntime,
+    ) -> Self {
+        Self {
+            _guard: Arc::new(_ObsSceneItemDropGuard {
+                scene_item: scene_item.clone(),
+                runtime: runtime.clone(),
+            }),
+            scene_item,
+            source,
+            scene,
+            runtime,
+        }
+    }
+
+    /// The source that is shown by this scene item.
+    pub fn source(&self) -> &ObsSourceRef {
+        &self.source
+    }
+
+    /// The scene this item belongs to.
+    pub fn scene(&self) -> &ObsSceneRef {
+        &self.scene
+    }
+
+    pub fn as_ptr(&self) -> Sendable<*mut obs_scene_item> {
+        self.scene_item.clone()
+    }
+
+    /// Id of the scene item, unique within its scene.
+    pub fn id(&self) -> Result<i64, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_id(item_ptr)
+        })
+    }
+
+    pub fn is_visible(&self) -> Result<bool, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_visible(item_ptr)
+        })
+    }
+
+    /// Shows or hides the item. If a show or hide transition is set, it is played.
+    pub fn set_visible(&self, visible: bool) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_visible(item_ptr, visible);
+        })
+    }
+
+    pub fn is_locked(&self) -> Result<bool, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_locked(item_ptr)
+        })
+    }
+
+    /// Locked items can't be moved in the preview. This has no effect on changes made through code.
+    pub fn set_locked(&self, locked: bool) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_locked(item_ptr, locked);
+        })
+    }
+
+    /// Moves the item up or down relative to the other items of the scene.
+    pub fn set_order(&self, movement: ObsOrderMovement) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_order(item_ptr, movement as OsEnumType);
+        })
+    }
+
+    /// The index of the item in its scene, 0 is the item at the bottom.
+    pub fn index(&self) -> Result<i32, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_order_position(item_ptr)
+        })
+    }
+
+    /// Moves the item to the given index, 0 is the bottom of the scene.
+    pub fn move_to_index(&self, index: i32) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_order_position(item_ptr, index);
+        })
+    }
+
+    pub fn get_crop(&self) -> Result<ObsSceneItemCrop, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            let mut crop: obs_sceneitem_crop = std::mem::zeroed();
+            libobs::obs_sceneitem_get_crop(item_ptr, &mut crop);
+            ObsSceneItemCrop::from(crop)
+        })
+    }
+
+    pub fn set_crop(&self, crop: ObsSceneItemCrop) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_crop(item_ptr, &crop.into());
+        })
+    }
+
+    pub fn get_blending_mode(&self) -> Result<ObsBlendingType, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        let mode = run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_blending_mode(item_ptr)
+        })?;
+
+        enum_from_number!(ObsBlendingType, mode)
+            .ok_or_else(|| ObsError::EnumConversionError(format!("Unknown blending mode {}", mode)))
+    }
+
+    pub fn set_blending_mode(&self, mode: ObsBlendingType) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_blending_mode(item_ptr, mode as OsEnumType);
+        })
+    }
+
+    pub fn get_blending_method(&self) -> Result<ObsBlendingMethod, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        let method = run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_blending_method(item_ptr)
+        })?;
+
+        enum_from_number!(ObsBlendingMethod, method).ok_or_else(|| {
+            ObsError::EnumConversionError(format!("Unknown blending method {}", method))
+        })
+    }
+
+    pub fn set_blending_method(&self, method: ObsBlendingMethod) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_blending_method(item_ptr, method as OsEnumType);
+        })
+    }
+
+    pub fn get_scale_filter(&self) -> Result<ObsScaleType, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        let filter = run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_scale_filter(item_ptr)
+        })?;
+
+        enum_from_number!(ObsScaleType, filter).ok_or_else(|| {
+            ObsError::EnumConversionError(format!("Unknown scale filter {}", filter))
+        })
+    }
+
+    /// Sets the filter that is used when the item is scaled, `ObsScaleType::Disable`
+    /// uses the default filter of the video context.
+    pub fn set_scale_filter(&self, filter: ObsScaleType) -> Result<(), ObsError> {
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_scale_filter(item_ptr, filter as OsEnumType);
+        })
+    }
+
+    /// Sets the transition that is played when the item is shown (`show = true`)
+    /// or hidden (`show = false`). `None` removes the transition.
+    pub fn set_transition(
+        &self,
+        show: bool,
+        transition: Option<&ObsTransitionRef>,
+    ) -> Result<(), ObsError> {
+        let transition_ptr = Sendable(transition.map_or(ptr::null_mut(), |t| t.as_ptr()));
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr, transition_ptr), move || unsafe {
+            // The scene item takes its own reference of the transition
+            libobs::obs_sceneitem_set_transition(item_ptr, show, transition_ptr);
+        })
+    }
+
+    /// Duration of the show or hide transition.
+    pub fn get_transition_duration(&self, show: bool) -> Result<Duration, ObsError> {
+        let item_ptr = self.scene_item.clone();
+        let duration_ms = run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_get_transition_duration(item_ptr, show)
+        })?;
+
+        Ok(Duration::from_millis(duration_ms as u64))
+    }
+
+    pub fn set_transition_duration(&self, show: bool, duration: Duration) -> Result<(), ObsError> {
+        let duration_ms = duration_to_ms(duration);
+        let item_ptr = self.scene_item.clone();
+        run_with_obs!(self.runtime, (item_ptr), move || unsafe {
+            libobs::obs_sceneitem_set_transition_duration(item_ptr, show, duration_ms);
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{context::ObsContext, data::ObsObjectBuilder, utils::ObsError};
+
+use super::ObsServiceRef;
+
+pub trait ObsServiceBuilder: ObsObjectBuilder {
+    fn add_to_context(self, context: &mut ObsContext) -> Result<ObsServiceRef, ObsError>
+    where
+        Self: Sized,
+    {
+        let s = self.build()?;
+        context.service(s)
+    }
+}
@@ -0,0 +1,216 @@
+//! Streaming services
+//!
+//! A service (`rtmp_custom`, `rtmp_common`, `whip_custom`, ...) holds the
+//! server and credentials a streaming output connects to. It is attached to
+//! an output using `ObsOutputRef::set_service`, the output then reads the
+//! connection details from the service when it is started.
+mod builder;
+pub use builder::*;
+
+use std::{
+    ffi::{c_char, CStr},
+    ptr,
+    sync::Arc,
+};
+
+use libobs::obs_service_t;
+
+use crate::{
+    data::{immutable::ImmutableObsData, ObsData},
+    enums::{ObsServiceConnectInfo, OsEnumType},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::Sendable,
+    utils::{traits::ObsUpdatable, ObsError, ObsString, ServiceInfo},
+};
+
+#[derive(Debug)]
+struct _ObsServiceDropGuard {
+    service: Sendable<*mut obs_service_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsServiceDropGuard, (service), move || unsafe {
+    libobs::obs_service_release(service);
+});
+
+/// Converts a string that is owned by libobs, returning `None` for null pointers.
+unsafe fn string_from_ptr(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+
+    Some(CStr::from_ptr(ptr).to_string_lossy().to_string())
+}
+
+/// A reference to a streaming service. The service is reference
+/// counted, so it is safe to clone this struct.
+#[derive(Debug, Clone)]
+pub struct ObsServiceRef {
+    pub(crate) service: Sendable<*mut obs_service_t>,
+    pub(crate) id: ObsString,
+    pub(crate) name: ObsString,
+    pub(crate) runtime: ObsRuntime,
+    _drop_guard: Arc<_ObsServiceDropGuard>,
+}
+
+impl ObsServiceRef {
+    pub fn new(info: ServiceInfo, runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let ServiceInfo {
+            id,
+            name,
+            settings,
+            hotkey_data,
+        } = info;
+
+        let settings_ptr = match &settings {
+            Some(x) => x.as_ptr(),
+            None => Sendable(ptr::null_mut()),
+        };
+
+        let hotkey_data_ptr = match &hotkey_data {
+            Some(x) => x.as_ptr(),
+            None => Sendable(ptr::null_mut()),
+        };
+
+        let id_ptr = id.as_ptr();
+        let name_ptr = name.as_ptr();
+        let service = run_with_obs!(
+            runtime,
+            (id_ptr, name_ptr, settings_ptr, hotkey_data_ptr),
+            move || unsafe {
+                Sendable(libobs::obs_service_create(
+                    id_ptr,
+                    name_ptr,
+                    settings_ptr,
+                    hotkey_data_ptr,
+                ))
+            }
+        )?;
+
+        if service.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        Ok(Self {
+            service: service.clone(),
+            id,
+            name,
+            _drop_guard: Arc::new(_ObsServiceDropGuard {
+                service,
+                runtime: runtime.clone(),
+            }),
+            runtime,
+        })
+    }
+
+    pub fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    pub fn id(&self) -> String {
+        self.id.to_string()
+    }
+
+    pub fn as_ptr(&self) -> Sendable<*mut obs_service_t> {
+        self.service.clone()
+    }
+
+    /// The protocol used by this service, e.g. `RTMP`, `RTMPS`, `SRT`, `RIST` or `WHIP`.
+    /// For custom services this depends on the scheme of the server url.
+    pub fn protocol(&self) -> Result<Option<String>, ObsError> {
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr), move || unsafe {
+            string_from_ptr(libobs::obs_service_get_protocol(service_ptr))
+        })
+    }
+
+    /// The output type the service wants to be used with, if it has a preference.
+    pub fn preferred_output_type(&self) -> Result<Option<String>, ObsError> {
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr), move || unsafe {
+            string_from_ptr(libobs::obs_service_get_preferred_output_type(service_ptr))
+        })
+    }
+
+    /// Returns the given connection detail, or `None` if it is not set.
+    pub fn connect_info(&self, info: ObsServiceConnectInfo) -> Result<Option<String>, ObsError> {
+        // The connect info type is signed on windows, but the function takes an u32
+        #[allow(clippy::unnecessary_cast)]
+        let info = info as OsEnumType as u32;
+
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr), move || unsafe {
+            string_from_ptr(libobs::obs_service_get_connect_info(service_ptr, info))
+        })
+    }
+
+    /// Whether all connection details that are required by the service are set.
+    pub fn can_try_to_connect(&self) -> Result<bool, ObsError> {
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr), move || unsafe {
+            libobs::obs_service_can_try_to_connect(service_ptr)
+        })
+    }
+
+    /// Lets the service adjust encoder settings to its limits,
+    /// for example the maximum bitrate of a streaming platform.
+    pub fn apply_encoder_settings(
+        &self,
+        video_settings: Option<&mut ObsData>,
+        audio_settings: Option<&mut ObsData>,
+    ) -> Result<(), ObsError> {
+        let video_ptr = video_settings
+            .map(|x| x.as_ptr())
+            .unwrap_or(Sendable(ptr::null_mut()));
+        let audio_ptr = audio_settings
+            .map(|x| x.as_ptr())
+            .unwrap_or(Sendable(ptr::null_mut()));
+
+        let service_ptr = self.service.clone();
+        run_with_obs!(
+            self.runtime,
+            (service_ptr, video_ptr, audio_ptr),
+            move || unsafe {
+                libobs::obs_service_apply_encoder_settings(service_ptr, video_ptr, audio_ptr);
+            }
+        )
+    }
+}
+
+impl ObsUpdatable for ObsServiceRef {
+    fn runtime(&self) -> ObsRuntime {
+        self.runtime.clone()
+    }
+
+    fn update_raw(&mut self, data: ObsData) -> Result<(), ObsError> {
+        let data_ptr = data.as_ptr();
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr, data_ptr), move || unsafe {
+            libobs::obs_service_update(service_ptr, data_ptr);
+        })
+    }
+
+    fn reset_and_update_raw(&mut self, data: ObsData) -> Result<(), ObsError> {
+        let data_ptr = data.as_ptr();
+        let service_ptr = self.service.clone();
+        run_with_obs!(self.runtime, (service_ptr, data_ptr), move || unsafe {
+            // There is no reset function for services, so the current settings
+            // are cleared before the new ones are applied
+            let settings = libobs::obs_service_get_settings(service_ptr);
+            libobs::obs_data_clear(settings);
+            libobs::obs_data_release(settings);
+
+            libobs::obs_service_update(service_ptr, data_ptr);
+        })
+    }
+
+    fn get_settings(&self) -> Result<ImmutableObsData, ObsError> {
+        let service_ptr = self.service.clone();
+        let res = run_with_obs!(self.runtime, (service_ptr), move || unsafe {
+            Sendable(libobs::obs_service_get_settings(service_ptr))
+        })?;
+
+        Ok(ImmutableObsData::from_raw(res, self.runtime.clone()))
+    }
+}
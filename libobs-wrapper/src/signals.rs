@@ -223,6 +223,12 @@ macro_rules! impl_signal_manager {
                 let _ = senders.send(res);
             })*
 
+            lazy_static::lazy_static! {
+                /// Number of managers per pointer. The first one connects the handlers, the last one disconnects them.
+                static ref [<$name:snake:upper _MANAGERS>]: std::sync::Mutex<std::collections::HashMap<$crate::unsafe_send::SendableComp<$ptr>, usize>> = std::sync::Mutex::new(std::collections::HashMap::new());
+            }
+
+            /// Any number of managers can exist for the same pointer, they share its senders.
             #[derive(Debug)]
             pub struct $name {
                 pointer: $crate::unsafe_send::SendableComp<$ptr>,
@@ -231,9 +237,83 @@ macro_rules! impl_signal_manager {
 
             impl $name {
                 pub(crate) fn new(ptr: &Sendable<$ptr>, runtime: $crate::runtime::ObsRuntime) -> Result<Self, $crate::utils::ObsError> {
-                    use $crate::{utils::ObsString, unsafe_send::SendableComp};
-                    let pointer =  SendableComp(ptr.0);
+                    let pointer = $crate::unsafe_send::SendableComp(ptr.0);
+                    $crate::run_with_obs!(runtime, (pointer), move || unsafe {
+                        Self::acquire(pointer)
+                    })??;
+
+                    Ok(Self {
+                        pointer,
+                        runtime
+                    })
+                }
+
+                /// Same as `new`, but awaits connecting the handlers on the OBS thread.
+                #[cfg(feature = "async")]
+                pub(crate) async fn new_async(ptr: &Sendable<$ptr>, runtime: $crate::runtime::ObsRuntime) -> Result<Self, $crate::utils::ObsError> {
+                    let pointer = $crate::unsafe_send::SendableComp(ptr.0);
+                    $crate::run_with_obs_async!(runtime, (pointer), move || unsafe {
+                        Self::acquire(pointer)
+                    })??;
+
+                    Ok(Self {
+                        pointer,
+                        runtime
+                    })
+                }
+
+                /// Counts a new manager of the pointer and connects the handlers for the first one.
+                /// Must be called on the OBS thread, so it is ordered with `release`.
+                unsafe fn acquire(pointer: $ptr) -> Result<(), $crate::utils::ObsError> {
+                    let mut managers = [<$name:snake:upper _MANAGERS>].lock()
+                        .map_err(|_| $crate::utils::ObsError::LockError("Failed to acquire lock for signal managers".to_string()))?;
+
+                    let count = managers.entry($crate::unsafe_send::SendableComp(pointer)).or_insert(0);
+                    if *count == 0 {
+                        Self::register_senders(pointer)?;
+                        Self::connect_handlers(pointer);
+                    }
+
+                    *count += 1;
+                    Ok(())
+                }
+
+                /// Disconnects the handlers and removes the senders once the last manager of the pointer is dropped.
+                /// Must be called on the OBS thread.
+                unsafe fn release(pointer: $ptr) -> Result<(), $crate::utils::ObsError> {
+                    let key = $crate::unsafe_send::SendableComp(pointer);
+                    let mut managers = [<$name:snake:upper _MANAGERS>].lock()
+                        .map_err(|_| $crate::utils::ObsError::LockError("Failed to acquire lock for signal managers".to_string()))?;
+
+                    if let Some(count) = managers.get_mut(&key) {
+                        if *count > 1 {
+                            *count -= 1;
+                            return Ok(());
+                        }
+                    }
+
+                    managers.remove(&key);
 
+                    #[allow(unused_variables)]
+                    let handler = ($handler_getter)(pointer);
+                    $(
+                        let signal = $crate::utils::ObsString::new($signal_name);
+                        libobs::signal_handler_disconnect(
+                            handler,
+                            signal.as_ptr().0,
+                            Some([< $signal_name:snake _handler>]),
+                            pointer as *mut std::ffi::c_void,
+                        );
+
+                        [<$signal_name:snake:upper _SENDERS>].write()
+                            .map_err(|_| $crate::utils::ObsError::LockError("Failed to acquire write lock for signal senders".to_string()))?
+                            .remove(&key);
+                    )*
+
+                    Ok(())
+                }
+
+                fn register_senders(pointer: $ptr) -> Result<(), $crate::utils::ObsError> {
                     $(
                         let senders = [<$signal_name:snake:upper _SENDERS>].clone();
                         let senders = senders.write();
@@ -243,28 +323,24 @@ macro_rules! impl_signal_manager {
 
                         let (tx, [<_ $signal_name:snake _rx>]) = tokio::sync::broadcast::channel(16);
                         let mut senders = senders.unwrap();
-                        senders.insert(pointer.clone(), tx);
+                        senders.insert($crate::unsafe_send::SendableComp(pointer), tx);
                     )*
 
-                    $crate::run_with_obs!(runtime, (pointer), move || {
-                            let handler = ($handler_getter)(pointer);
-                            $(
-                                let signal = ObsString::new($signal_name);
-                                unsafe {
-                                    libobs::signal_handler_connect(
-                                        handler,
-                                        signal.as_ptr().0,
-                                        Some([< $signal_name:snake _handler>]),
-                                        pointer as *mut std::ffi::c_void,
-                                    );
-                                };
-                            )*
-                    })?;
+                    Ok(())
+                }
 
-                    Ok(Self {
-                        pointer,
-                        runtime
-                    })
+                /// Must be called on the OBS thread
+                unsafe fn connect_handlers(pointer: $ptr) {
+                    let handler = ($handler_getter)(pointer);
+                    $(
+                        let signal = $crate::utils::ObsString::new($signal_name);
+                        libobs::signal_handler_connect(
+                            handler,
+                            signal.as_ptr().0,
+                            Some([< $signal_name:snake _handler>]),
+                            pointer as *mut std::ffi::c_void,
+                        );
+                    )*
                 }
 
                 $(
@@ -289,48 +365,21 @@ macro_rules! impl_signal_manager {
                 fn drop(&mut self) {
                     log::trace!("Dropping signal manager {}...", stringify!($name));
 
-                    #[allow(unused_variables)]
                     let ptr = self.pointer.clone();
-                    #[allow(unused_variables)]
                     let runtime = self.runtime.clone();
 
                     //TODO make this non blocking
-                    let future = $crate::run_with_obs!(runtime, (ptr), move || {
-                        #[allow(unused_variables)]
-                        let handler = ($handler_getter)(ptr);
-                        $(
-                            let signal = $crate::utils::ObsString::new($signal_name);
-                            unsafe {
-                                libobs::signal_handler_disconnect(
-                                    handler,
-                                    signal.as_ptr().0,
-                                    Some([< $signal_name:snake _handler>]),
-                                    ptr as *mut std::ffi::c_void,
-                                );
-                            }
-                        )*
+                    let r = $crate::run_with_obs!(runtime, (ptr), move || unsafe {
+                        Self::release(ptr)
                     });
 
-                    let r = {
-                        $(
-                            let handlers = [<$signal_name:snake:upper _SENDERS>].write();
-                            if handlers.is_err() {
-                                log::warn!("Failed to acquire write lock for signal {} senders during drop", stringify!($signal_name));
-                                return;
-                            }
-
-                            let mut handlers = handlers.unwrap();
-                            handlers.remove(&self.pointer);
-                        )*
-
-                        future
-                    };
-
                     if std::thread::panicking() {
                         return;
                     }
 
-                    r.unwrap();
+                    if let Err(e) = r.unwrap() {
+                        log::warn!("Failed to release signal manager {}: {:?}", stringify!($name), e);
+                    }
                 }
             }
         }
@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use crate::{
+    enums::{ObsMonitoringType, OsEnumType},
+    macros::enum_from_number,
+    run_with_obs,
+    utils::ObsError,
+};
+
+use super::ObsSourceRef;
+
+/// Bitmask with every audio mixer (track) enabled.
+pub const ALL_AUDIO_MIXERS: u32 = (1 << libobs::MAX_AUDIO_MIXES) - 1;
+
+impl ObsSourceRef {
+    /// Sets the volume of this source as a linear multiplier, where
+    /// `1.0` is the original volume and `0.0` is silent.
+    pub fn set_volume(&self, volume: f32) -> Result<(), ObsError> {
+        if !volume.is_finite() || volume < 0.0 {
+            return Err(ObsError::InvalidOperation(format!(
+                "Volume must be a positive number, got {}",
+                volume
+            )));
+        }
+
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_volume(source_ptr, volume);
+        })
+    }
+
+    /// Returns the volume of this source as a linear multiplier.
+    pub fn volume(&self) -> Result<f32, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_volume(source_ptr)
+        })
+    }
+
+    /// Sets the volume of this source in decibels. `0.0` is the original
+    /// volume and `f32::NEG_INFINITY` is silent.
+    pub fn set_volume_db(&self, db: f32) -> Result<(), ObsError> {
+        let mul = unsafe { libobs::obs_db_to_mul(db) };
+        self.set_volume(mul)
+    }
+
+    /// Returns the volume of this source in decibels.
+    pub fn volume_db(&self) -> Result<f32, ObsError> {
+        let mul = self.volume()?;
+        Ok(unsafe { libobs::obs_mul_to_db(mul) })
+    }
+
+    pub fn set_muted(&self, muted: bool) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_muted(source_ptr, muted);
+        })
+    }
+
+    pub fn is_muted(&self) -> Result<bool, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_muted(source_ptr)
+        })
+    }
+
+    /// Sets the stereo balance of this source, where `0.0` is fully left,
+    /// `0.5` is centered and `1.0` is fully right.
+    pub fn set_balance(&self, balance: f32) -> Result<(), ObsError> {
+        if !(0.0..=1.0).contains(&balance) {
+            return Err(ObsError::InvalidOperation(format!(
+                "Balance must be between 0.0 and 1.0, got {}",
+                balance
+            )));
+        }
+
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_balance_value(source_ptr, balance);
+        })
+    }
+
+    pub fn balance(&self) -> Result<f32, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_balance_value(source_ptr)
+        })
+    }
+
+    /// Sets the audio sync offset of this source in nanoseconds.
+    /// Negative values make the audio play earlier.
+    pub fn set_sync_offset(&self, offset_ns: i64) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_sync_offset(source_ptr, offset_ns);
+        })
+    }
+
+    /// Returns the audio sync offset of this source in nanoseconds.
+    pub fn sync_offset(&self) -> Result<i64, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_sync_offset(source_ptr)
+        })
+    }
+
+    /// Sets the audio mixers (tracks) this source is routed to.
+    /// Bit `n` of the mask enables mixer `n`, see `ALL_AUDIO_MIXERS`.
+    pub fn set_audio_mixers(&self, mixers: u32) -> Result<(), ObsError> {
+        if mixers & !ALL_AUDIO_MIXERS != 0 {
+            return Err(ObsError::InvalidOperation(format!(
+                "Audio mixer mask {:#b} contains mixers above {}",
+                mixers,
+                libobs::MAX_AUDIO_MIXES - 1
+            )));
+        }
+
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_audio_mixers(source_ptr, mixers);
+        })
+    }
+
+    /// Returns the bitmask of audio mixers (tracks) this source is routed to.
+    pub fn audio_mixers(&self) -> Result<u32, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_audio_mixers(source_ptr)
+        })
+    }
+
+    pub fn set_monitoring_type(&self, monitoring_type: ObsMonitoringType) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_monitoring_type(source_ptr, monitoring_type as OsEnumType);
+        })
+    }
+
+    pub fn monitoring_type(&self) -> Result<ObsMonitoringType, ObsError> {
+        let source_ptr = self.source.clone();
+        let monitoring_type = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_monitoring_type(source_ptr)
+        })?;
+
+        enum_from_number!(ObsMonitoringType, monitoring_type).ok_or_else(|| {
+            ObsError::EnumConversionError(format!("Invalid monitoring type {}", monitoring_type))
+        })
+    }
+
+    /// Enables push-to-talk. While enabled, the source is muted
+    /// unless its push-to-talk hotkey is held down.
+    pub fn set_push_to_talk(&self, enabled: bool) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_enable_push_to_talk(source_ptr, enabled);
+        })
+    }
+
+    pub fn push_to_talk_enabled(&self) -> Result<bool, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_push_to_talk_enabled(source_ptr)
+        })
+    }
+
+    /// Sets how long the source stays unmuted after the push-to-talk hotkey was released.
+    pub fn set_push_to_talk_delay(&self, delay: Duration) -> Result<(), ObsError> {
+        let delay = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_push_to_talk_delay(source_ptr, delay);
+        })
+    }
+
+    pub fn push_to_talk_delay(&self) -> Result<Duration, ObsError> {
+        let source_ptr = self.source.clone();
+        let delay = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_push_to_talk_delay(source_ptr)
+        })?;
+
+        Ok(Duration::from_millis(delay))
+    }
+
+    /// Enables push-to-mute. While enabled, the source is muted
+    /// as long as its push-to-mute hotkey is held down.
+    pub fn set_push_to_mute(&self, enabled: bool) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_enable_push_to_mute(source_ptr, enabled);
+        })
+    }
+
+    pub fn push_to_mute_enabled(&self) -> Result<bool, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_push_to_mute_enabled(source_ptr)
+        })
+    }
+
+    /// Sets how long the source stays muted after the push-to-mute hotkey was released.
+    pub fn set_push_to_mute_delay(&self, delay: Duration) -> Result<(), ObsError> {
+        let delay = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_set_push_to_mute_delay(source_ptr, delay);
+        })
+    }
+
+    pub fn push_to_mute_delay(&self) -> Result<Duration, ObsError> {
+        let source_ptr = self.source.clone();
+        let delay = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_push_to_mute_delay(source_ptr)
+        })?;
+
+        Ok(Duration::from_millis(delay))
+    }
+}
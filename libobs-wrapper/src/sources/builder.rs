@@ -8,6 +8,7 @@ pub trait ObsSourceBuilder: ObsObjectBuilder {
         Self: Sized,
     {
         let s = self.build()?;
-        scene.add_source(s)
+        let item = scene.add_source(s)?;
+        Ok(item.source().clone())
     }
 }
@@ -0,0 +1,397 @@
+//! Sources that are implemented in Rust
+//!
+//! Implement `ObsCustomSource` and register the type once with
+//! `ObsContext::register_source`. Afterwards the source can be created by
+//! its id like any source of a C plugin, for example with a builder that is
+//! generated by `obs_object_builder` and implements `ObsSourceBuilder`.
+//!
+//! The callbacks are called by libobs on its own threads (e.g. the graphics
+//! thread for `video_render`), which is why every instance is kept behind a
+//! mutex and why the callbacks must not call into the OBS runtime.
+//! Panics in callbacks are logged instead of unwinding into libobs.
+mod properties;
+mod settings;
+
+pub use properties::*;
+pub use settings::*;
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, c_void, CStr, CString},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+};
+
+use libobs::{
+    gs_effect_t, obs_data_t, obs_properties_t, obs_property_t, obs_source_info, obs_source_t,
+};
+
+use crate::{
+    context::ObsContext,
+    run_with_obs,
+    unsafe_send::Sendable,
+    utils::{self, ObsError, ObsString},
+};
+
+/// A source type that is implemented in Rust.
+///
+/// Only `create` is required, the remaining callbacks default to doing nothing.
+/// Sources with `OBS_SOURCE_VIDEO` in their output flags that are not async
+/// should implement `video_render`, `get_width` and `get_height`.
+pub trait ObsCustomSource: Send + Sized + 'static {
+    /// Unique id of the source type, used when creating a source
+    const ID: &'static str;
+    /// Name of the source type that is shown to the user
+    const NAME: &'static str;
+    /// Output flags of the source type, e.g. `OBS_SOURCE_VIDEO | OBS_SOURCE_CUSTOM_DRAW`
+    const OUTPUT_FLAGS: u32 = libobs::OBS_SOURCE_VIDEO;
+
+    /// Creates a new instance of the source with its initial settings.
+    fn create(settings: &ObsSourceSettings, context: ObsCustomSourceContext) -> Self;
+
+    /// Called when the settings of the source were changed.
+    fn update(&mut self, _settings: &ObsSourceSettings) {}
+
+    /// Called on the graphics thread whenever the source is drawn.
+    fn video_render(&mut self, _render: &ObsSourceRenderContext) {}
+
+    /// Called once per rendered frame with the seconds since the last frame.
+    fn video_tick(&mut self, _seconds: f32) {}
+
+    /// The size is read after `create` and every callback that takes `&mut self`,
+    /// libobs only sees the cached value.
+    fn get_width(&self) -> u32 {
+        0
+    }
+
+    fn get_height(&self) -> u32 {
+        0
+    }
+
+    /// Describes the properties of the source type. This is not tied to an
+    /// instance, libobs also asks for the properties of a source type by its id.
+    fn get_properties(_properties: &mut ObsPropertiesDefinition) {}
+
+    /// Called when a property that was added with
+    /// `ObsPropertyDefinition::set_modified_callback` was changed.
+    /// Returns whether the properties have to be refreshed.
+    fn property_modified(_name: &str, _settings: &ObsSourceSettings) -> bool {
+        false
+    }
+
+    /// Called when the button property `name` was clicked, see
+    /// `ObsPropertiesDefinition::add_button`. Returns whether the properties
+    /// have to be refreshed.
+    fn button_clicked(&mut self, _name: &str) -> bool {
+        false
+    }
+
+    /// Sets the default values of the settings.
+    fn get_defaults(_settings: &mut ObsSourceSettings) {}
+}
+
+/// Gives a custom source access to the libobs source it belongs to.
+#[derive(Debug, Clone)]
+pub struct ObsCustomSourceContext {
+    source: Sendable<*mut obs_source_t>,
+}
+
+impl ObsCustomSourceContext {
+    /// Pointer to the source this instance belongs to. It is valid until the
+    /// instance is dropped, but it is not reference counted.
+    pub fn as_ptr(&self) -> *mut obs_source_t {
+        self.source.0
+    }
+}
+
+/// Passed to `ObsCustomSource::video_render`. Rendering happens on the
+/// graphics thread with the graphics context already entered.
+#[derive(Debug)]
+pub struct ObsSourceRenderContext {
+    effect: *mut gs_effect_t,
+}
+
+impl ObsSourceRenderContext {
+    /// The effect the source should be drawn with. It is null
+    /// if the source has the `OBS_SOURCE_CUSTOM_DRAW` flag.
+    pub fn effect(&self) -> *mut gs_effect_t {
+        self.effect
+    }
+
+    /// Fills an area of the given size with a solid ARGB color.
+    pub fn draw_solid_color(&self, argb: u32, width: u32, height: u32) {
+        let color_name = ObsString::new("color");
+        let technique_name = ObsString::new("Solid");
+
+        unsafe {
+            let solid = libobs::obs_get_base_effect(libobs::obs_base_effect_OBS_EFFECT_SOLID);
+            let color = libobs::gs_effect_get_param_by_name(solid, color_name.as_ptr().0);
+            let technique = libobs::gs_effect_get_technique(solid, technique_name.as_ptr().0);
+
+            libobs::gs_effect_set_color(color, argb);
+            libobs::gs_technique_begin(technique);
+            libobs::gs_technique_begin_pass(technique, 0);
+            libobs::gs_draw_sprite(std::ptr::null_mut(), 0, width, height);
+            libobs::gs_technique_end_pass(technique);
+            libobs::gs_technique_end(technique);
+        }
+    }
+}
+
+/// The data pointer libobs keeps for every instance of a custom source.
+struct CustomSourceInstance<T> {
+    instance: Mutex<T>,
+    /// Size of the source after the last callback that could change it, so libobs
+    /// can ask for it without locking the instance, even while a callback runs
+    width: AtomicU32,
+    height: AtomicU32,
+}
+
+impl<T: ObsCustomSource> CustomSourceInstance<T> {
+    fn new(instance: T) -> Self {
+        let (width, height) = (instance.get_width(), instance.get_height());
+        Self {
+            instance: Mutex::new(instance),
+            width: AtomicU32::new(width),
+            height: AtomicU32::new(height),
+        }
+    }
+}
+
+thread_local! {
+    /// Instances that are currently running a callback on this thread
+    static ENTERED_INSTANCES: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Runs `f` and logs a panic instead of unwinding into libobs.
+fn catch_panic<R>(id: &str, callback: &str, f: impl FnOnce() -> R) -> Option<R> {
+    utils::catch_panic(
+        format_args!("Callback {} of custom source {}", callback, id),
+        f,
+    )
+}
+
+/// Runs `f` with the instance behind the `data` pointer of libobs.
+///
+/// Callbacks that libobs calls while the same instance is already running a
+/// callback on this thread (e.g. `update` calling `obs_source_update` on itself)
+/// are skipped, as the instance is locked until the outer callback returns.
+unsafe fn with_instance<T: ObsCustomSource, R>(
+    data: *mut c_void,
+    callback: &str,
+    f: impl FnOnce(&mut T) -> R,
+) -> Option<R> {
+    if data.is_null() {
+        return None;
+    }
+
+    let key = data as usize;
+    let reentered = ENTERED_INSTANCES.with(|entered| {
+        let mut entered = entered.borrow_mut();
+        if entered.contains(&key) {
+            return true;
+        }
+
+        entered.push(key);
+        false
+    });
+
+    if reentered {
+        log::warn!(
+            "Skipped callback {} of custom source {}, it was called while another callback of the same source was running",
+            callback,
+            T::ID
+        );
+        return None;
+    }
+
+    let data = &*(data as *const CustomSourceInstance<T>);
+    let result = {
+        let mut instance = data.instance.lock().unwrap_or_else(|e| e.into_inner());
+        catch_panic(T::ID, callback, || {
+            let result = f(&mut instance);
+            data.width.store(instance.get_width(), Ordering::Relaxed);
+            data.height.store(instance.get_height(), Ordering::Relaxed);
+            result
+        })
+    };
+
+    ENTERED_INSTANCES.with(|entered| entered.borrow_mut().retain(|k| *k != key));
+    result
+}
+
+unsafe extern "C" fn source_get_name(type_data: *mut c_void) -> *const c_char {
+    type_data as *const c_char
+}
+
+unsafe extern "C" fn source_create<T: ObsCustomSource>(
+    settings: *mut obs_data_t,
+    source: *mut obs_source_t,
+) -> *mut c_void {
+    let settings = ObsSourceSettings::from_raw(settings);
+    let context = ObsCustomSourceContext {
+        source: Sendable(source),
+    };
+
+    catch_panic(T::ID, "create", || {
+        let instance = Box::new(CustomSourceInstance::new(T::create(&settings, context)));
+        Box::into_raw(instance) as *mut c_void
+    })
+    .unwrap_or(std::ptr::null_mut())
+}
+
+unsafe extern "C" fn source_destroy<T: ObsCustomSource>(data: *mut c_void) {
+    if !data.is_null() {
+        catch_panic(T::ID, "destroy", || {
+            drop(Box::from_raw(data as *mut CustomSourceInstance<T>))
+        });
+    }
+}
+
+unsafe extern "C" fn source_update<T: ObsCustomSource>(
+    data: *mut c_void,
+    settings: *mut obs_data_t,
+) {
+    let settings = ObsSourceSettings::from_raw(settings);
+    with_instance::<T, _>(data, "update", |s| s.update(&settings));
+}
+
+unsafe extern "C" fn source_video_render<T: ObsCustomSource>(
+    data: *mut c_void,
+    effect: *mut gs_effect_t,
+) {
+    let render = ObsSourceRenderContext { effect };
+    with_instance::<T, _>(data, "video_render", |s| s.video_render(&render));
+}
+
+unsafe extern "C" fn source_video_tick<T: ObsCustomSource>(data: *mut c_void, seconds: f32) {
+    with_instance::<T, _>(data, "video_tick", |s| s.video_tick(seconds));
+}
+
+unsafe extern "C" fn source_get_width<T: ObsCustomSource>(data: *mut c_void) -> u32 {
+    if data.is_null() {
+        return 0;
+    }
+
+    let data = &*(data as *const CustomSourceInstance<T>);
+    data.width.load(Ordering::Relaxed)
+}
+
+unsafe extern "C" fn source_get_height<T: ObsCustomSource>(data: *mut c_void) -> u32 {
+    if data.is_null() {
+        return 0;
+    }
+
+    let data = &*(data as *const CustomSourceInstance<T>);
+    data.height.load(Ordering::Relaxed)
+}
+
+unsafe extern "C" fn source_get_properties<T: ObsCustomSource>(
+    _data: *mut c_void,
+) -> *mut obs_properties_t {
+    let properties = catch_panic(T::ID, "get_properties", || {
+        let mut properties = ObsPropertiesDefinition::new();
+        T::get_properties(&mut properties);
+        properties
+    });
+
+    // An empty set of properties is shown if the definition panicked
+    properties
+        .unwrap_or_else(ObsPropertiesDefinition::new)
+        .into_raw()
+}
+
+/// Name of a property that is passed to a property callback.
+unsafe fn property_name(property: *mut obs_property_t) -> String {
+    let name = libobs::obs_property_name(property);
+    if name.is_null() {
+        return String::new();
+    }
+
+    CStr::from_ptr(name).to_string_lossy().to_string()
+}
+
+unsafe extern "C" fn source_property_modified<T: ObsCustomSource>(
+    _properties: *mut obs_properties_t,
+    property: *mut obs_property_t,
+    settings: *mut obs_data_t,
+) -> bool {
+    let name = property_name(property);
+    let settings = ObsSourceSettings::from_raw(settings);
+    catch_panic(T::ID, "property_modified", || {
+        T::property_modified(&name, &settings)
+    })
+    .unwrap_or(false)
+}
+
+/// libobs passes the data pointer of the source the button was clicked on
+unsafe extern "C" fn source_button_clicked<T: ObsCustomSource>(
+    _properties: *mut obs_properties_t,
+    property: *mut obs_property_t,
+    data: *mut c_void,
+) -> bool {
+    let name = property_name(property);
+    with_instance::<T, _>(data, "button_clicked", |s| s.button_clicked(&name)).unwrap_or(false)
+}
+
+unsafe extern "C" fn source_get_defaults<T: ObsCustomSource>(settings: *mut obs_data_t) {
+    let mut settings = ObsSourceSettings::from_raw(settings);
+    catch_panic(T::ID, "get_defaults", || T::get_defaults(&mut settings));
+}
+
+impl ObsContext {
+    /// Registers a source type that is implemented in Rust. After this,
+    /// sources with the id `T::ID` can be created like any other source.
+    ///
+    /// Source types can't be unregistered, they are available until libobs shuts down.
+    pub fn register_source<T: ObsCustomSource>(&self) -> Result<(), ObsError> {
+        if !self.register_source_if_missing::<T>()? {
+            return Err(ObsError::InvalidOperation(format!(
+                "A source type with the id {} is already registered",
+                T::ID
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Registers the source type unless a source type with the same id exists.
+    /// Returns whether the source type was registered.
+    pub(crate) fn register_source_if_missing<T: ObsCustomSource>(&self) -> Result<bool, ObsError> {
+        let id = CString::new(T::ID).map_err(|_| ObsError::StringConversionError)?;
+        let name = CString::new(T::NAME).map_err(|_| ObsError::StringConversionError)?;
+
+        run_with_obs!(self.runtime, move || unsafe {
+            if !libobs::obs_source_get_display_name(id.as_ptr()).is_null() {
+                return false;
+            }
+
+            let mut info: obs_source_info = std::mem::zeroed();
+            info.type_ = libobs::obs_source_type_OBS_SOURCE_TYPE_INPUT;
+            info.output_flags = T::OUTPUT_FLAGS;
+            info.get_name = Some(source_get_name);
+            info.create = Some(source_create::<T>);
+            info.destroy = Some(source_destroy::<T>);
+            info.update = Some(source_update::<T>);
+            info.get_properties = Some(source_get_properties::<T>);
+            info.get_defaults = Some(source_get_defaults::<T>);
+
+            // Async sources are drawn by libobs from the frames they output
+            if T::OUTPUT_FLAGS & libobs::OBS_SOURCE_ASYNC == 0 {
+                info.video_render = Some(source_video_render::<T>);
+                info.get_width = Some(source_get_width::<T>);
+                info.get_height = Some(source_get_height::<T>);
+            }
+            info.video_tick = Some(source_video_tick::<T>);
+
+            // libobs keeps these pointers for as long as the source type is registered
+            info.id = id.into_raw();
+            info.type_data = name.into_raw() as *mut c_void;
+
+            libobs::obs_register_source_s(&info, std::mem::size_of::<obs_source_info>());
+            true
+        })
+    }
+}
@@ -0,0 +1,243 @@
+use libobs::{obs_properties_t, obs_property_t};
+
+use crate::{
+    data::properties::{ObsComboFormat, ObsComboType, ObsPathType, ObsTextType},
+    enums::OsEnumType,
+    utils::ObsString,
+};
+
+use super::{source_button_clicked, source_property_modified, ObsCustomSource};
+
+/// Describes the properties of a custom source, which are shown
+/// to the user and returned by `ObsPropertyObject::get_properties`.
+///
+/// Every `add_*` function returns the created property, which can be
+/// used to set a long description or to add items to a list.
+#[derive(Debug)]
+pub struct ObsPropertiesDefinition {
+    properties: *mut obs_properties_t,
+}
+
+impl ObsPropertiesDefinition {
+    pub(crate) fn new() -> Self {
+        Self {
+            properties: unsafe { libobs::obs_properties_create() },
+        }
+    }
+
+    /// Hands the properties over to libobs, which is responsible for destroying them.
+    pub(crate) fn into_raw(self) -> *mut obs_properties_t {
+        self.properties
+    }
+
+    pub fn add_bool(&mut self, name: &str, description: &str) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_bool(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+            )
+        })
+    }
+
+    pub fn add_int(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: i32,
+        max: i32,
+        step: i32,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_int(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                min,
+                max,
+                step,
+            )
+        })
+    }
+
+    pub fn add_int_slider(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: i32,
+        max: i32,
+        step: i32,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_int_slider(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                min,
+                max,
+                step,
+            )
+        })
+    }
+
+    pub fn add_float(
+        &mut self,
+        name: &str,
+        description: &str,
+        min: f64,
+        max: f64,
+        step: f64,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_float(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                min,
+                max,
+                step,
+            )
+        })
+    }
+
+    pub fn add_text(
+        &mut self,
+        name: &str,
+        description: &str,
+        text_type: ObsTextType,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_text(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                text_type as OsEnumType,
+            )
+        })
+    }
+
+    /// Adds a path property. `filter` uses the Qt filter format,
+    /// e.g. `"Images (*.png *.jpg);;All files (*.*)"`.
+    pub fn add_path(
+        &mut self,
+        name: &str,
+        description: &str,
+        path_type: ObsPathType,
+        filter: Option<&str>,
+        default_path: Option<&str>,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        let filter = filter.map(ObsString::new);
+        let default_path = default_path.map(ObsString::new);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_path(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                path_type as OsEnumType,
+                filter.as_ref().map_or(std::ptr::null(), |f| f.as_ptr().0),
+                default_path
+                    .as_ref()
+                    .map_or(std::ptr::null(), |p| p.as_ptr().0),
+            )
+        })
+    }
+
+    /// Adds a list property, items are added with `ObsPropertyDefinition::add_list_string`
+    /// or `ObsPropertyDefinition::add_list_int` depending on the `format`.
+    pub fn add_list(
+        &mut self,
+        name: &str,
+        description: &str,
+        combo_type: ObsComboType,
+        format: ObsComboFormat,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_list(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+                combo_type as OsEnumType,
+                format as OsEnumType,
+            )
+        })
+    }
+
+    /// Adds a button that calls `ObsCustomSource::button_clicked` of the
+    /// source it was clicked on.
+    pub fn add_button<T: ObsCustomSource>(
+        &mut self,
+        name: &str,
+        text: &str,
+    ) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let text = ObsString::new(text);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_button(
+                self.properties,
+                name.as_ptr().0,
+                text.as_ptr().0,
+                Some(source_button_clicked::<T>),
+            )
+        })
+    }
+
+    pub fn add_color(&mut self, name: &str, description: &str) -> ObsPropertyDefinition {
+        let name = ObsString::new(name);
+        let description = ObsString::new(description);
+        ObsPropertyDefinition(unsafe {
+            libobs::obs_properties_add_color(
+                self.properties,
+                name.as_ptr().0,
+                description.as_ptr().0,
+            )
+        })
+    }
+}
+
+/// A single property that was added to an `ObsPropertiesDefinition`.
+#[derive(Debug)]
+pub struct ObsPropertyDefinition(*mut obs_property_t);
+
+impl ObsPropertyDefinition {
+    /// Sets the description that is shown as a tooltip.
+    pub fn set_long_description(&self, long_description: &str) -> &Self {
+        let long_description = ObsString::new(long_description);
+        unsafe { libobs::obs_property_set_long_description(self.0, long_description.as_ptr().0) };
+        self
+    }
+
+    /// Calls `ObsCustomSource::property_modified` whenever this property is changed.
+    pub fn set_modified_callback<T: ObsCustomSource>(&self) -> &Self {
+        unsafe {
+            libobs::obs_property_set_modified_callback(self.0, Some(source_property_modified::<T>))
+        };
+        self
+    }
+
+    pub fn add_list_string(&self, name: &str, value: &str) -> &Self {
+        let name = ObsString::new(name);
+        let value = ObsString::new(value);
+        unsafe { libobs::obs_property_list_add_string(self.0, name.as_ptr().0, value.as_ptr().0) };
+        self
+    }
+
+    pub fn add_list_int(&self, name: &str, value: i64) -> &Self {
+        let name = ObsString::new(name);
+        unsafe { libobs::obs_property_list_add_int(self.0, name.as_ptr().0, value) };
+        self
+    }
+}
@@ -0,0 +1,85 @@
+use std::{ffi::CStr, marker::PhantomData};
+
+use libobs::obs_data_t;
+
+use crate::utils::ObsString;
+
+/// Settings of a custom source, borrowed from libobs for the duration of a
+/// callback.
+///
+/// Unlike `ObsData`, this does not go through the OBS runtime, because the
+/// callbacks of a custom source are already called by libobs itself and
+/// dispatching to the OBS thread from there would deadlock.
+#[derive(Debug)]
+pub struct ObsSourceSettings<'a> {
+    data: *mut obs_data_t,
+    _marker: PhantomData<&'a mut obs_data_t>,
+}
+
+impl ObsSourceSettings<'_> {
+    /// # Safety
+    /// `data` must be a valid `obs_data_t` that outlives the returned struct.
+    pub(crate) unsafe fn from_raw(data: *mut obs_data_t) -> Self {
+        Self {
+            data,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut obs_data_t {
+        self.data
+    }
+
+    /// Whether the user (or the code that created the source) set a value for this key.
+    pub fn has_user_value(&self, key: &str) -> bool {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_has_user_value(self.data, key.as_ptr().0) }
+    }
+
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        let key = ObsString::new(key);
+        let value = unsafe { libobs::obs_data_get_string(self.data, key.as_ptr().0) };
+        if value.is_null() {
+            return None;
+        }
+
+        let value = unsafe { CStr::from_ptr(value) };
+        Some(value.to_string_lossy().to_string())
+    }
+
+    pub fn get_int(&self, key: &str) -> i64 {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_get_int(self.data, key.as_ptr().0) }
+    }
+
+    pub fn get_double(&self, key: &str) -> f64 {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_get_double(self.data, key.as_ptr().0) }
+    }
+
+    pub fn get_bool(&self, key: &str) -> bool {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_get_bool(self.data, key.as_ptr().0) }
+    }
+
+    pub fn set_default_string(&mut self, key: &str, value: &str) {
+        let key = ObsString::new(key);
+        let value = ObsString::new(value);
+        unsafe { libobs::obs_data_set_default_string(self.data, key.as_ptr().0, value.as_ptr().0) }
+    }
+
+    pub fn set_default_int(&mut self, key: &str, value: i64) {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_set_default_int(self.data, key.as_ptr().0, value) }
+    }
+
+    pub fn set_default_double(&mut self, key: &str, value: f64) {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_set_default_double(self.data, key.as_ptr().0, value) }
+    }
+
+    pub fn set_default_bool(&mut self, key: &str, value: bool) {
+        let key = ObsString::new(key);
+        unsafe { libobs::obs_data_set_default_bool(self.data, key.as_ptr().0, value) }
+    }
+}
@@ -0,0 +1,146 @@
+//! Lookup of sources that already exist in libobs
+//!
+//! Sources are not necessarily created through this wrapper, they may have
+//! been loaded from a scene collection or created by a plugin. These
+//! functions query libobs directly and wrap whatever is found. If the source
+//! was created through the wrapper and added to a scene, the existing
+//! `ObsSourceRef` is returned instead, so its scene items stay tracked.
+//! Any other source is wrapped again, which is fine as all wrappers of a
+//! source share its signals.
+use std::ffi::{c_void, CStr};
+
+use libobs::obs_source_t;
+
+use crate::{
+    context::ObsContext,
+    run_with_obs,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+use super::ObsSourceRef;
+
+unsafe extern "C" fn enum_sources_callback(param: *mut c_void, source: *mut obs_source_t) -> bool {
+    let sources = &mut *(param as *mut Vec<Sendable<*mut obs_source_t>>);
+    sources.push(Sendable(libobs::obs_source_get_ref(source)));
+
+    true
+}
+
+impl ObsContext {
+    /// Returns all public input sources that currently exist in libobs,
+    /// regardless of whether they were created by this wrapper.
+    /// Scenes and transitions are not included.
+    pub fn sources(&self) -> Result<Vec<ObsSourceRef>, ObsError> {
+        let sources = run_with_obs!(self.runtime, move || unsafe {
+            let mut sources: Vec<Sendable<*mut obs_source_t>> = Vec::new();
+            libobs::obs_enum_sources(
+                Some(enum_sources_callback),
+                &mut sources as *mut _ as *mut c_void,
+            );
+
+            sources
+        })?;
+
+        // Adopt all sources first, so every reference is released even if one fails
+        let adopted = sources
+            .into_iter()
+            .map(|source| self.adopt_source(source))
+            .collect::<Vec<_>>();
+
+        adopted.into_iter().collect()
+    }
+
+    /// Gets any public source, including scenes, by its name.
+    pub fn get_source_by_name(&self, name: &str) -> Result<Option<ObsSourceRef>, ObsError> {
+        let name = ObsString::new(name);
+        let name_ptr = name.as_ptr();
+        let source = run_with_obs!(self.runtime, (name_ptr), move || unsafe {
+            Sendable(libobs::obs_get_source_by_name(name_ptr))
+        })?;
+
+        if source.0.is_null() {
+            return Ok(None);
+        }
+
+        self.adopt_source(source).map(Some)
+    }
+
+    /// Gets any source, including scenes, by the UUID libobs assigned to it.
+    pub fn get_source_by_uuid(&self, uuid: &str) -> Result<Option<ObsSourceRef>, ObsError> {
+        let uuid = ObsString::new(uuid);
+        let uuid_ptr = uuid.as_ptr();
+        let source = run_with_obs!(self.runtime, (uuid_ptr), move || unsafe {
+            Sendable(libobs::obs_get_source_by_uuid(uuid_ptr))
+        })?;
+
+        if source.0.is_null() {
+            return Ok(None);
+        }
+
+        self.adopt_source(source).map(Some)
+    }
+
+    /// Takes ownership of one reference of the source. Returns the source
+    /// that is already tracked by a scene of this context if there is one.
+    fn adopt_source(&self, source: Sendable<*mut obs_source_t>) -> Result<ObsSourceRef, ObsError> {
+        let existing = self.find_tracked_source(source.0);
+        match existing {
+            Ok(Some(existing)) => {
+                run_with_obs!(self.runtime, (source), move || unsafe {
+                    libobs::obs_source_release(source);
+                })?;
+
+                Ok(existing)
+            }
+            Ok(None) => ObsSourceRef::from_raw(source, self.runtime.clone()),
+            Err(e) => {
+                run_with_obs!(self.runtime, (source), move || unsafe {
+                    libobs::obs_source_release(source);
+                })?;
+
+                Err(e)
+            }
+        }
+    }
+
+    fn find_tracked_source(
+        &self,
+        source: *mut obs_source_t,
+    ) -> Result<Option<ObsSourceRef>, ObsError> {
+        let scenes = self.scenes.read().map_err(|_| {
+            ObsError::LockError("Failed to acquire read lock on scenes".to_string())
+        })?;
+
+        for scene in scenes.iter() {
+            let found = scene
+                .sources
+                .read()
+                .map_err(|e| ObsError::LockError(format!("{:?}", e)))?
+                .iter()
+                .find(|s| s.source.0 == source)
+                .cloned();
+
+            if found.is_some() {
+                return Ok(found);
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl ObsSourceRef {
+    /// The UUID libobs assigned to this source.
+    pub fn uuid(&self) -> Result<String, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let uuid = libobs::obs_source_get_uuid(source_ptr);
+            if uuid.is_null() {
+                return String::new();
+            }
+
+            CStr::from_ptr(uuid).to_string_lossy().to_string()
+        })
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+use libobs::obs_fader_t;
+use tokio::sync::broadcast;
+
+use crate::{
+    enums::{ObsFaderType, OsEnumType},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::{Sendable, SendableComp},
+    utils::ObsError,
+};
+
+use super::ObsSourceRef;
+
+lazy_static! {
+    static ref FADER_SENDERS: RwLock<HashMap<SendableComp<*mut obs_fader_t>, broadcast::Sender<f32>>> =
+        RwLock::new(HashMap::new());
+}
+
+const LOG_OFFSET_DB: f32 = 6.0;
+const LOG_RANGE_DB: f32 = 96.0;
+/// `-log10(LOG_OFFSET_DB)`
+const LOG_OFFSET_VAL: f32 = -0.778_151_3;
+/// `-log10(LOG_RANGE_DB + LOG_OFFSET_DB)`
+const LOG_RANGE_VAL: f32 = -2.008_600_2;
+
+/// Converts a volume multiplier to decibels, same as `obs_mul_to_db`.
+pub fn mul_to_db(mul: f32) -> f32 {
+    if mul == 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        20.0 * mul.log10()
+    }
+}
+
+/// Converts decibels to a volume multiplier, same as `obs_db_to_mul`.
+pub fn db_to_mul(db: f32) -> f32 {
+    if db == f32::NEG_INFINITY {
+        0.0
+    } else {
+        10.0_f32.powf(db / 20.0)
+    }
+}
+
+impl ObsFaderType {
+    /// Maps a volume in decibels to a fader deflection between 0.0 and 1.0
+    /// using the curve of this fader type.
+    pub fn db_to_deflection(&self, db: f32) -> f32 {
+        match self {
+            ObsFaderType::Cubic => {
+                if db == 0.0 {
+                    1.0
+                } else if db == f32::NEG_INFINITY {
+                    0.0
+                } else {
+                    db_to_mul(db).cbrt()
+                }
+            }
+            ObsFaderType::IEC => {
+                if db == 0.0 {
+                    1.0
+                } else if db == f32::NEG_INFINITY {
+                    0.0
+                } else if db >= -9.0 {
+                    (db + 9.0) / 9.0 * 0.25 + 0.75
+                } else if db >= -20.0 {
+                    (db + 20.0) / 11.0 * 0.25 + 0.5
+                } else if db >= -30.0 {
+                    (db + 30.0) / 10.0 * 0.2 + 0.3
+                } else if db >= -40.0 {
+                    (db + 40.0) / 10.0 * 0.15 + 0.15
+                } else if db >= -50.0 {
+                    (db + 50.0) / 10.0 * 0.075 + 0.075
+                } else if db >= -60.0 {
+                    (db + 60.0) / 10.0 * 0.05 + 0.025
+                } else if db >= -114.0 {
+                    (db + 150.0) / 90.0 * 0.025
+                } else {
+                    0.0
+                }
+            }
+            ObsFaderType::Log => {
+                if db >= 0.0 {
+                    1.0
+                } else if db <= -LOG_RANGE_DB {
+                    0.0
+                } else {
+                    (-(-db + LOG_OFFSET_DB).log10() - LOG_RANGE_VAL)
+                        / (LOG_OFFSET_VAL - LOG_RANGE_VAL)
+                }
+            }
+        }
+    }
+
+    /// Maps a fader deflection between 0.0 and 1.0 to a volume in decibels
+    /// using the curve of this fader type.
+    pub fn deflection_to_db(&self, deflection: f32) -> f32 {
+        if deflection >= 1.0 {
+            return 0.0;
+        } else if deflection <= 0.0 {
+            return f32::NEG_INFINITY;
+        }
+
+        match self {
+            ObsFaderType::Cubic => mul_to_db(deflection * deflection * deflection),
+            ObsFaderType::IEC => {
+                let def = deflection;
+                if def >= 0.75 {
+                    (def - 1.0) / 0.25 * 9.0
+                } else if def >= 0.5 {
+                    (def - 0.75) / 0.25 * 11.0 - 9.0
+                } else if def >= 0.3 {
+                    (def - 0.5) / 0.2 * 10.0 - 20.0
+                } else if def >= 0.15 {
+                    (def - 0.3) / 0.15 * 10.0 - 30.0
+                } else if def >= 0.075 {
+                    (def - 0.15) / 0.075 * 10.0 - 40.0
+                } else if def >= 0.025 {
+                    (def - 0.075) / 0.05 * 10.0 - 50.0
+                } else if def >= 0.001 {
+                    (def - 0.025) / 0.025 * 90.0 - 60.0
+                } else {
+                    f32::NEG_INFINITY
+                }
+            }
+            ObsFaderType::Log => {
+                -(LOG_RANGE_DB + LOG_OFFSET_DB)
+                    * ((LOG_RANGE_DB + LOG_OFFSET_DB) / LOG_OFFSET_DB).powf(-deflection)
+                    + LOG_OFFSET_DB
+            }
+        }
+    }
+}
+
+unsafe extern "C" fn fader_changed(param: *mut c_void, db: f32) {
+    let fader = param as *mut obs_fader_t;
+    let senders = match FADER_SENDERS.read() {
+        Ok(s) => s,
+        Err(e) => {
+            log::warn!("Failed to acquire read lock for fader senders: {}", e);
+            return;
+        }
+    };
+
+    if let Some(sender) = senders.get(&SendableComp(fader)) {
+        let _ = sender.send(db);
+    }
+}
+
+/// A fader that controls the volume of a source using the curve of its
+/// `ObsFaderType`. When attached to a source, changes to the fader are
+/// applied to the source and changes of the source volume are reflected
+/// in the fader.
+#[derive(Debug, Clone)]
+pub struct ObsFader {
+    fader: Sendable<*mut obs_fader_t>,
+    fader_type: ObsFaderType,
+    _guard: Arc<_ObsFaderGuard>,
+    runtime: ObsRuntime,
+}
+
+impl ObsFader {
+    pub fn new(fader_type: ObsFaderType, runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let fader = run_with_obs!(runtime, move || unsafe {
+            Sendable(libobs::obs_fader_create(fader_type as OsEnumType))
+        })?;
+
+        if fader.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let guard = Arc::new(_ObsFaderGuard {
+            fader: fader.clone(),
+            runtime: runtime.clone(),
+        });
+
+        let (tx, _rx) = broadcast::channel(16);
+        FADER_SENDERS
+            .write()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire write lock on fader senders".to_string())
+            })?
+            .insert(SendableComp(fader.0), tx);
+
+        let fader_ptr = fader.clone();
+        run_with_obs!(runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_add_callback(
+                fader_ptr,
+                Some(fader_changed),
+                fader_ptr as *mut c_void,
+            );
+        })?;
+
+        Ok(Self {
+            fader,
+            fader_type,
+            _guard: guard,
+            runtime,
+        })
+    }
+
+    pub fn fader_type(&self) -> ObsFaderType {
+        self.fader_type
+    }
+
+    /// Attaches the fader to the given source. Only one source can be
+    /// attached at a time, a previously attached source is detached.
+    pub fn attach_source(&self, source: &ObsSourceRef) -> Result<(), ObsError> {
+        let fader_ptr = self.fader.clone();
+        let source_ptr = source.source.clone();
+        let attached = run_with_obs!(self.runtime, (fader_ptr, source_ptr), move || unsafe {
+            libobs::obs_fader_attach_source(fader_ptr, source_ptr)
+        })?;
+
+        if !attached {
+            return Err(ObsError::InvalidOperation(format!(
+                "Could not attach fader to source {}",
+                source.name()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn detach_source(&self) -> Result<(), ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_detach_source(fader_ptr);
+        })
+    }
+
+    /// Returns a receiver which gets the new volume in decibels whenever the fader changes.
+    pub fn on_changed(&self) -> Result<broadcast::Receiver<f32>, ObsError> {
+        let rx = FADER_SENDERS
+            .read()
+            .map_err(|_| {
+                ObsError::LockError("Failed to acquire read lock on fader senders".to_string())
+            })?
+            .get(&SendableComp(self.fader.0))
+            .ok_or(ObsError::NoSenderError)?
+            .subscribe();
+
+        Ok(rx)
+    }
+
+    /// Sets the fader to the given volume in decibels.
+    /// Returns `false` if the value was out of range and had to be clamped.
+    pub fn set_db(&self, db: f32) -> Result<bool, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_set_db(fader_ptr, db)
+        })
+    }
+
+    pub fn db(&self) -> Result<f32, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_get_db(fader_ptr)
+        })
+    }
+
+    /// Sets the position of the fader between 0.0 and 1.0.
+    /// Returns `false` if the value was out of range and had to be clamped.
+    pub fn set_deflection(&self, deflection: f32) -> Result<bool, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_set_deflection(fader_ptr, deflection)
+        })
+    }
+
+    pub fn deflection(&self) -> Result<f32, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_get_deflection(fader_ptr)
+        })
+    }
+
+    /// Sets the fader to the given linear volume multiplier.
+    /// Returns `false` if the value was out of range and had to be clamped.
+    pub fn set_mul(&self, mul: f32) -> Result<bool, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_set_mul(fader_ptr, mul)
+        })
+    }
+
+    pub fn mul(&self) -> Result<f32, ObsError> {
+        let fader_ptr = self.fader.clone();
+        run_with_obs!(self.runtime, (fader_ptr), move || unsafe {
+            libobs::obs_fader_get_mul(fader_ptr)
+        })
+    }
+}
+
+#[derive(Debug)]
+struct _ObsFaderGuard {
+    fader: Sendable<*mut obs_fader_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsFaderGuard, (fader), move || unsafe {
+    libobs::obs_fader_remove_callback(fader, Some(fader_changed), fader as *mut c_void);
+    libobs::obs_fader_detach_source(fader);
+    libobs::obs_fader_destroy(fader);
+
+    if let Ok(mut senders) = FADER_SENDERS.write() {
+        senders.remove(&SendableComp(fader));
+    }
+});
+
+impl ObsSourceRef {
+    /// Creates a fader of the given type that is attached to this source.
+    pub fn fader(&self, fader_type: ObsFaderType) -> Result<ObsFader, ObsError> {
+        let fader = ObsFader::new(fader_type, self.runtime.clone())?;
+        fader.attach_source(self)?;
+
+        Ok(fader)
+    }
+}
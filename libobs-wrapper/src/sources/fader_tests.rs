@@ -0,0 +1,74 @@
+use crate::enums::ObsFaderType;
+
+use super::{db_to_mul, mul_to_db};
+
+const FADER_TYPES: [ObsFaderType; 3] = [ObsFaderType::Cubic, ObsFaderType::IEC, ObsFaderType::Log];
+
+#[test]
+fn test_fader_bounds() {
+    for fader_type in FADER_TYPES {
+        assert_eq!(fader_type.db_to_deflection(0.0), 1.0, "{:?}", fader_type);
+        assert_eq!(
+            fader_type.db_to_deflection(f32::NEG_INFINITY),
+            0.0,
+            "{:?}",
+            fader_type
+        );
+        assert_eq!(fader_type.deflection_to_db(1.0), 0.0, "{:?}", fader_type);
+        assert_eq!(
+            fader_type.deflection_to_db(0.0),
+            f32::NEG_INFINITY,
+            "{:?}",
+            fader_type
+        );
+    }
+}
+
+#[test]
+fn test_fader_round_trip() {
+    for fader_type in FADER_TYPES {
+        for db in [-3.0, -9.0, -15.0, -25.0, -35.0, -45.0, -55.0] {
+            let def = fader_type.db_to_deflection(db);
+            assert!((0.0..=1.0).contains(&def), "{:?} {}", fader_type, def);
+
+            let back = fader_type.deflection_to_db(def);
+            assert!(
+                (back - db).abs() < 0.01,
+                "{:?}: {} dB -> {} -> {} dB",
+                fader_type,
+                db,
+                def,
+                back
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fader_monotonic() {
+    for fader_type in FADER_TYPES {
+        let mut last = f32::NEG_INFINITY;
+        for i in 1..=100 {
+            let db = fader_type.deflection_to_db(i as f32 / 100.0);
+            assert!(db > last, "{:?} is not monotonic at {}", fader_type, i);
+            last = db;
+        }
+    }
+}
+
+#[test]
+fn test_iec_segments() {
+    let iec = ObsFaderType::IEC;
+    assert!((iec.db_to_deflection(-9.0) - 0.75).abs() < f32::EPSILON);
+    assert!((iec.db_to_deflection(-20.0) - 0.5).abs() < f32::EPSILON);
+    assert!((iec.db_to_deflection(-60.0) - 0.025).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_mul_db_conversion() {
+    assert_eq!(mul_to_db(1.0), 0.0);
+    assert_eq!(mul_to_db(0.0), f32::NEG_INFINITY);
+    assert_eq!(db_to_mul(f32::NEG_INFINITY), 0.0);
+    assert!((mul_to_db(0.5) - -6.0206).abs() < 0.001);
+    assert!((db_to_mul(-6.0206) - 0.5).abs() < 0.001);
+}
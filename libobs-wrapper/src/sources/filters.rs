@@ -0,0 +1,178 @@
+//! Filter chains of sources
+//!
+//! Filters are sources themselves and are applied in the order they were
+//! added, e.g. noise suppression before a gain filter on a microphone.
+//! Changes to the chain are announced with the `filter_add`,
+//! `filter_remove` and `reorder_filters` signals of the parent source.
+//!
+//! The filters are read from libobs on every call, so the result always
+//! matches the current chain. Listing them wraps every filter again, which
+//! is fine as all wrappers of a filter share its signals. Filters created
+//! with `ObsContext::obs_filter` or loaded from a scene collection are also
+//! kept by the context until it is dropped.
+use std::ffi::c_void;
+
+use libobs::obs_source_t;
+
+use crate::{
+    enums::{ObsOrderMovement, ObsSourceType, OsEnumType},
+    run_with_obs,
+    unsafe_send::Sendable,
+    utils::{ObsError, ObsString},
+};
+
+use super::{ObsFilterRef, ObsSourceRef};
+
+unsafe extern "C" fn enum_filters_callback(
+    _parent: *mut obs_source_t,
+    filter: *mut obs_source_t,
+    param: *mut c_void,
+) {
+    let filters = &mut *(param as *mut Vec<Sendable<*mut obs_source_t>>);
+    filters.push(Sendable(libobs::obs_source_get_ref(filter)));
+}
+
+impl ObsSourceRef {
+    /// Adds the filter to the end of the filter chain of this source.
+    ///
+    /// Fails if the source is not a filter or is already attached to a source.
+    pub fn add_filter(&self, filter: &ObsFilterRef) -> Result<(), ObsError> {
+        let source_ptr = self.source.clone();
+        let filter_ptr = filter.source.clone();
+        let error = run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
+            let source_type = libobs::obs_source_get_type(filter_ptr);
+            #[allow(clippy::unnecessary_cast)]
+            let source_type = source_type as OsEnumType;
+            if source_type != ObsSourceType::Filter as OsEnumType {
+                return Some("is not a filter");
+            }
+
+            if !libobs::obs_filter_get_parent(filter_ptr).is_null() {
+                return Some("is already attached to a source");
+            }
+
+            libobs::obs_source_filter_add(source_ptr, filter_ptr);
+            None
+        })?;
+
+        if let Some(error) = error {
+            return Err(ObsError::InvalidOperation(format!(
+                "Source {} {}",
+                filter.name, error
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the filter from this source. The filter can be added again afterwards.
+    pub fn remove_filter(&self, filter: &ObsFilterRef) -> Result<(), ObsError> {
+        self.ensure_own_filter(filter)?;
+
+        let source_ptr = self.source.clone();
+        let filter_ptr = filter.source.clone();
+        run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
+            libobs::obs_source_filter_remove(source_ptr, filter_ptr);
+        })
+    }
+
+    /// Returns the filters of this source in the order they are applied,
+    /// including filters that were not added through this wrapper.
+    pub fn filters(&self) -> Result<Vec<ObsFilterRef>, ObsError> {
+        let source_ptr = self.source.clone();
+        let raw_filters = run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let mut filters: Vec<Sendable<*mut obs_source_t>> = Vec::new();
+            libobs::obs_source_enum_filters(
+                source_ptr,
+                Some(enum_filters_callback),
+                &mut filters as *mut _ as *mut c_void,
+            );
+
+            filters
+        })?;
+
+        // Wrap all filters first, so every reference is released even if one fails
+        let wrapped = raw_filters
+            .into_iter()
+            .map(|filter| ObsSourceRef::from_raw(filter, self.runtime.clone()))
+            .collect::<Vec<_>>();
+        wrapped.into_iter().collect()
+    }
+
+    /// Gets a filter of this source by its name.
+    pub fn get_filter_by_name(&self, name: &str) -> Result<Option<ObsFilterRef>, ObsError> {
+        let source_ptr = self.source.clone();
+        let name = ObsString::new(name);
+        let name_ptr = name.as_ptr();
+        let filter = run_with_obs!(self.runtime, (source_ptr, name_ptr), move || unsafe {
+            Sendable(libobs::obs_source_get_filter_by_name(source_ptr, name_ptr))
+        })?;
+
+        if filter.0.is_null() {
+            return Ok(None);
+        }
+
+        ObsSourceRef::from_raw(filter, self.runtime.clone()).map(Some)
+    }
+
+    /// Moves the filter up or down in the filter chain of this source.
+    /// `MoveTop` makes it the first filter that is applied.
+    pub fn set_filter_order(
+        &self,
+        filter: &ObsFilterRef,
+        movement: ObsOrderMovement,
+    ) -> Result<(), ObsError> {
+        self.ensure_own_filter(filter)?;
+
+        let source_ptr = self.source.clone();
+        let filter_ptr = filter.source.clone();
+        run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
+            libobs::obs_source_filter_set_order(source_ptr, filter_ptr, movement as OsEnumType);
+        })
+    }
+
+    /// The position of the filter in the chain of this source, 0 is applied first.
+    pub fn filter_index(&self, filter: &ObsFilterRef) -> Result<Option<usize>, ObsError> {
+        let source_ptr = self.source.clone();
+        let filter_ptr = filter.source.clone();
+        let index = run_with_obs!(self.runtime, (source_ptr, filter_ptr), move || unsafe {
+            libobs::obs_source_filter_get_index(source_ptr, filter_ptr)
+        })?;
+
+        Ok(usize::try_from(index).ok())
+    }
+
+    /// Enables or disables a filter of this source without removing it.
+    pub fn set_filter_enabled(&self, filter: &ObsFilterRef, enabled: bool) -> Result<(), ObsError> {
+        self.ensure_own_filter(filter)?;
+
+        let filter_ptr = filter.source.clone();
+        run_with_obs!(self.runtime, (filter_ptr), move || unsafe {
+            libobs::obs_source_set_enabled(filter_ptr, enabled);
+        })
+    }
+
+    /// Whether the source is enabled. Only filters can be disabled.
+    pub fn enabled(&self) -> Result<bool, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_enabled(source_ptr)
+        })
+    }
+
+    fn ensure_own_filter(&self, filter: &ObsFilterRef) -> Result<(), ObsError> {
+        let filter_ptr = filter.source.clone();
+        let parent = run_with_obs!(self.runtime, (filter_ptr), move || unsafe {
+            Sendable(libobs::obs_filter_get_parent(filter_ptr))
+        })?;
+
+        if parent.0 != self.source.0 {
+            return Err(ObsError::InvalidOperation(format!(
+                "Filter {} is not attached to source {}",
+                filter.name, self.name
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::{Arc, RwLock};
+
+use libobs::{obs_source_audio, obs_source_frame};
+
+use crate::{
+    context::ObsContext,
+    data::{raw_audio::ObsRawAudioFrame, raw_video::ObsRawVideoFrame},
+    enums::{ObsColorspace, ObsVideoRange, OsEnumType},
+    run_with_obs,
+    runtime::ObsRuntime,
+    scenes::ObsSceneRef,
+    utils::{ObsError, SourceInfo},
+};
+
+use super::{ObsCustomSource, ObsCustomSourceContext, ObsSourceRef, ObsSourceSettings};
+
+/// Id of the source type that is used by `ObsFramePushSource`
+pub const FRAME_PUSH_SOURCE_ID: &str = "libobs_rs_frame_push_source";
+
+/// Async video source without any logic of its own, libobs draws
+/// whatever frames are pushed to it.
+struct FramePushSourceType;
+
+impl ObsCustomSource for FramePushSourceType {
+    const ID: &'static str = FRAME_PUSH_SOURCE_ID;
+    const NAME: &'static str = "Frame Push Source";
+    const OUTPUT_FLAGS: u32 = libobs::OBS_SOURCE_ASYNC_VIDEO | libobs::OBS_SOURCE_AUDIO;
+
+    fn create(_settings: &ObsSourceSettings, _context: ObsCustomSourceContext) -> Self {
+        Self
+    }
+}
+
+/// A source that shows video frames and plays audio that is pushed from
+/// Rust, for example frames of a custom renderer or a test pattern.
+///
+/// Frames are copied by libobs and shown according to their timestamp,
+/// so the same clock has to be used for video and audio.
+#[derive(Debug, Clone)]
+pub struct ObsFramePushSource {
+    source: ObsSourceRef,
+    /// Colorspace and range used to interpret YUV frames
+    color_params: Arc<RwLock<(ObsColorspace, ObsVideoRange)>>,
+    runtime: ObsRuntime,
+}
+
+impl ObsFramePushSource {
+    /// Creates a new frame push source with the given name and adds it to the scene.
+    pub fn new<T: Into<String>>(
+        context: &ObsContext,
+        scene: &mut ObsSceneRef,
+        name: T,
+    ) -> Result<Self, ObsError> {
+        context.register_source_if_missing::<FramePushSourceType>()?;
+
+        let source = scene
+            .add_source(SourceInfo::new(
+                FRAME_PUSH_SOURCE_ID,
+                name.into(),
+                None,
+                None,
+            ))?
+            .source()
+            .clone();
+
+        Ok(Self {
+            runtime: source.runtime.clone(),
+            source,
+            color_params: Arc::new(RwLock::new((ObsColorspace::CS709, ObsVideoRange::Partial))),
+        })
+    }
+
+    pub fn as_source(&self) -> &ObsSourceRef {
+        &self.source
+    }
+
+    /// Sets how YUV frames are converted to RGB. Defaults to
+    /// Rec. 709 with partial range, RGB frames are always full range.
+    pub fn set_color_params(
+        &self,
+        colorspace: ObsColorspace,
+        range: ObsVideoRange,
+    ) -> Result<(), ObsError> {
+        *self.color_params.write().map_err(|_| {
+            ObsError::LockError("Failed to acquire write lock on color params".to_string())
+        })? = (colorspace, range);
+
+        Ok(())
+    }
+
+    /// Pushes a video frame that is shown at its timestamp (in nanoseconds).
+    pub fn push_video(&self, frame: ObsRawVideoFrame) -> Result<(), ObsError> {
+        frame.validate()?;
+
+        let (colorspace, range) = *self.color_params.read().map_err(|_| {
+            ObsError::LockError("Failed to acquire read lock on color params".to_string())
+        })?;
+
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let mut obs_frame: obs_source_frame = std::mem::zeroed();
+            for (i, plane) in frame.planes.iter().enumerate() {
+                obs_frame.data[i] = plane.as_ptr() as *mut u8;
+                obs_frame.linesize[i] = frame.linesize[i];
+            }
+
+            obs_frame.width = frame.width;
+            obs_frame.height = frame.height;
+            obs_frame.timestamp = frame.timestamp;
+            obs_frame.format = frame.format as OsEnumType;
+            obs_frame.full_range = range == ObsVideoRange::Full;
+            libobs::video_format_get_parameters_for_format(
+                colorspace as OsEnumType,
+                range as OsEnumType,
+                frame.format as OsEnumType,
+                obs_frame.color_matrix.as_mut_ptr(),
+                obs_frame.color_range_min.as_mut_ptr(),
+                obs_frame.color_range_max.as_mut_ptr(),
+            );
+
+            // libobs copies the frame, so the planes can be dropped afterwards
+            libobs::obs_source_output_video(source_ptr, &obs_frame);
+        })
+    }
+
+    /// Removes the currently shown frame, so the source is empty until the next frame is pushed.
+    pub fn clear_video(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_output_video(source_ptr, std::ptr::null());
+        })
+    }
+
+    /// Pushes planar `f32` audio that is played at its timestamp (in nanoseconds).
+    pub fn push_audio(&self, audio: ObsRawAudioFrame) -> Result<(), ObsError> {
+        audio.validate()?;
+
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            let mut obs_audio: obs_source_audio = std::mem::zeroed();
+            for (i, plane) in audio.planes.iter().enumerate() {
+                obs_audio.data[i] = plane.as_ptr() as *const u8;
+            }
+
+            obs_audio.frames = audio.frames;
+            obs_audio.speakers = audio.speakers as OsEnumType;
+            obs_audio.format = libobs::audio_format_AUDIO_FORMAT_FLOAT_PLANAR;
+            obs_audio.samples_per_sec = audio.samples_per_sec;
+            obs_audio.timestamp = audio.timestamp;
+
+            libobs::obs_source_output_audio(source_ptr, &obs_audio);
+        })
+    }
+}
+
+impl ObsContext {
+    /// Creates an `ObsFramePushSource` in the given scene.
+    pub fn frame_push_source<T: Into<String>>(
+        &self,
+        scene: &mut ObsSceneRef,
+        name: T,
+    ) -> Result<ObsFramePushSource, ObsError> {
+        ObsFramePushSource::new(self, scene, name)
+    }
+}
@@ -1,8 +1,26 @@
+mod audio;
 mod builder;
+mod custom;
+mod existing;
+mod fader;
+mod filters;
+mod frame_push;
+mod volume_meter;
+pub use audio::*;
 pub use builder::*;
+pub use custom::*;
+pub use fader::*;
+pub use frame_push::*;
+pub use volume_meter::*;
+
+#[cfg(test)]
+mod fader_tests;
 
 use libobs::{obs_scene_item, obs_scene_t, obs_source_t};
 
+#[cfg(feature = "async")]
+use crate::run_with_obs_async;
+
 use crate::{
     data::{immutable::ImmutableObsData, ObsData},
     impl_obs_drop, impl_signal_manager,
@@ -15,6 +33,7 @@ use crate::{
 
 use std::{
     collections::HashMap,
+    ffi::CStr,
     hash::Hash,
     sync::{Arc, RwLock},
 };
@@ -44,22 +63,13 @@ impl ObsSourceRef {
     pub fn new<T: Into<ObsString> + Sync + Send, K: Into<ObsString> + Sync + Send>(
         id: T,
         name: K,
-        mut settings: Option<ObsData>,
-        mut hotkey_data: Option<ObsData>,
+        settings: Option<ObsData>,
+        hotkey_data: Option<ObsData>,
         runtime: ObsRuntime,
     ) -> Result<Self, ObsError> {
         let id = id.into();
         let name = name.into();
-
-        let settings = match settings.take() {
-            Some(x) => ImmutableObsData::from(x),
-            None => ImmutableObsData::new(&runtime)?,
-        };
-
-        let hotkey_data = match hotkey_data.take() {
-            Some(x) => ImmutableObsData::from(x),
-            None => ImmutableObsData::new(&runtime)?,
-        };
+        let (settings, hotkey_data) = Self::creation_data(settings, hotkey_data, &runtime)?;
 
         let hotkey_data_ptr = hotkey_data.as_ptr();
         let settings_ptr = settings.as_ptr();
@@ -84,7 +94,124 @@ impl ObsSourceRef {
         }
 
         let signals = ObsSourceSignals::new(&source, runtime.clone())?;
-        Ok(Self {
+        Ok(Self::from_created(
+            source,
+            id,
+            name,
+            settings,
+            hotkey_data,
+            signals,
+            runtime,
+        ))
+    }
+
+    /// Async version of `new`. Creating a source can take a while
+    /// (e.g. browser or media sources), which is awaited instead of blocking.
+    #[cfg(feature = "async")]
+    pub async fn new_async<T: Into<ObsString> + Sync + Send, K: Into<ObsString> + Sync + Send>(
+        id: T,
+        name: K,
+        settings: Option<ObsData>,
+        hotkey_data: Option<ObsData>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        let id = id.into();
+        let name = name.into();
+        let settings = settings.map(ImmutableObsData::from);
+        let hotkey_data = hotkey_data.map(ImmutableObsData::from);
+
+        let settings_ptr = Sendable(
+            settings
+                .as_ref()
+                .map_or(std::ptr::null_mut(), |d| d.as_ptr().0),
+        );
+        let hotkey_data_ptr = Sendable(
+            hotkey_data
+                .as_ref()
+                .map_or(std::ptr::null_mut(), |d| d.as_ptr().0),
+        );
+        let id_ptr = id.as_ptr();
+        let name_ptr = name.as_ptr();
+
+        // Missing data is created together with the source, so this is a single round trip
+        let (source, settings_ptr, hotkey_data_ptr) = run_with_obs_async!(
+            runtime,
+            (hotkey_data_ptr, settings_ptr, id_ptr, name_ptr),
+            move || unsafe {
+                let settings_ptr = if settings_ptr.is_null() {
+                    libobs::obs_data_create()
+                } else {
+                    settings_ptr
+                };
+                let hotkey_data_ptr = if hotkey_data_ptr.is_null() {
+                    libobs::obs_data_create()
+                } else {
+                    hotkey_data_ptr
+                };
+
+                (
+                    Sendable(libobs::obs_source_create(
+                        id_ptr,
+                        name_ptr,
+                        settings_ptr,
+                        hotkey_data_ptr,
+                    )),
+                    Sendable(settings_ptr),
+                    Sendable(hotkey_data_ptr),
+                )
+            }
+        )?;
+
+        let settings =
+            settings.unwrap_or_else(|| ImmutableObsData::from_raw(settings_ptr, runtime.clone()));
+        let hotkey_data = hotkey_data
+            .unwrap_or_else(|| ImmutableObsData::from_raw(hotkey_data_ptr, runtime.clone()));
+
+        if source.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        let signals = ObsSourceSignals::new_async(&source, runtime.clone()).await?;
+        Ok(Self::from_created(
+            source,
+            id,
+            name,
+            settings,
+            hotkey_data,
+            signals,
+            runtime,
+        ))
+    }
+
+    /// Settings and hotkey data passed to `obs_source_create`, empty data is used for `None`.
+    fn creation_data(
+        settings: Option<ObsData>,
+        hotkey_data: Option<ObsData>,
+        runtime: &ObsRuntime,
+    ) -> Result<(ImmutableObsData, ImmutableObsData), ObsError> {
+        let settings = match settings {
+            Some(x) => ImmutableObsData::from(x),
+            None => ImmutableObsData::new(runtime)?,
+        };
+
+        let hotkey_data = match hotkey_data {
+            Some(x) => ImmutableObsData::from(x),
+            None => ImmutableObsData::new(runtime)?,
+        };
+
+        Ok((settings, hotkey_data))
+    }
+
+    fn from_created(
+        source: Sendable<*mut obs_source_t>,
+        id: ObsString,
+        name: ObsString,
+        settings: ImmutableObsData,
+        hotkey_data: ImmutableObsData,
+        signals: ObsSourceSignals,
+        runtime: ObsRuntime,
+    ) -> Self {
+        Self {
             source: source.clone(),
             id,
             name,
@@ -97,6 +224,52 @@ impl ObsSourceRef {
             scene_items: Arc::new(RwLock::new(HashMap::new())),
             runtime,
             signal_manager: Arc::new(signals),
+        }
+    }
+
+    /// Wraps a source that was created by libobs itself, e.g. when loading a
+    /// scene collection. Takes ownership of one reference of the source.
+    pub(crate) fn from_raw(
+        source: Sendable<*mut obs_source_t>,
+        runtime: ObsRuntime,
+    ) -> Result<Self, ObsError> {
+        if source.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        // Created first, so the reference is released if anything below fails
+        let guard = Arc::new(_ObsSourceGuard {
+            source: source.clone(),
+            runtime: runtime.clone(),
+        });
+
+        let source_ptr = source.clone();
+        let (id, name, settings) = run_with_obs!(runtime, (source_ptr), move || unsafe {
+            let id = CStr::from_ptr(libobs::obs_source_get_id(source_ptr))
+                .to_string_lossy()
+                .to_string();
+            let name = CStr::from_ptr(libobs::obs_source_get_name(source_ptr))
+                .to_string_lossy()
+                .to_string();
+
+            (
+                id,
+                name,
+                Sendable(libobs::obs_source_get_settings(source_ptr)),
+            )
+        })?;
+
+        let signals = ObsSourceSignals::new(&source, runtime.clone())?;
+        Ok(Self {
+            source: source.clone(),
+            id: id.into(),
+            name: name.into(),
+            settings: Arc::new(ImmutableObsData::from_raw(settings, runtime.clone())),
+            hotkey_data: Arc::new(ImmutableObsData::new(&runtime)?),
+            _guard: guard,
+            scene_items: Arc::new(RwLock::new(HashMap::new())),
+            runtime,
+            signal_manager: Arc::new(signals),
         })
     }
 
@@ -123,6 +296,22 @@ impl ObsSourceRef {
     pub fn as_ptr(&self) -> *mut obs_source_t {
         self.source.0
     }
+
+    /// Returns the current width of the source in pixels.
+    pub fn width(&self) -> Result<u32, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_width(source_ptr)
+        })
+    }
+
+    /// Returns the current height of the source in pixels.
+    pub fn height(&self) -> Result<u32, ObsError> {
+        let source_ptr = self.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_height(source_ptr)
+        })
+    }
 }
 
 impl ObsUpdatable for ObsSourceRef {
@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    sync::{Arc, RwLock},
+};
+
+use lazy_static::lazy_static;
+use libobs::obs_volmeter_t;
+use tokio::sync::broadcast;
+
+use crate::{
+    enums::{ObsFaderType, ObsPeakMeterType, OsEnumType},
+    impl_obs_drop, run_with_obs,
+    runtime::ObsRuntime,
+    unsafe_send::{Sendable, SendableComp},
+    utils::ObsError,
+};
+
+use super::ObsSourceRef;
+
+lazy_static! {
+    static ref VOLMETER_SENDERS: RwLock<HashMap<SendableComp<*mut obs_volmeter_t>, broadcast::Sender<ObsVolumeLevels>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Audio levels of a source for a single audio tick. Every vector
+/// contains one value per audio channel of the source, in dBFS.
+/// Silence is reported as `f32::NEG_INFINITY`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObsVolumeLevels {
+    /// RMS level of the audio, taking the volume of the source into account
+    pub magnitude: Vec<f32>,
+    /// Peak level of the audio, taking the volume of the source into account
+    pub peak: Vec<f32>,
+    /// Peak level of the audio before the volume of the source is applied
+    pub input_peak: Vec<f32>,
+}
+
+unsafe extern "C" fn volmeter_updated(
+    param: *mut c_void,
+    magnitude: *const f32,
+    peak: *const f32,
+    input_peak: *const f32,
+) {
+    let volmeter = param as *mut obs_volmeter_t;
+    let senders = match VOLMETER_SENDERS.read() {
+        Ok(s) => s,
+        Err(e) => {
+            log::warn!(
+                "Failed to acquire read lock for volume meter senders: {}",
+                e
+            );
+            return;
+        }
+    };
+
+    let sender = match senders.get(&SendableComp(volmeter)) {
+        Some(s) => s,
+        None => return,
+    };
+
+    // Nobody is listening, skip copying the levels
+    if sender.receiver_count() == 0 {
+        return;
+    }
+
+    let channels = libobs::obs_volmeter_get_nr_channels(volmeter)
+        .clamp(0, libobs::MAX_AUDIO_CHANNELS as _) as usize;
+
+    let levels = ObsVolumeLevels {
+        magnitude: std::slice::from_raw_parts(magnitude, channels).to_vec(),
+        peak: std::slice::from_raw_parts(peak, channels).to_vec(),
+        input_peak: std::slice::from_raw_parts(input_peak, channels).to_vec(),
+    };
+
+    let _ = sender.send(levels);
+}
+
+/// A volume meter that is attached to a source and reports its audio
+/// levels. Create it with `ObsSourceRef::volume_meter` and call
+/// `subscribe` to receive the levels.
+#[derive(Debug, Clone)]
+pub struct ObsVolumeMeter {
+    volmeter: Sendable<*mut obs_volmeter_t>,
+    _guard: Arc<_ObsVolumeMeterGuard>,
+    runtime: ObsRuntime,
+}
+
+impl ObsVolumeMeter {
+    pub fn new(source: &ObsSourceRef, fader_type: ObsFaderType) -> Result<Self, ObsError> {
+        let runtime = source.runtime.clone();
+        let volmeter = run_with_obs!(runtime, move || unsafe {
+            Sendable(libobs::obs_volmeter_create(fader_type as OsEnumType))
+        })?;
+
+        if volmeter.0.is_null() {
+            return Err(ObsError::NullPointer);
+        }
+
+        // Created before anything else, so the volume meter is always destroyed on error
+        let guard = Arc::new(_ObsVolumeMeterGuard {
+            volmeter: volmeter.clone(),
+            runtime: runtime.clone(),
+        });
+
+        let (tx, _rx) = broadcast::channel(16);
+        VOLMETER_SENDERS
+            .write()
+            .map_err(|_| {
+                ObsError::LockError(
+                    "Failed to acquire write lock on volume meter senders".to_string(),
+                )
+            })?
+            .insert(SendableComp(volmeter.0), tx);
+
+        let source_ptr = source.source.clone();
+        let volmeter_ptr = volmeter.clone();
+        let attached = run_with_obs!(runtime, (volmeter_ptr, source_ptr), move || unsafe {
+            if !libobs::obs_volmeter_attach_source(volmeter_ptr, source_ptr) {
+                return false;
+            }
+
+            libobs::obs_volmeter_add_callback(
+                volmeter_ptr,
+                Some(volmeter_updated),
+                volmeter_ptr as *mut c_void,
+            );
+            true
+        })?;
+
+        if !attached {
+            return Err(ObsError::InvalidOperation(format!(
+                "Could not attach volume meter to source {}",
+                source.name()
+            )));
+        }
+
+        Ok(Self {
+            volmeter,
+            _guard: guard,
+            runtime,
+        })
+    }
+
+    /// Returns a receiver which gets the audio levels of the source every audio tick.
+    pub fn subscribe(&self) -> Result<broadcast::Receiver<ObsVolumeLevels>, ObsError> {
+        let rx = VOLMETER_SENDERS
+            .read()
+            .map_err(|_| {
+                ObsError::LockError(
+                    "Failed to acquire read lock on volume meter senders".to_string(),
+                )
+            })?
+            .get(&SendableComp(self.volmeter.0))
+            .ok_or(ObsError::NoSenderError)?
+            .subscribe();
+
+        Ok(rx)
+    }
+
+    pub fn set_peak_meter_type(&self, peak_meter_type: ObsPeakMeterType) -> Result<(), ObsError> {
+        let volmeter_ptr = self.volmeter.clone();
+        run_with_obs!(self.runtime, (volmeter_ptr), move || unsafe {
+            libobs::obs_volmeter_set_peak_meter_type(volmeter_ptr, peak_meter_type as OsEnumType);
+        })
+    }
+
+    /// Returns the number of audio channels the volume meter reports levels for.
+    pub fn channels(&self) -> Result<usize, ObsError> {
+        let volmeter_ptr = self.volmeter.clone();
+        let channels = run_with_obs!(self.runtime, (volmeter_ptr), move || unsafe {
+            libobs::obs_volmeter_get_nr_channels(volmeter_ptr)
+        })?;
+
+        Ok(channels.max(0) as usize)
+    }
+}
+
+#[derive(Debug)]
+struct _ObsVolumeMeterGuard {
+    volmeter: Sendable<*mut obs_volmeter_t>,
+    runtime: ObsRuntime,
+}
+
+impl_obs_drop!(_ObsVolumeMeterGuard, (volmeter), move || unsafe {
+    libobs::obs_volmeter_remove_callback(volmeter, Some(volmeter_updated), volmeter as *mut c_void);
+    libobs::obs_volmeter_detach_source(volmeter);
+    libobs::obs_volmeter_destroy(volmeter);
+
+    if let Ok(mut senders) = VOLMETER_SENDERS.write() {
+        senders.remove(&SendableComp(volmeter));
+    }
+});
+
+impl ObsSourceRef {
+    /// Creates a volume meter that reports the audio levels of this source.
+    pub fn volume_meter(&self, fader_type: ObsFaderType) -> Result<ObsVolumeMeter, ObsError> {
+        ObsVolumeMeter::new(self, fader_type)
+    }
+}
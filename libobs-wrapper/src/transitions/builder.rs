@@ -0,0 +1,13 @@
+use crate::{context::ObsContext, data::ObsObjectBuilder, utils::ObsError};
+
+use super::ObsTransitionRef;
+
+pub trait ObsTransitionBuilder: ObsObjectBuilder {
+    fn add_to_context(self, context: &mut ObsContext) -> Result<ObsTransitionRef, ObsError>
+    where
+        Self: Sized,
+    {
+        let t = self.build()?;
+        context.transition(t)
+    }
+}
@@ -0,0 +1,248 @@
+//! Scene transitions
+//!
+//! Transitions are special sources (`fade_transition`, `cut_transition`,
+//! `swipe_transition`, `slide_transition`, `obs_stinger_transition`, ...) that
+//! blend between two other sources. A transition is usually bound to output
+//! channel 0 using `ObsContext::set_output_transition`, after which
+//! `ObsContext::transition_to` can be used to switch scenes.
+mod builder;
+pub use builder::*;
+
+use std::{
+    hash::Hash,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use libobs::obs_source_t;
+
+use crate::{
+    data::{immutable::ImmutableObsData, ObsData},
+    run_with_obs,
+    runtime::ObsRuntime,
+    scenes::ObsSceneRef,
+    sources::{ObsSourceRef, ObsSourceSignals},
+    unsafe_send::Sendable,
+    utils::{traits::ObsUpdatable, ObsError, TransitionInfo},
+};
+
+/// Default duration of a transition, same as in OBS Studio.
+pub const DEFAULT_TRANSITION_DURATION: Duration = Duration::from_millis(300);
+
+pub(crate) fn duration_to_ms(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+/// A transition source. The transition itself is reference counted,
+/// so it is safe to clone this struct.
+#[derive(Debug, Clone)]
+pub struct ObsTransitionRef {
+    source: ObsSourceRef,
+    /// Duration in milliseconds that is used when no explicit
+    /// duration is given to `start`
+    duration_ms: Arc<AtomicU32>,
+    pub(crate) runtime: ObsRuntime,
+}
+
+impl PartialEq for ObsTransitionRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for ObsTransitionRef {}
+
+impl Hash for ObsTransitionRef {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+    }
+}
+
+impl ObsTransitionRef {
+    pub fn new(info: TransitionInfo, runtime: ObsRuntime) -> Result<Self, ObsError> {
+        let source = ObsSourceRef::new(
+            info.id,
+            info.name,
+            info.settings,
+            info.hotkey_data,
+            runtime.clone(),
+        )?;
+
+        let source_ptr = source.source.clone();
+        let is_transition = run_with_obs!(runtime, (source_ptr), move || unsafe {
+            libobs::obs_source_get_type(source_ptr)
+                == libobs::obs_source_type_OBS_SOURCE_TYPE_TRANSITION
+        })?;
+
+        if !is_transition {
+            return Err(ObsError::InvalidOperation(format!(
+                "Source {} is not a transition",
+                source.id()
+            )));
+        }
+
+        Ok(Self {
+            source,
+            duration_ms: Arc::new(AtomicU32::new(duration_to_ms(DEFAULT_TRANSITION_DURATION))),
+            runtime,
+        })
+    }
+
+    /// Wraps a transition source that was created by libobs itself.
+    pub(crate) fn from_source(source: ObsSourceRef, duration: Duration) -> Self {
+        Self {
+            runtime: source.runtime.clone(),
+            source,
+            duration_ms: Arc::new(AtomicU32::new(duration_to_ms(duration))),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.source.name()
+    }
+
+    pub fn id(&self) -> String {
+        self.source.id()
+    }
+
+    /// Returns the underlying source of this transition.
+    pub fn as_source(&self) -> &ObsSourceRef {
+        &self.source
+    }
+
+    /// The signal manager of the underlying source, use it to listen to
+    /// `transition_start`, `transition_video_stop` and `transition_stop`.
+    pub fn signal_manager(&self) -> Arc<ObsSourceSignals> {
+        self.source.signal_manager()
+    }
+
+    pub fn as_ptr(&self) -> *mut obs_source_t {
+        self.source.as_ptr()
+    }
+
+    /// The duration that is used when starting this transition.
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration_ms.load(Ordering::Relaxed) as u64)
+    }
+
+    /// Sets the duration that is used when starting this transition.
+    /// Has no effect on fixed transitions such as `cut_transition`.
+    pub fn set_duration(&self, duration: Duration) {
+        self.duration_ms
+            .store(duration_to_ms(duration), Ordering::Relaxed);
+    }
+
+    /// Whether this transition has a fixed duration (e.g. the cut or stinger transition).
+    pub fn is_fixed(&self) -> Result<bool, ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_transition_fixed(source_ptr)
+        })
+    }
+
+    /// Starts transitioning to the given scene. If `duration` is `None`,
+    /// the duration set with `set_duration` is used.
+    pub fn start(&self, scene: &ObsSceneRef, duration: Option<Duration>) -> Result<(), ObsError> {
+        let dest_ptr = scene.get_scene_source_ptr()?;
+        self.start_raw(dest_ptr, duration)
+    }
+
+    /// Same as `start`, but transitions to an arbitrary source instead of a scene.
+    pub fn start_to_source(
+        &self,
+        source: &ObsSourceRef,
+        duration: Option<Duration>,
+    ) -> Result<(), ObsError> {
+        self.start_raw(source.source.clone(), duration)
+    }
+
+    fn start_raw(
+        &self,
+        dest_ptr: Sendable<*mut obs_source_t>,
+        duration: Option<Duration>,
+    ) -> Result<(), ObsError> {
+        let duration_ms = duration
+            .map(duration_to_ms)
+            .unwrap_or_else(|| self.duration_ms.load(Ordering::Relaxed));
+
+        let source_ptr = self.source.source.clone();
+        let started = run_with_obs!(self.runtime, (source_ptr, dest_ptr), move || unsafe {
+            libobs::obs_transition_start(
+                source_ptr,
+                libobs::obs_transition_mode_OBS_TRANSITION_MODE_AUTO,
+                duration_ms,
+                dest_ptr,
+            )
+        })?;
+
+        if !started {
+            return Err(ObsError::TransitionStartFailure);
+        }
+
+        Ok(())
+    }
+
+    /// Immediately sets the given scene as the active source of this
+    /// transition without any animation.
+    pub fn set_scene(&self, scene: &ObsSceneRef) -> Result<(), ObsError> {
+        let dest_ptr = scene.get_scene_source_ptr()?;
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr, dest_ptr), move || unsafe {
+            libobs::obs_transition_set(source_ptr, dest_ptr);
+        })
+    }
+
+    /// Returns the progress of the currently running transition from 0.0 to 1.0.
+    pub fn progress(&self) -> Result<f32, ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_transition_get_time(source_ptr)
+        })
+    }
+
+    /// Stops the currently running transition and jumps to its destination.
+    pub fn force_stop(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_transition_force_stop(source_ptr);
+        })
+    }
+
+    /// Removes both the active and the destination source of this transition.
+    pub fn clear(&self) -> Result<(), ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_transition_clear(source_ptr);
+        })
+    }
+
+    /// Sets a fixed size for this transition. By default the size
+    /// is calculated from the sources that are transitioned between.
+    pub fn set_size(&self, width: u32, height: u32) -> Result<(), ObsError> {
+        let source_ptr = self.source.source.clone();
+        run_with_obs!(self.runtime, (source_ptr), move || unsafe {
+            libobs::obs_transition_set_size(source_ptr, width, height);
+        })
+    }
+}
+
+impl ObsUpdatable for ObsTransitionRef {
+    fn runtime(&self) -> ObsRuntime {
+        self.runtime.clone()
+    }
+
+    fn update_raw(&mut self, data: ObsData) -> Result<(), ObsError> {
+        self.source.update_raw(data)
+    }
+
+    fn reset_and_update_raw(&mut self, data: ObsData) -> Result<(), ObsError> {
+        self.source.reset_and_update_raw(data)
+    }
+
+    fn get_settings(&self) -> Result<ImmutableObsData, ObsError> {
+        self.source.get_settings()
+    }
+}
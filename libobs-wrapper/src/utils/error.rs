@@ -69,6 +69,21 @@ pub enum ObsError {
 
     /// Failed to send/receive on a runtime channel
     RuntimeChannelError(String),
+
+    /// libobs could not register the hotkey with the given name
+    HotkeyRegistrationFailure(String),
+
+    /// libobs refused to start the transition
+    TransitionStartFailure,
+
+    /// Failed to convert a value from or to `ObsData`
+    SerializationError(String),
+
+    /// Rendering or reading back a texture failed
+    RenderFailure(String),
+
+    /// A setting is outside of the range its property allows
+    InvalidSettingValue(String),
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
@@ -107,6 +122,11 @@ impl Display for ObsError {
             ObsError::SignalDataError(e) => write!(f, "Signal data error: {}", e),
             ObsError::EnumConversionError(e) => write!(f, "Enum conversion error: {}", e),
             ObsError::RuntimeChannelError(e) => write!(f, "Runtime channel error: {}", e),
+            ObsError::HotkeyRegistrationFailure(name) => write!(f, "Failed to register hotkey {}", name),
+            ObsError::TransitionStartFailure => write!(f, "Failed to start the transition."),
+            ObsError::SerializationError(e) => write!(f, "Serialization error: {}", e),
+            ObsError::RenderFailure(e) => write!(f, "Render failure: {}", e),
+            ObsError::InvalidSettingValue(e) => write!(f, "Invalid setting value: {}", e),
             ObsError::InvalidDll => write!(f, "A dummy DLL was loaded instead of the real libobs DLL. Make sure you bootstrap properly with libobs-bootstrapper"),
         }
     }
@@ -35,5 +35,7 @@ impl ObjectInfo {
 pub type OutputInfo = ObjectInfo;
 pub type SourceInfo = ObjectInfo;
 pub type FilterInfo = ObjectInfo;
+pub type TransitionInfo = ObjectInfo;
 pub type AudioEncoderInfo = ObjectInfo;
 pub type VideoEncoderInfo = ObjectInfo;
+pub type ServiceInfo = ObjectInfo;
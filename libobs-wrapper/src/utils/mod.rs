@@ -32,3 +32,21 @@ pub(crate) unsafe fn calldata_free(data: *mut libobs::calldata_t) {
         libobs::bfree((*data).stack as *mut _);
     }
 }
+
+/// Runs `f` and logs a panic instead of unwinding into libobs.
+/// Used by every Rust callback that libobs calls, `what` names the callback in the log.
+pub(crate) fn catch_panic<R>(what: std::fmt::Arguments, f: impl FnOnce() -> R) -> Option<R> {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(e) => {
+            let message = e
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| e.downcast_ref::<String>().map(|s| s.as_str()))
+                .unwrap_or("unknown panic");
+
+            log::error!("{} panicked: {}", what, message);
+            None
+        }
+    }
+}